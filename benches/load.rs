@@ -0,0 +1,135 @@
+//! Benchmarks for loading a JSONL export.
+//!
+//! The input is a generated architecture with many signal declarations, each
+//! taking five nodes. Run with `--features parallel` to include the parallel
+//! and memory-mapped loaders.
+
+#![expect(
+    unused_crate_dependencies,
+    reason = "benchmarks only use some dependencies of the library"
+)]
+#![expect(missing_docs, reason = "generated by the criterion macros")]
+#![expect(clippy::unwrap_used, reason = "ok for benchmarks")]
+
+#[cfg(feature = "parallel")]
+use std::env;
+use std::fmt::Write as _;
+#[cfg(feature = "parallel")]
+use std::fs;
+use std::hint::black_box;
+#[cfg(feature = "parallel")]
+use std::process;
+
+use criterion::Criterion;
+use criterion::Throughput;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use ghdl_ast::Ast;
+#[cfg(feature = "parallel")]
+use ghdl_ast::AstBuilder;
+#[cfg(feature = "parallel")]
+use ghdl_ast::LoadOptions;
+
+/// Number of generated signal declarations.
+const SIGNALS: usize = 40_000;
+
+/// Generates a single JSONL chunk with `8 + 5 * signals` nodes.
+fn generate_export(signals: usize) -> String {
+    let last_id = 8 + 5 * signals;
+    let declarations = (0..signals)
+        .map(|index| (9 + 5 * index).to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut export = String::new();
+    writeln!(
+        export,
+        r#"{{"first_id":1,"last_id":{last_id},"files":[{{"source":"top.vhd","start":1,"end":{end}}}],"libraries":[1]}}"#,
+        end = last_id + 1,
+    )
+    .unwrap();
+    export.push_str(concat!(
+        r#"{"library_declaration":{"identifier":["work",null],"design_files":[3]}}"#,
+        "\n",
+        r#"{"error":{}}"#,
+        "\n",
+        r#"{"design_file":{"design_units":[4,6]}}"#,
+        "\n",
+        r#"{"design_unit":{"library_unit":5,"design_file":3,"location":[1,1,1]}}"#,
+        "\n",
+        r#"{"entity_declaration":{"id":5,"identifier":["top",null],"parent":4,"location":[1,1,8]}}"#,
+        "\n",
+        r#"{"design_unit":{"library_unit":7,"design_file":3,"location":[1,4,1]}}"#,
+        "\n",
+    ));
+    writeln!(
+        export,
+        r#"{{"architecture_body":{{"location":[1,4,14],"identifier":["rtl",null],"entity_name":8,"parent":6,"declarations":[{declarations}]}}}}"#,
+    )
+    .unwrap();
+    export.push_str(concat!(
+        r#"{"simple_name":{"identifier":["top",null],"named_entity":5,"location":[1,4,21]}}"#,
+        "\n",
+    ));
+    for index in 0..signals {
+        let id = 9 + 5 * index;
+        let line = index + 5;
+        writeln!(
+            export,
+            r#"{{"signal_declaration":{{"identifier":["sig_{index}",null],"type":{subtype},"subtype_indication":{subtype},"location":[1,{line},10]}}}}"#,
+            subtype = id + 1,
+        )
+        .unwrap();
+        writeln!(
+            export,
+            r#"{{"integer_subtype_definition":{{"range_constraint":{range}}}}}"#,
+            range = id + 2,
+        )
+        .unwrap();
+        writeln!(
+            export,
+            r#"{{"range_expression":{{"direction":"to","left_limit":{left},"right_limit":{right}}}}}"#,
+            left = id + 3,
+            right = id + 4,
+        )
+        .unwrap();
+        export.push_str("{\"integer_literal\":{\"value\":0}}\n");
+        writeln!(export, r#"{{"integer_literal":{{"value":{index}}}}}"#).unwrap();
+    }
+    export.push('\n');
+    export
+}
+
+fn bench_load(criterion: &mut Criterion) {
+    let export = generate_export(SIGNALS);
+    let mut group = criterion.benchmark_group("load");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(export.len() as u64));
+
+    group.bench_function("from_json", |bencher| {
+        bencher.iter(|| Ast::from_json(&mut black_box(export.as_bytes()), 1).unwrap());
+    });
+
+    #[cfg(feature = "parallel")]
+    {
+        group.bench_function("append_json_str", |bencher| {
+            bencher.iter(|| {
+                let mut builder = AstBuilder::new();
+                builder.append_json_str(black_box(&export), 1).unwrap();
+                builder.finish()
+            });
+        });
+
+        let path = env::temp_dir().join(format!("ghdl-ast-bench-{}.jsonl", process::id()));
+        fs::write(&path, &export).unwrap();
+        group.bench_function("from_json_file", |bencher| {
+            bencher.iter(|| Ast::from_json_file(black_box(&path), LoadOptions::default()).unwrap());
+        });
+        fs::remove_file(&path).unwrap();
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_load);
+criterion_main!(benches);
@@ -6,61 +6,70 @@
 
 use super::*;
 
-/// Value of an attribute for a designated entity after attribute specification.
-///
-/// Links a designated named entity to the expression from an
-/// [`AttributeSpecification`](crate::AttributeSpecification) and the resulting
-/// analyzed type.
-///
-/// ```vhdl
-/// attribute keep : boolean;
-/// attribute keep of clk : signal is true;
-/// -- AttributeValue.designated_entity = clk, expression comes from the spec
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AttributeValue {
-    /// Analyzed type of the attribute value.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Entity (signal, variable, …) this attribute value applies to.
-    pub designated_entity: Option<NamedEntityNodeId>,
-    /// Attribute specification that created this value.
-    pub attribute_specification: Option<NodeId<AttributeSpecification>>,
+node_struct! {
+    /// Value of an attribute for a designated entity after attribute specification.
+    ///
+    /// Links a designated named entity to the expression from an
+    /// [`AttributeSpecification`](crate::AttributeSpecification) and the resulting
+    /// analyzed type.
+    ///
+    /// ```vhdl
+    /// attribute keep : boolean;
+    /// attribute keep of clk : signal is true;
+    /// -- AttributeValue.designated_entity = clk, expression comes from the spec
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AttributeValue {
+        /// Analyzed type of the attribute value.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Entity (signal, variable, …) this attribute value applies to.
+        @reference
+        pub designated_entity: Option<NamedEntityNodeId>,
+        /// Attribute specification that created this value.
+        @reference
+        pub attribute_specification: Option<NodeId<AttributeSpecification>>,
+    }
 }
 
-/// Predefined attribute application (`prefix'kind`).
-///
-/// Distinguishes type attributes (`'left`, `'image`), array attributes
-/// (`'length`, `'range`), signal attributes (`'event`, `'last_value`), and AMS
-/// attributes. The [`kind`](Self::kind) enum encodes which predefined attribute
-/// was applied. Parameters such as `'image(x)` or `'val(n)` are stored on this
-/// node ([`parameter`](Self::parameter) / [`parameter_2`](Self::parameter_2)…).
-///
-/// ```vhdl
-/// integer'high
-/// vec'length
-/// clk'event
-/// real'image(3.14)
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Attribute {
-    /// Prefix the attribute is applied to.
-    pub prefix: PrefixNodeId,
-    /// Which predefined attribute was selected.
-    pub kind: AttributeKind,
-    /// First attribute parameter (`ATTR(param)`), when present.
-    pub parameter: Option<ExpressionNodeId>,
-    /// Second attribute parameter (AMS attributes).
-    pub parameter_2: Option<ExpressionNodeId>,
-    /// Third attribute parameter (AMS attributes such as `'ztf`).
-    pub parameter_3: Option<ExpressionNodeId>,
-    /// Fourth attribute parameter (AMS attributes such as `'ztf`).
-    pub parameter_4: Option<ExpressionNodeId>,
-    /// Analyzed result type of the attribute application.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Index subtype for array attributes that yield a discrete range / type.
-    pub index_subtype: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Predefined attribute application (`prefix'kind`).
+    ///
+    /// Distinguishes type attributes (`'left`, `'image`), array attributes
+    /// (`'length`, `'range`), signal attributes (`'event`, `'last_value`), and AMS
+    /// attributes. The [`kind`](Self::kind) enum encodes which predefined attribute
+    /// was applied. Parameters such as `'image(x)` or `'val(n)` are stored on this
+    /// node ([`parameter`](Self::parameter) / [`parameter_2`](Self::parameter_2)…).
+    ///
+    /// ```vhdl
+    /// integer'high
+    /// vec'length
+    /// clk'event
+    /// real'image(3.14)
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct Attribute {
+        /// Prefix the attribute is applied to.
+        pub prefix: PrefixNodeId,
+        /// Which predefined attribute was selected.
+        pub kind: AttributeKind,
+        /// First attribute parameter (`ATTR(param)`), when present.
+        pub parameter: Option<ExpressionNodeId>,
+        /// Second attribute parameter (AMS attributes).
+        pub parameter_2: Option<ExpressionNodeId>,
+        /// Third attribute parameter (AMS attributes such as `'ztf`).
+        pub parameter_3: Option<ExpressionNodeId>,
+        /// Fourth attribute parameter (AMS attributes such as `'ztf`).
+        pub parameter_4: Option<ExpressionNodeId>,
+        /// Analyzed result type of the attribute application.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Index subtype for array attributes that yield a discrete range / type.
+        @reference
+        pub index_subtype: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
 /// Discriminant for a predefined VHDL attribute kind (LRM clause 16).
@@ -182,6 +191,9 @@ pub enum AttributeKind {
     RangeArray,
     /// `ARRAY'reverse_range`
     ReverseRangeArray,
+    /// Attribute not known to this crate (only produced by lenient loading).
+    #[serde(alias = "$unknown")]
+    Unknown,
 }
 
 impl fmt::Display for AttributeKind {
@@ -243,6 +255,7 @@ impl fmt::Display for AttributeKind {
             AscendingArray => "ASCENDING",
             RangeArray => "RANGE",
             ReverseRangeArray => "REVERSE_RANGE",
+            Unknown => "<unknown>",
         };
         fmt::Display::fmt(attribute_name, f)
     }
@@ -0,0 +1,877 @@
+//! Incremental construction of an [`Ast`] from one or more JSONL chunks.
+
+use std::io;
+use std::mem;
+
+use serde_json::Value;
+use serde_path_to_error::Segment;
+
+use super::*;
+use crate::nodes::NodeTag;
+
+/// Placeholder string which the `Unknown` variants of enums accept as an alias.
+const UNKNOWN_VARIANT: &str = "$unknown";
+
+/// Builds an [`Ast`] from consecutive JSONL chunks of the same GHDL process.
+///
+/// GHDL may export a design in several chunks, each starting with its own
+/// metadata line and ending with an empty line. Node IDs are global to the GHDL
+/// process, so the nodes of a later chunk are stored at their original IDs. A
+/// node exported again by a later chunk replaces the earlier version, e.g. a
+/// library node whose design file list has grown.
+///
+/// ```ignore
+/// let mut builder = AstBuilder::new();
+/// let mut next_line_number = builder.append_json(&mut std_and_ieee, 1)?;
+/// next_line_number = builder.append_json(&mut work, next_line_number)?;
+/// let ast = builder.finish();
+/// ```
+#[derive(Debug)]
+pub struct AstBuilder {
+    ast: Ast,
+    options: LoadOptions,
+    warnings: Vec<LoadWarning>,
+}
+
+impl AstBuilder {
+    /// Creates a builder for an empty AST.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from_ast(Ast::empty())
+    }
+
+    /// Creates a builder which appends to an existing AST.
+    #[must_use]
+    pub fn from_ast(ast: Ast) -> Self {
+        Self {
+            ast,
+            options: LoadOptions::default(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Sets the options used for the following chunks.
+    #[must_use]
+    pub fn with_options(mut self, options: LoadOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Reads one JSONL chunk and adds its nodes to the AST.
+    ///
+    /// `next_line_number` is the 1-based line number of the chunk's metadata line
+    /// within the stream; it is only used for error messages. Returns the 1-based
+    /// line number following the empty line terminating the chunk, which is the
+    /// metadata line of the next chunk, or following the last record at the end
+    /// of the stream.
+    ///
+    /// The lookup maps and indexes of the AST are updated to cover all chunks
+    /// appended so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the buffer or parsing the JSON fails, or if
+    /// the chunk is inconsistent with its metadata or the previous chunks. The AST
+    /// may contain part of the chunk in that case.
+    pub fn append_json(
+        &mut self,
+        reader: &mut dyn BufRead,
+        mut next_line_number: u32,
+    ) -> Result<u32, LoadError> {
+        let mut line_buffer = String::new();
+        reader.read_line(&mut line_buffer)?;
+        let metadata = self.begin_chunk(&line_buffer, next_line_number)?;
+
+        let mut node_index = metadata.first_id as usize;
+        loop {
+            next_line_number += 1;
+            line_buffer.clear();
+            let read = reader.read_line(&mut line_buffer)?;
+            let line = line_buffer.trim();
+            if line.is_empty() {
+                if read != 0 {
+                    // The empty line terminating the chunk.
+                    next_line_number += 1;
+                }
+                break;
+            }
+
+            let parsed = parse_node(line, next_line_number);
+            self.insert_record(node_index, line, next_line_number, parsed)?;
+            node_index += 1;
+        }
+
+        self.end_chunk(metadata, node_index)?;
+        Ok(next_line_number)
+    }
+
+    /// Adds all JSONL chunks in `input` to the AST, parsing the node records in parallel.
+    ///
+    /// Produces the same AST and warnings as calling [`append_json`](Self::append_json)
+    /// once per chunk. `next_line_number` is the 1-based line number of the first
+    /// line of `input`; returns the line number following the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing the JSON fails, or if a chunk is inconsistent
+    /// with its metadata or the previous chunks. The AST may contain part of the
+    /// input in that case.
+    #[cfg(feature = "parallel")]
+    pub fn append_json_str(
+        &mut self,
+        input: &str,
+        mut next_line_number: u32,
+    ) -> Result<u32, LoadError> {
+        use rayon::prelude::*;
+
+        /// Number of records parsed at a time, which bounds the memory taken by parsed nodes in flight.
+        const BATCH_SIZE: usize = 1 << 16;
+
+        let mut lines = input.lines().map(str::trim);
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+        while let Some(metadata_line) = lines.next() {
+            let metadata_line_number = next_line_number;
+            next_line_number += 1;
+            if metadata_line.is_empty() {
+                continue;
+            }
+            let metadata = self.begin_chunk(metadata_line, metadata_line_number)?;
+
+            let mut node_index = metadata.first_id as usize;
+            let mut chunk_done = false;
+            while !chunk_done {
+                batch.clear();
+                while batch.len() < BATCH_SIZE {
+                    match lines.next() {
+                        Some(line) if !line.is_empty() => {
+                            batch.push((next_line_number, line));
+                            next_line_number += 1;
+                        },
+                        terminator => {
+                            // The empty line terminating the chunk, unless the input ends.
+                            if terminator.is_some() {
+                                next_line_number += 1;
+                            }
+                            chunk_done = true;
+                            break;
+                        },
+                    }
+                }
+
+                let records = batch
+                    .par_iter()
+                    .map(|&(line_number, line)| parse_node(line, line_number))
+                    .collect::<Vec<_>>();
+                for (&(line_number, line), parsed) in iter::zip(&batch, records) {
+                    self.insert_record(node_index, line, line_number, parsed)?;
+                    node_index += 1;
+                }
+            }
+
+            self.end_chunk(metadata, node_index)?;
+        }
+        Ok(next_line_number)
+    }
+
+    /// Parses the metadata line of a chunk and prepares the node table for its records.
+    fn begin_chunk(&mut self, line: &str, line_number: u32) -> Result<AstMetadata, LoadError> {
+        let ast = &mut self.ast;
+        let metadata: AstMetadata =
+            serde_json::from_str(line).map_err(|source| LoadError::Metadata {
+                line: line_number,
+                source,
+            })?;
+        debug!("AST metadata: {metadata:#?}");
+        ast.source_hash = cache::hash_source_line(ast.source_hash, line);
+
+        let first_id = metadata.first_id as usize;
+        if ast.nodes.is_empty() {
+            ast.first_id = metadata.first_id;
+        } else if first_id > ast.nodes.len() {
+            return Err(LoadError::IdGap {
+                first_id: metadata.first_id,
+                expected_first_id: ast.nodes.len(),
+            });
+        } else {
+            ast.first_id = ast.first_id.min(metadata.first_id);
+        }
+
+        let additional = (metadata.last_id as usize + 1).saturating_sub(ast.nodes.len());
+        ast.nodes.reserve(additional);
+        ast.locations.reserve(additional);
+        Ok(metadata)
+    }
+
+    /// Stores the parsed node record for the given node ID, recovering from parse errors in lenient mode.
+    fn insert_record(
+        &mut self,
+        node_index: usize,
+        line: &str,
+        line_number: u32,
+        parsed: Result<Option<LocatedNode>, LoadError>,
+    ) -> Result<(), LoadError> {
+        self.ast.source_hash = cache::hash_source_line(self.ast.source_hash, line);
+        let node_opt = match parsed {
+            Ok(node_opt) => node_opt.map(LocatedNode::into_parts),
+            Err(error) if !self.options.strict => {
+                recover_node(line, line_number, error, &mut self.warnings)?
+            },
+            Err(error) => return Err(error),
+        };
+        // A `null` record doesn't replace a node exported by an earlier chunk.
+        if let Some((node, location)) = node_opt {
+            self.ast.set_node(node_index, node, location);
+        } else if node_index >= self.ast.nodes.len() {
+            self.ast.set_node(node_index, Node::Empty, None);
+        }
+        Ok(())
+    }
+
+    /// Checks that a chunk is complete and updates the file table, lookup maps and indexes.
+    fn end_chunk(&mut self, metadata: AstMetadata, end_index: usize) -> Result<(), LoadError> {
+        let ast = &mut self.ast;
+        if end_index != metadata.last_id as usize + 1 {
+            return Err(LoadError::NodeCountMismatch {
+                first_id: metadata.first_id,
+                last_id: metadata.last_id,
+                count: end_index - metadata.first_id as usize,
+            });
+        }
+
+        // The file table only ever grows; each chunk lists all files known so far.
+        let known_files = ast.files.len();
+        ast.files
+            .extend(metadata.files.into_iter().skip(known_files));
+        for library_id in metadata.libraries {
+            if !ast.library_ids.contains(&library_id) {
+                ast.library_ids.push(library_id);
+            }
+        }
+
+        let first_index = ast.build_indexes(metadata.first_id as usize);
+        for error in lookups::build_maps(ast, first_index) {
+            if self.options.strict {
+                return Err(error.into());
+            }
+            self.warnings
+                .push(LoadWarning::UnresolvedLibraryUnit { error });
+        }
+        if Error::GLOBAL_ID.try_get(ast).is_err() {
+            return Err(LoadError::MissingErrorNode);
+        }
+        Ok(())
+    }
+
+    /// Returns the warnings recorded so far in lenient mode.
+    #[must_use]
+    pub fn warnings(&self) -> &[LoadWarning] {
+        &self.warnings
+    }
+
+    /// Removes and returns the warnings recorded so far.
+    pub fn take_warnings(&mut self) -> Vec<LoadWarning> {
+        mem::take(&mut self.warnings)
+    }
+
+    /// Returns the AST built so far.
+    #[must_use]
+    pub fn ast(&self) -> &Ast {
+        &self.ast
+    }
+
+    /// Returns the built AST.
+    #[must_use]
+    pub fn finish(self) -> Ast {
+        self.ast
+    }
+}
+
+impl Default for AstBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Options for loading an AST.
+#[derive(Clone, Copy, Debug)]
+pub struct LoadOptions {
+    /// Whether to reject input which this crate doesn't know.
+    ///
+    /// In lenient mode (`false`), a record of an unknown node kind, or one that
+    /// can't be parsed at all, is stored as [`Node::Unknown`]. Unknown values of
+    /// enums with an `Unknown` variant (e.g. [`ImplicitDefinition`]) are mapped to
+    /// that variant. A [`LoadWarning`] is recorded in both cases.
+    ///
+    /// Defaults to `true`.
+    pub strict: bool,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        Self { strict: true }
+    }
+}
+
+/// Input which lenient loading accepted despite not understanding it.
+#[derive(Clone, Debug)]
+pub enum LoadWarning {
+    /// A record of an unknown node kind was stored as [`Node::Unknown`].
+    UnknownNodeKind {
+        /// 1-based line number of the node record.
+        line: u32,
+        /// Tag of the node record.
+        kind: String,
+    },
+
+    /// A record of a known node kind couldn't be parsed and was stored as [`Node::Unknown`].
+    InvalidNode {
+        /// 1-based line number of the node record.
+        line: u32,
+        /// Tag of the node record.
+        kind: String,
+        /// Description of the parse error.
+        message: String,
+    },
+
+    /// An unknown enum value was replaced by the `Unknown` variant of the enum.
+    UnknownValue {
+        /// 1-based line number of the node record.
+        line: u32,
+        /// Tag of the node record.
+        kind: String,
+        /// Path of the value within the record.
+        path: String,
+        /// The unknown value.
+        value: String,
+    },
+
+    /// A design file, design unit or library unit of a library, or the entity of an
+    /// architecture body or configuration declaration, couldn't be resolved. The library
+    /// unit was left out of the lookup maps.
+    UnresolvedLibraryUnit {
+        /// Why the node couldn't be resolved.
+        error: LookupNodeError,
+    },
+}
+
+impl fmt::Display for LoadWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadWarning::UnknownNodeKind { line, kind } => {
+                write!(f, "line {line}: unknown node kind `{kind}`")
+            },
+            LoadWarning::InvalidNode {
+                line,
+                kind,
+                message,
+            } => write!(f, "line {line}: invalid {kind} node: {message}"),
+            LoadWarning::UnknownValue {
+                line,
+                kind: _,
+                path,
+                value,
+            } => write!(f, "line {line}: unknown value `{value}` at `{path}`"),
+            LoadWarning::UnresolvedLibraryUnit { error } => {
+                write!(f, "skipped library unit: {error}")
+            },
+        }
+    }
+}
+
+/// Error returned when loading an AST from JSON fails.
+#[derive(Debug, thiserror::Error)]
+pub enum LoadError {
+    /// Reading from the input failed.
+    #[error("could not read AST: {0}")]
+    Io(#[from] io::Error),
+
+    /// The metadata line at the start of a chunk could not be parsed.
+    #[error("could not parse AST metadata in line {line}: {source}")]
+    Metadata {
+        /// 1-based line number of the metadata line.
+        line: u32,
+        /// Underlying JSON error.
+        source: serde_json::Error,
+    },
+
+    /// A node record could not be parsed.
+    #[error(
+        "parse error in line {line} ({kind} at `{path}`): {source}",
+        kind = kind.as_deref().unwrap_or("unknown node kind"),
+    )]
+    Node {
+        /// 1-based line number of the node record.
+        line: u32,
+        /// Tag of the node record (e.g. `entity_declaration`), if it could be determined.
+        kind: Option<String>,
+        /// Path of the offending value within the record (e.g. `entity_declaration.ports[1]`).
+        path: String,
+        /// Underlying JSON error.
+        source: serde_json::Error,
+    },
+
+    /// A chunk doesn't continue the node table of the previous chunks.
+    #[error(
+        "chunk starts at node #{first_id}, but the AST only has nodes up to #{expected_first_id}"
+    )]
+    IdGap {
+        /// First node ID declared by the chunk metadata.
+        first_id: u32,
+        /// Highest first node ID the chunk could have started at.
+        expected_first_id: usize,
+    },
+
+    /// The number of node records doesn't match the ID range declared by the metadata.
+    #[error("expected nodes #{first_id}..=#{last_id}, but found {count} node records")]
+    NodeCountMismatch {
+        /// First node ID declared by the chunk metadata.
+        first_id: u32,
+        /// Last node ID declared by the chunk metadata.
+        last_id: u32,
+        /// Number of node records found in the chunk.
+        count: usize,
+    },
+
+    /// The global error node ([`Error::GLOBAL_ID`]) is missing.
+    #[error("global error node #{id} is missing", id = Error::GLOBAL_ID.to_raw())]
+    MissingErrorNode,
+
+    /// A node referenced by a library unit is missing or of the wrong kind.
+    #[error("invalid library unit: {0}")]
+    Lookup(#[from] LookupNodeError),
+}
+
+/// Parses a single node record, tracking the path of the offending value on errors.
+fn parse_node(line: &str, line_number: u32) -> Result<Option<LocatedNode>, LoadError> {
+    let node_error = |path: String, source| {
+        let kind = serde_json::from_str::<serde_json::Map<String, Value>>(line)
+            .ok()
+            .and_then(|record| record.keys().next().cloned());
+        LoadError::Node {
+            line: line_number,
+            kind,
+            path,
+            source,
+        }
+    };
+
+    let mut deserializer = serde_json::Deserializer::from_str(line);
+    let node = serde_path_to_error::deserialize(&mut deserializer).map_err(|error| {
+        let path = error.path().to_string();
+        node_error(path, error.into_inner())
+    })?;
+    deserializer
+        .end()
+        .map_err(|source| node_error(String::new(), source))?;
+    Ok(node)
+}
+
+/// Recovers from a node record which failed to parse, for lenient loading.
+///
+/// Returns the original error if the record isn't a JSON object with a single
+/// node kind tag.
+fn recover_node(
+    line: &str,
+    line_number: u32,
+    parse_error: LoadError,
+    warnings: &mut Vec<LoadWarning>,
+) -> Result<Option<(Node, Option<Location>)>, LoadError> {
+    let Ok(mut record) = serde_json::from_str::<Value>(line) else {
+        return Err(parse_error);
+    };
+    let Some((kind, _)) = record
+        .as_object()
+        .filter(|object| object.len() == 1)
+        .and_then(|object| object.iter().next())
+    else {
+        return Err(parse_error);
+    };
+    let kind = kind.clone();
+
+    if NodeTag::deserialize(Value::String(kind.clone())).is_ok() {
+        // Replace unknown enum values by the placeholder one at a time, until the
+        // record parses or fails for another reason.
+        let original_record = record.clone();
+        let mut replaced = Vec::<(String, String, String)>::new();
+        let message = loop {
+            let error = match serde_path_to_error::deserialize::<_, LocatedNode>(&record) {
+                Ok(node) => {
+                    warnings.extend(replaced.into_iter().map(|(path, unknown_value, _)| {
+                        LoadWarning::UnknownValue {
+                            line: line_number,
+                            kind: kind.clone(),
+                            path,
+                            value: unknown_value,
+                        }
+                    }));
+                    return Ok(Some(node.into_parts()));
+                },
+                Err(error) => error,
+            };
+            let path = error.path().to_string();
+            let message = error.inner().to_string();
+            match value_at_path(&mut record, error.path()) {
+                Some(Value::String(string))
+                    if string != UNKNOWN_VARIANT && message.starts_with("unknown variant") =>
+                {
+                    let unknown_value = mem::replace(string, UNKNOWN_VARIANT.to_owned());
+                    replaced.push((path, unknown_value, message));
+                },
+                // The enum has no variant accepting the placeholder, so report the original value.
+                Some(Value::String(string)) if string == UNKNOWN_VARIANT => {
+                    break replaced
+                        .into_iter()
+                        .find_map(|(replaced_path, _, first_message)| {
+                            (replaced_path == path).then_some(first_message)
+                        })
+                        .unwrap_or(message);
+                },
+                _ => break message,
+            }
+        };
+        warnings.push(LoadWarning::InvalidNode {
+            line: line_number,
+            kind: kind.clone(),
+            message,
+        });
+        record = original_record;
+    } else {
+        warnings.push(LoadWarning::UnknownNodeKind {
+            line: line_number,
+            kind: kind.clone(),
+        });
+    }
+
+    let raw = record.get_mut(&kind).map_or(Value::Null, Value::take);
+    let location = raw
+        .get("location")
+        .and_then(|location| Location::deserialize(location).ok());
+    Ok(Some((Node::Unknown { kind, raw }, location)))
+}
+
+/// Returns the value at the given deserialization path.
+fn value_at_path<'value>(
+    mut value: &'value mut Value,
+    path: &serde_path_to_error::Path,
+) -> Option<&'value mut Value> {
+    for segment in path {
+        value = match segment {
+            Segment::Seq { index } => value.get_mut(index)?,
+            Segment::Map { key } | Segment::Enum { variant: key } => value.get_mut(key)?,
+            Segment::Unknown => return None,
+        };
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+    use crate::tests::SECOND_CHUNK;
+
+    #[test]
+    fn test_builder_appends_chunks() {
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        assert_eq!(next_line_number, 22);
+        builder
+            .append_json(&mut SECOND_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        let ast = builder.finish();
+
+        let work = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap();
+        let lib = ast
+            .lookup_library(&NormalizedIdentifier::new("lib"))
+            .unwrap();
+        assert!(
+            ast.lookup_entity_declaration(work, NormalizedIdentifier::new("top"))
+                .is_some()
+        );
+        let leaf = ast
+            .lookup_entity_declaration(lib, NormalizedIdentifier::new("leaf"))
+            .unwrap();
+
+        let leaf_file = ast.location_file(ast.location(leaf).unwrap()).unwrap();
+        assert_eq!(leaf_file.source.path(), Some(Path::new("leaf.vhd")));
+        assert_eq!(ast.ancestors(leaf).last(), Some(lib.into()));
+    }
+
+    #[test]
+    fn test_builder_indexes_appended_nodes() {
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut SECOND_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        let mut ast = builder.finish();
+        let parents = ast.parents.clone();
+        let node_symbols = ast.node_symbols.clone();
+        let entity_references = ast.entity_references.clone();
+        let entity_declarations = ast.entity_declarations.clone();
+
+        // Indexing only the second chunk gives the same result as indexing everything.
+        assert_eq!(ast.build_indexes(0), 0);
+        lookups::build_maps(&mut ast, 0);
+        assert_eq!(ast.parents, parents);
+        assert_eq!(ast.node_symbols, node_symbols);
+        assert_eq!(ast.entity_references, entity_references);
+        assert_eq!(ast.entity_declarations, entity_declarations);
+
+        // A chunk replacing indexed nodes makes the builder index everything again.
+        let replacement = FIXTURE.replace(
+            r#"{"signal_declaration":{"identifier":["clk",null]"#,
+            r#"{"signal_declaration":{"identifier":["rst",null]"#,
+        );
+        let mut replacing_builder = AstBuilder::from_ast(ast);
+        replacing_builder
+            .append_json(&mut replacement.as_bytes(), 1)
+            .unwrap();
+        let replaced = replacing_builder.finish();
+        let signal = NodeId::<SignalDeclaration>::from_raw(IdPrimitive::new(9).unwrap());
+        assert_eq!(
+            replaced
+                .symbols()
+                .resolve(signal.symbol(&replaced).unwrap())
+                .as_str(),
+            "rst"
+        );
+        assert_eq!(replaced.parents, parents);
+    }
+
+    #[test]
+    fn test_failed_chunk_keeps_earlier_units() {
+        const BROKEN_CHUNK: &str = r#"{"first_id":20,"last_id":24,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"broken.vhd","start":20,"end":25}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"architecture_body":{"identifier":["rtl",null],"entity_name":24,"parent":22}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9}}
+
+"#;
+
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        let error = builder
+            .append_json(&mut BROKEN_CHUNK.as_bytes(), next_line_number)
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            LoadError::Lookup(LookupNodeError::WrongType {
+                actual: "SignalDeclaration",
+                ..
+            })
+        ));
+
+        let ast = builder.finish();
+        let work = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap();
+        let top = ast
+            .lookup_entity_declaration(work, NormalizedIdentifier::new("top"))
+            .unwrap();
+        assert_eq!(ast.lookup_architecture_bodies(top).len(), 1);
+        assert!(
+            ast.lookup_library(&NormalizedIdentifier::new("lib"))
+                .is_some()
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_loading() {
+        let input = FIXTURE
+            .replace(r#"{"value":0}"#, r#"{"value":"zero"}"#)
+            .replace(r#""waveform_element":"#, r#""waveform_element_2077":"#);
+        let options = LoadOptions { strict: false };
+        let expected = Ast::from_json_with_options(&mut input.as_bytes(), 1, options).unwrap();
+
+        let mut builder = AstBuilder::new().with_options(options);
+        let next_line_number = builder.append_json_str(&input, 1).unwrap();
+        assert_eq!(next_line_number, 22);
+        assert_eq!(next_line_number, expected.next_line_number);
+        let warnings = builder.take_warnings();
+        let ast = builder.finish();
+
+        assert_eq!(
+            format!("{:?}", ast.nodes),
+            format!("{:?}", expected.ast.nodes)
+        );
+        assert_eq!(ast.locations, expected.ast.locations);
+        assert_eq!(ast.parents, expected.ast.parents);
+        assert_eq!(ast.source_hash, expected.ast.source_hash);
+        assert_eq!(format!("{warnings:?}"), format!("{:?}", expected.warnings));
+
+        builder = AstBuilder::new();
+        let error = builder
+            .append_json_str(&FIXTURE.replace(r#"{"value":0}"#, r#"{"value":"zero"}"#), 1)
+            .unwrap_err();
+        assert!(matches!(error, LoadError::Node { line: 13, .. }));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_next_line_numbers() {
+        let next_line_numbers = |input: &str| {
+            let serial = Ast::from_json(&mut input.as_bytes(), 1).unwrap();
+            let mut builder = AstBuilder::new();
+            let parallel = builder.append_json_str(input, 1).unwrap();
+            (serial.next_line_number, parallel)
+        };
+        assert_eq!(next_line_numbers(FIXTURE), (22, 22));
+        assert_eq!(next_line_numbers(FIXTURE.trim_end()), (21, 21));
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let load_error = |from: &str, to: &str| {
+            let input = FIXTURE.replace(from, to);
+            Ast::from_json(&mut input.as_bytes(), 1).unwrap_err()
+        };
+
+        assert!(matches!(
+            load_error(r#"{"value":0}"#, r#"{"value":"zero"}"#),
+            LoadError::Node { line: 13, kind: Some(kind), path, .. }
+                if kind == "integer_literal" && path == "integer_literal.value"
+        ));
+        assert!(matches!(
+            load_error(r#""last_id":19"#, r#""last_id":20"#),
+            LoadError::NodeCountMismatch {
+                first_id: 1,
+                last_id: 20,
+                count: 19,
+            }
+        ));
+        assert!(matches!(
+            load_error(r#"{"error":{}}"#, "null"),
+            LoadError::MissingErrorNode
+        ));
+        assert!(matches!(
+            load_error(r#""named_entity":5"#, r#""named_entity":9"#),
+            LoadError::Lookup(LookupNodeError::WrongType {
+                actual: "SignalDeclaration",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_lenient_loading() {
+        let input = FIXTURE
+            .replace(r#"{"value":0}"#, r#"{"value":"zero"}"#)
+            .replace(
+                r#"{"waveform_element":{"we_value":19}}"#,
+                r#"{"waveform_element_2077":{"we_value":19,"location":[1,9,12]}}"#,
+            )
+            .replace(
+                r#"{"integer_literal":{"value":1,"location":[1,9,12]}}"#,
+                r#"{"binary_operator":{"kind":"<=>","left":12,"right":13,"implementation":2}}"#,
+            );
+        Ast::from_json(&mut input.as_bytes(), 1).unwrap_err();
+
+        let options = LoadOptions { strict: false };
+        let output = Ast::from_json_with_options(&mut input.as_bytes(), 1, options).unwrap();
+        let ast = output.ast;
+        let id = |raw| GenericNodeId::from_raw(IdPrimitive::new(raw).unwrap());
+
+        assert!(matches!(
+            id(12).get(&ast),
+            Node::Unknown { kind, raw } if kind == "integer_literal" && raw["value"] == "zero"
+        ));
+        assert!(matches!(
+            id(18).get(&ast),
+            Node::Unknown { kind, .. } if kind == "waveform_element_2077"
+        ));
+        assert_eq!(ast.location(id(18)).map(|location| location.line), Some(9));
+        assert!(matches!(
+            id(19).get(&ast),
+            Node::BinaryOperator(BinaryOperator {
+                kind: BinaryOperatorKind::Unknown,
+                ..
+            })
+        ));
+
+        let warnings = output
+            .warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            warnings,
+            [
+                "line 13: invalid integer_literal node: invalid type: string \"zero\", expected i64",
+                "line 19: unknown node kind `waveform_element_2077`",
+                "line 20: unknown value `<=>` at `binary_operator.kind`",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_loading_unknown_library_unit() {
+        let input = FIXTURE
+            .replace(r#""architecture_body":"#, r#""architecture_body_2077":"#)
+            .replace(r#""direction":"to""#, r#""direction":"sideways""#);
+        Ast::from_json(&mut input.as_bytes(), 1).unwrap_err();
+
+        let options = LoadOptions { strict: false };
+        let output = Ast::from_json_with_options(&mut input.as_bytes(), 1, options).unwrap();
+        let ast = output.ast;
+        let work = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap();
+        let top = ast
+            .lookup_entity_declaration(work, NormalizedIdentifier::new("top"))
+            .unwrap();
+        assert_eq!(work.get(&ast).library_units_iter(&ast).count(), 1);
+        assert!(ast.lookup_architecture_bodies(top).is_empty());
+
+        let warnings = output
+            .warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            warnings,
+            [
+                "line 8: unknown node kind `architecture_body_2077`",
+                "line 12: invalid range_expression node: unknown variant `sideways`, expected `to` or `downto`",
+                "skipped library unit: node #7 is of type <unknown>; expected ghdl_ast::libraries::LibraryUnit<'_>",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lenient_loading_unknown_entity() {
+        let input = FIXTURE.replace(r#""entity_declaration":"#, r#""entity_declaration_2077":"#);
+        Ast::from_json(&mut input.as_bytes(), 1).unwrap_err();
+
+        let options = LoadOptions { strict: false };
+        let output = Ast::from_json_with_options(&mut input.as_bytes(), 1, options).unwrap();
+        let ast = output.ast;
+        let work = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap();
+        assert!(
+            ast.lookup_entity_declaration(work, NormalizedIdentifier::new("top"))
+                .is_none()
+        );
+        assert!(ast.architecture_bodies.is_empty());
+
+        let warnings = output
+            .warnings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            warnings,
+            [
+                "line 6: unknown node kind `entity_declaration_2077`",
+                "skipped library unit: node #5 is of type <unknown>; expected ghdl_ast::libraries::LibraryUnit<'_>",
+                "skipped library unit: node #5 is of type <unknown>; expected &ghdl_ast::libraries::EntityDeclaration",
+            ]
+        );
+    }
+}
@@ -0,0 +1,355 @@
+//! Binary cache format for a loaded [`Ast`].
+//!
+//! A cache file starts with [`MAGIC`], followed by a MessagePack-encoded
+//! [`CacheHeader`] and the MessagePack-encoded node table, parent index and
+//! lookup maps. The header records the [`Ast::source_hash`] of the JSONL export,
+//! so that a cache can be matched against a re-exported design. Structs are
+//! encoded as maps with field names, so the format is self-describing, but a
+//! cache is only ever read back by the exact crate version that wrote it: the
+//! node structs change between versions, and re-parsing the JSON export is
+//! always possible.
+
+use std::borrow::Cow;
+use std::hash::Hasher;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+
+use rmp_serde::decode;
+use rmp_serde::encode;
+use rustc_hash::FxHasher;
+use serde_json::Value;
+
+use super::*;
+
+/// Leading bytes of every cache file.
+const MAGIC: &[u8; 8] = b"GHDLAST\0";
+
+/// Version of the cache layout, incremented on changes which aren't covered by the crate version.
+const FORMAT_VERSION: u32 = 1;
+
+/// Error returned when saving or loading a binary AST cache fails.
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    /// Reading from or writing to the cache failed.
+    #[error("could not access AST cache: {0}")]
+    Io(#[from] io::Error),
+
+    /// The input doesn't start with the cache file magic.
+    #[error("not an AST cache file")]
+    NotACache,
+
+    /// The cache was written by another crate version or with another layout.
+    #[error(
+        "stale AST cache (format {format_version}, ghdl-ast {crate_version}; expected format {FORMAT_VERSION}, ghdl-ast {expected})",
+        expected = env!("CARGO_PKG_VERSION"),
+    )]
+    Stale {
+        /// Format version found in the cache.
+        format_version: u32,
+        /// Crate version which wrote the cache.
+        crate_version: String,
+    },
+
+    /// The cache was written for an AST loaded from another JSONL export.
+    #[error("outdated AST cache (source hash {source_hash:#018x}; expected {expected:#018x})")]
+    Outdated {
+        /// [`Ast::source_hash`] recorded in the cache.
+        source_hash: u64,
+        /// Hash of the current export.
+        expected: u64,
+    },
+
+    /// The AST could not be encoded.
+    #[error("could not encode AST cache: {0}")]
+    Encode(#[from] encode::Error),
+
+    /// The cache content could not be decoded.
+    #[error("could not decode AST cache: {0}")]
+    Decode(#[from] decode::Error),
+}
+
+#[derive(Deserialize, Serialize)]
+struct CacheHeader<'a> {
+    format_version: u32,
+    crate_version: Cow<'a, str>,
+    source_hash: u64,
+}
+
+/// Node table entry; unlike [`Node`], nodes of unknown kinds can be deserialized.
+#[derive(Deserialize)]
+enum CachedNode {
+    Known(Node),
+    Unknown { kind: String, raw: Value },
+}
+
+/// Borrowed counterpart of [`CachedNode`].
+#[derive(Serialize)]
+enum CachedNodeRef<'a> {
+    Known(&'a Node),
+    Unknown { kind: &'a str, raw: &'a Value },
+}
+
+/// Borrowed cache content, as written by [`Ast::save_binary`].
+#[derive(Serialize)]
+struct CacheContentRef<'a> {
+    #[serde(serialize_with = "serialize_nodes")]
+    nodes: &'a [Node],
+    locations: &'a [Option<Location>],
+    files: &'a [FileMetadata],
+    first_id: u32,
+    parents: &'a [Option<GenericNodeId>],
+    symbols: &'a SymbolTable,
+    node_symbols: &'a [Option<Symbol>],
+    entity_references: &'a Map<NamedEntityNodeId, Vec<EntityReference>>,
+    library_ids: &'a [NodeId<Library>],
+    libraries: &'a Map<Symbol, NodeId<Library>>,
+    package_declarations: &'a Map<(NodeId<Library>, Symbol), NodeId<PackageDeclaration>>,
+    entity_declarations: &'a Map<(NodeId<Library>, Symbol), NodeId<EntityDeclaration>>,
+    package_bodies: &'a Map<(NodeId<Library>, Symbol), NodeId<PackageBody>>,
+    package_instantiations:
+        &'a Map<(NodeId<Library>, Symbol), NodeId<PackageInstantiationDeclaration>>,
+    configuration_declarations:
+        &'a Map<(NodeId<Library>, Symbol), NodeId<ConfigurationDeclaration>>,
+    context_declarations: &'a Map<(NodeId<Library>, Symbol), NodeId<ContextDeclaration>>,
+    architecture_bodies: &'a Map<NodeId<EntityDeclaration>, Vec<NodeId<ArchitectureBody>>>,
+    configurations: &'a Map<NodeId<EntityDeclaration>, Vec<NodeId<ConfigurationDeclaration>>>,
+}
+
+/// Owned cache content, as read by [`Ast::load_binary`].
+#[derive(Deserialize)]
+struct CacheContent {
+    nodes: Vec<CachedNode>,
+    locations: Vec<Option<Location>>,
+    files: Vec<FileMetadata>,
+    first_id: u32,
+    parents: Vec<Option<GenericNodeId>>,
+    symbols: SymbolTable,
+    node_symbols: Vec<Option<Symbol>>,
+    entity_references: Map<NamedEntityNodeId, Vec<EntityReference>>,
+    library_ids: Vec<NodeId<Library>>,
+    libraries: Map<Symbol, NodeId<Library>>,
+    package_declarations: Map<(NodeId<Library>, Symbol), NodeId<PackageDeclaration>>,
+    entity_declarations: Map<(NodeId<Library>, Symbol), NodeId<EntityDeclaration>>,
+    package_bodies: Map<(NodeId<Library>, Symbol), NodeId<PackageBody>>,
+    package_instantiations: Map<(NodeId<Library>, Symbol), NodeId<PackageInstantiationDeclaration>>,
+    configuration_declarations: Map<(NodeId<Library>, Symbol), NodeId<ConfigurationDeclaration>>,
+    context_declarations: Map<(NodeId<Library>, Symbol), NodeId<ContextDeclaration>>,
+    architecture_bodies: Map<NodeId<EntityDeclaration>, Vec<NodeId<ArchitectureBody>>>,
+    configurations: Map<NodeId<EntityDeclaration>, Vec<NodeId<ConfigurationDeclaration>>>,
+}
+
+fn serialize_nodes<S>(nodes: &&[Node], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(nodes.iter().map(|node| match node {
+        Node::Unknown { kind, raw } => CachedNodeRef::Unknown { kind, raw },
+        _ => CachedNodeRef::Known(node),
+    }))
+}
+
+/// Writes `ast` in the cache format, see [`Ast::save_binary`].
+pub(crate) fn save(ast: &Ast, mut writer: impl Write) -> Result<(), CacheError> {
+    writer.write_all(MAGIC)?;
+    let header = CacheHeader {
+        format_version: FORMAT_VERSION,
+        crate_version: Cow::Borrowed(env!("CARGO_PKG_VERSION")),
+        source_hash: ast.source_hash,
+    };
+    encode::write_named(&mut writer, &header)?;
+    let content = CacheContentRef {
+        nodes: &ast.nodes,
+        locations: &ast.locations,
+        files: &ast.files,
+        first_id: ast.first_id,
+        parents: &ast.parents,
+        symbols: &ast.symbols,
+        node_symbols: &ast.node_symbols,
+        entity_references: &ast.entity_references,
+        library_ids: &ast.library_ids,
+        libraries: &ast.libraries,
+        package_declarations: &ast.package_declarations,
+        entity_declarations: &ast.entity_declarations,
+        package_bodies: &ast.package_bodies,
+        package_instantiations: &ast.package_instantiations,
+        configuration_declarations: &ast.configuration_declarations,
+        context_declarations: &ast.context_declarations,
+        architecture_bodies: &ast.architecture_bodies,
+        configurations: &ast.configurations,
+    };
+    encode::write_named(&mut writer, &content)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads an AST in the cache format, see [`Ast::load_binary`] and [`Ast::load_binary_for_source`].
+pub(crate) fn load(mut reader: impl Read, source_hash: Option<u64>) -> Result<Ast, CacheError> {
+    let mut magic = [0; MAGIC.len()];
+    match reader.read_exact(&mut magic) {
+        Ok(()) if magic == *MAGIC => {},
+        Err(error) if error.kind() != io::ErrorKind::UnexpectedEof => return Err(error.into()),
+        Ok(()) | Err(_) => return Err(CacheError::NotACache),
+    }
+    let header: CacheHeader<'_> = decode::from_read(&mut reader)?;
+    if header.format_version != FORMAT_VERSION || header.crate_version != env!("CARGO_PKG_VERSION")
+    {
+        return Err(CacheError::Stale {
+            format_version: header.format_version,
+            crate_version: header.crate_version.into_owned(),
+        });
+    }
+    if let Some(expected) = source_hash
+        && header.source_hash != expected
+    {
+        return Err(CacheError::Outdated {
+            source_hash: header.source_hash,
+            expected,
+        });
+    }
+
+    let content: CacheContent = decode::from_read(&mut reader)?;
+    let nodes = content
+        .nodes
+        .into_iter()
+        .map(|cached| match cached {
+            CachedNode::Known(node) => node,
+            CachedNode::Unknown { kind, raw } => Node::Unknown { kind, raw },
+        })
+        .collect();
+    Ok(Ast {
+        nodes,
+        locations: content.locations,
+        files: content.files,
+        first_id: content.first_id,
+        parents: content.parents,
+        symbols: content.symbols,
+        node_symbols: content.node_symbols,
+        entity_references: content.entity_references,
+        source_hash: header.source_hash,
+        library_ids: content.library_ids,
+        libraries: content.libraries,
+        package_declarations: content.package_declarations,
+        entity_declarations: content.entity_declarations,
+        package_bodies: content.package_bodies,
+        package_instantiations: content.package_instantiations,
+        configuration_declarations: content.configuration_declarations,
+        context_declarations: content.context_declarations,
+        architecture_bodies: content.architecture_bodies,
+        configurations: content.configurations,
+    })
+}
+
+/// Adds a JSONL line to an [`Ast::source_hash`]; blank lines and surrounding whitespace are ignored.
+pub(crate) fn hash_source_line(source_hash: u64, line: &str) -> u64 {
+    let line = line.trim();
+    if line.is_empty() {
+        return source_hash;
+    }
+    let mut hasher = FxHasher::default();
+    hasher.write_u64(source_hash);
+    hasher.write(line.as_bytes());
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+    use crate::tests::load_fixture;
+
+    #[test]
+    fn test_binary_cache() {
+        let input = FIXTURE
+            .replace(
+                r#"{"integer_literal":{"value":0}}"#,
+                r##"{"floating_point_literal":{"fp_value":"#3ff8000000000000"}}"##,
+            )
+            .replace(r#""waveform_element":"#, r#""waveform_element_2077":"#);
+        let options = LoadOptions { strict: false };
+        let ast = Ast::from_json_with_options(&mut input.as_bytes(), 1, options)
+            .unwrap()
+            .ast;
+
+        let mut cache = Vec::new();
+        ast.save_binary(&mut cache).unwrap();
+        let loaded = Ast::load_binary(cache.as_slice()).unwrap();
+        let id = |raw| GenericNodeId::from_raw(IdPrimitive::new(raw).unwrap());
+
+        assert_eq!(format!("{:?}", loaded.nodes), format!("{:?}", ast.nodes));
+        assert_eq!(loaded.locations, ast.locations);
+        assert_eq!(loaded.parents, ast.parents);
+        assert_eq!(loaded.source_hash(), ast.source_hash());
+        assert_eq!(loaded.first_id, ast.first_id);
+        assert_eq!(format!("{:?}", loaded.files), format!("{:?}", ast.files));
+        assert!(matches!(
+            id(12).get(&loaded),
+            Node::FloatingPointLiteral(FloatingPointLiteral { value }) if value.to_bits() == 1.5_f64.to_bits()
+        ));
+        assert!(matches!(
+            id(18).get(&loaded),
+            Node::Unknown { kind, raw } if kind == "waveform_element_2077" && raw["we_value"] == 19
+        ));
+        let (_, work) = loaded.single_library().unwrap();
+        let top = loaded
+            .lookup_entity_declaration(work, NormalizedIdentifier::new("top"))
+            .unwrap();
+        assert_eq!(loaded.lookup_architecture_bodies(top).len(), 1);
+    }
+
+    #[test]
+    fn test_binary_cache_rejects_stale() {
+        let ast = load_fixture();
+        let mut cache = Vec::new();
+        ast.save_binary(&mut cache).unwrap();
+
+        let truncated = Ast::load_binary(&cache[1..]).unwrap_err();
+        assert!(matches!(truncated, CacheError::NotACache));
+
+        let stale_header = |header: &CacheHeader<'_>| {
+            let mut stale = MAGIC.to_vec();
+            encode::write_named(&mut stale, header).unwrap();
+            Ast::load_binary(stale.as_slice()).unwrap_err()
+        };
+        let newer_format = stale_header(&CacheHeader {
+            format_version: FORMAT_VERSION + 1,
+            crate_version: Cow::Borrowed(env!("CARGO_PKG_VERSION")),
+            source_hash: ast.source_hash(),
+        });
+        assert!(matches!(
+            newer_format,
+            CacheError::Stale { format_version, .. } if format_version == FORMAT_VERSION + 1
+        ));
+        let old_crate = stale_header(&CacheHeader {
+            format_version: FORMAT_VERSION,
+            crate_version: Cow::Borrowed("0.0.0"),
+            source_hash: ast.source_hash(),
+        });
+        assert!(matches!(
+            old_crate,
+            CacheError::Stale { crate_version, .. } if crate_version == "0.0.0"
+        ));
+    }
+
+    #[test]
+    fn test_binary_cache_rejects_other_source() {
+        let ast = load_fixture();
+        let mut cache = Vec::new();
+        ast.save_binary(&mut cache).unwrap();
+
+        let source_hash = Ast::hash_json(&mut FIXTURE.as_bytes()).unwrap();
+        assert_eq!(source_hash, ast.source_hash());
+        Ast::load_binary_for_source(cache.as_slice(), source_hash).unwrap();
+
+        let reexported = FIXTURE.replace(r#"{"value":0}"#, r#"{"value":1}"#);
+        let reexported_hash = Ast::hash_json(&mut reexported.as_bytes()).unwrap();
+        assert_ne!(reexported_hash, source_hash);
+        let error = Ast::load_binary_for_source(cache.as_slice(), reexported_hash).unwrap_err();
+        assert!(matches!(
+            error,
+            CacheError::Outdated { source_hash: cached, expected }
+                if cached == source_hash && expected == reexported_hash
+        ));
+    }
+}
@@ -65,8 +65,8 @@ pub enum Mode {
     /// Mode was not determined (GHDL exports this as `"???"`).
     ///
     /// Can appear on interface constants when no mode was written and analysis
-    /// left the mode unspecified.
-    #[serde(rename = "???")]
+    /// left the mode unspecified. Lenient loading also maps unrecognized modes here.
+    #[serde(rename = "???", alias = "$unknown")]
     Unknown,
 }
 
@@ -353,21 +353,25 @@ impl<'de> Deserialize<'de> for InstantiationList {
     }
 }
 
-/// Sentinel node used when GHDL could not form a valid AST node.
-///
-/// A single global error node exists at [`Error::GLOBAL_ID`] and is also used as
-/// the named entity of unresolved names (see [`crate::NamedEntity::Unresolved`]).
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Error {}
+node_struct! {
+    /// Sentinel node used when GHDL could not form a valid AST node.
+    ///
+    /// A single global error node exists at [`Error::GLOBAL_ID`] and is also used as
+    /// the named entity of unresolved names (see [`crate::NamedEntity::Unresolved`]).
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct Error {}
+}
 
 impl Error {
     /// The ID of the global error node.
     pub const GLOBAL_ID: NodeId<Self> = NodeId(IdPrimitive::new(2).unwrap(), PhantomData);
 }
 
-/// Overload-resolution candidate list built during name analysis.
-///
-/// This is an analysis artifact; it is not needed for simulation of an already
-/// analyzed design.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct OverloadList {}
+node_struct! {
+    /// Overload-resolution candidate list built during name analysis.
+    ///
+    /// This is an analysis artifact; it is not needed for simulation of an already
+    /// analyzed design.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct OverloadList {}
+}
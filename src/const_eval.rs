@@ -0,0 +1,614 @@
+//! Evaluation of static expressions (LRM §9.4).
+//!
+//! Evaluates literals, enumeration literals, predefined operators and
+//! functions, qualified expressions, type conversions, positional aggregates
+//! and references to constants. Generics are static once their value is bound
+//! with [`Evaluator::bind_generic`]. Attributes, user-defined functions and
+//! named aggregates are reported as not static.
+
+use super::*;
+use crate::predefined::integer_to_real;
+use crate::predefined::real_to_integer;
+
+/// Maximum nesting of evaluated expressions, which also bounds cyclic constant definitions.
+const MAX_DEPTH: usize = 256;
+
+/// Error returned when evaluating a static expression fails.
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+pub enum EvalError {
+    /// The expression, or the given part of it, isn't static or isn't supported.
+    #[error("expression {node} is not static")]
+    NotStatic {
+        /// The offending node.
+        node: GenericNodeId,
+    },
+
+    /// The result of an operation or literal is out of range.
+    #[error("overflow evaluating {node}")]
+    Overflow {
+        /// The offending node.
+        node: GenericNodeId,
+    },
+
+    /// An operation divides by zero.
+    #[error("division by zero evaluating {node}")]
+    DivisionByZero {
+        /// The offending node.
+        node: GenericNodeId,
+    },
+
+    /// The operands of an operation don't fit its predefined definition.
+    #[error("invalid operands evaluating {node}")]
+    InvalidOperands {
+        /// The offending node.
+        node: GenericNodeId,
+    },
+
+    /// A node referenced by the expression is missing or has the wrong type.
+    #[error(transparent)]
+    Lookup(#[from] LookupNodeError),
+}
+
+/// Evaluator of static expressions, see [`Ast::evaluate_static`].
+pub struct Evaluator<'ast> {
+    ast: &'ast Ast,
+    generics: Map<NodeId<InterfaceConstantDeclaration>, Value>,
+}
+
+impl fmt::Debug for Evaluator<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Evaluator")
+            .field("generics", &self.generics)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'ast> Evaluator<'ast> {
+    /// Creates an evaluator without bound generics.
+    #[must_use]
+    pub fn new(ast: &'ast Ast) -> Self {
+        Self {
+            ast,
+            generics: Map::default(),
+        }
+    }
+
+    /// Binds the value of a generic constant, returning its previous value.
+    pub fn bind_generic(
+        &mut self,
+        generic: NodeId<InterfaceConstantDeclaration>,
+        value: Value,
+    ) -> Option<Value> {
+        self.generics.insert(generic, value)
+    }
+
+    /// Evaluates a static expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error pointing at the offending node if the expression isn't static, or if
+    /// its evaluation overflows or divides by zero.
+    pub fn evaluate(&self, expression: ExpressionNodeId) -> Result<Value, EvalError> {
+        self.eval(expression, None, 0)
+    }
+
+    /// Evaluates `id`, whose type is `expected` if known from the context.
+    fn eval(
+        &self,
+        id: ExpressionNodeId,
+        expected: Option<TypeAndSubtypeDefinitionNodeId>,
+        depth: usize,
+    ) -> Result<Value, EvalError> {
+        let ast = self.ast;
+        let node = GenericNodeId::from(id);
+        if depth > MAX_DEPTH {
+            return Err(EvalError::NotStatic { node });
+        }
+        let depth = depth + 1;
+        match id.try_get(ast)? {
+            Expression::IntegerLiteral(literal) => Ok(Value::Integer(literal.value)),
+            Expression::FloatingPointLiteral(literal) => Ok(Value::Real(literal.value)),
+            Expression::PhysicalIntLiteral(literal) => {
+                let unit = self.unit_value(literal.unit_name, depth)?;
+                literal
+                    .value
+                    .checked_mul(unit)
+                    .map(Value::Physical)
+                    .ok_or(EvalError::Overflow { node })
+            },
+            Expression::PhysicalFpLiteral(literal) => {
+                let unit = self.unit_value(literal.unit_name, depth)?;
+                real_to_integer(literal.value * integer_to_real(unit))
+                    .map(Value::Physical)
+                    .ok_or(EvalError::Overflow { node })
+            },
+            Expression::OverflowLiteral(_) => Err(EvalError::Overflow { node }),
+            Expression::CharacterLiteral(literal) => {
+                self.named_entity_value(literal.named_entity, node, depth)
+            },
+            Expression::SimpleName(name) => self.named_entity_value(name.named_entity, node, depth),
+            Expression::SelectedName(name) => {
+                self.named_entity_value(name.named_entity, node, depth)
+            },
+            Expression::StringLiteral(literal) => {
+                self.string_value(literal.value.as_str().as_bytes(), expected, node)
+            },
+            Expression::SimpleAggregate(aggregate) => aggregate
+                .simple_aggregate_list
+                .iter()
+                .map(|literal_id| Ok(Value::Enum(literal_id.try_get(ast)?.enum_pos)))
+                .collect::<Result<_, EvalError>>()
+                .map(Value::Array),
+            Expression::ParenthesisExpression(parenthesis) => {
+                self.eval(parenthesis.expression, Some(parenthesis.typ.into()), depth)
+            },
+            Expression::QualifiedExpression(qualified) => {
+                self.eval(qualified.expression, Some(qualified.typ.into()), depth)
+            },
+            Expression::TypeConversion(conversion) => {
+                let value = self.eval(conversion.expression, None, depth)?;
+                self.convert(value, conversion.typ.into(), node)
+            },
+            Expression::Unary(operator) => {
+                self.call(operator.implementation, &[operator.operand], node, depth)
+            },
+            Expression::Binary(operator) => self.call(
+                operator.implementation,
+                &[operator.left, operator.right],
+                node,
+                depth,
+            ),
+            Expression::FunctionCall(call) => {
+                let actuals = call
+                    .parameter_associations
+                    .iter()
+                    .map(|association_id| match association_id.try_get(ast)? {
+                        AssociationElement::ByExpression(association) => Ok(association.actual),
+                        _ => Err(EvalError::NotStatic {
+                            node: association_id.into(),
+                        }),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                self.call(call.implementation, &actuals, node, depth)
+            },
+            Expression::Aggregate(aggregate) => self.aggregate(aggregate, expected, node, depth),
+            _ => Err(EvalError::NotStatic { node }),
+        }
+    }
+
+    /// Applies the predefined function `implementation` to the values of `operands`.
+    fn call(
+        &self,
+        implementation: FunctionImplementationNodeId,
+        operands: &[ExpressionNodeId],
+        node: GenericNodeId,
+        depth: usize,
+    ) -> Result<Value, EvalError> {
+        let ast = self.ast;
+        let FunctionImplementation::Function(function) = implementation.try_get(ast)? else {
+            return Err(EvalError::NotStatic { node });
+        };
+        let Some(definition) = function.implicit_definition else {
+            return Err(EvalError::NotStatic { node });
+        };
+        let values = operands
+            .iter()
+            .enumerate()
+            .map(|(index, &operand)| {
+                let expected =
+                    function
+                        .interface_declarations
+                        .get(index)
+                        .and_then(|parameter_id| match parameter_id.try_get(ast).ok()? {
+                            InterfaceDeclaration::Constant(parameter) => Some(parameter.typ.into()),
+                            _ => None,
+                        });
+                self.eval(operand, expected, depth)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        definition.apply(&values).map_err(|error| match error {
+            PredefinedError::Unsupported => EvalError::NotStatic { node },
+            PredefinedError::Overflow => EvalError::Overflow { node },
+            PredefinedError::DivisionByZero => EvalError::DivisionByZero { node },
+            PredefinedError::InvalidOperands => EvalError::InvalidOperands { node },
+        })
+    }
+
+    /// Returns the value of a name denoting `named_entity`.
+    fn named_entity_value(
+        &self,
+        named_entity: NamedEntityNodeId,
+        node: GenericNodeId,
+        depth: usize,
+    ) -> Result<Value, EvalError> {
+        let ast = self.ast;
+        match named_entity.try_get(ast)? {
+            NamedEntity::EnumerationLiteral(literal) => Ok(Value::Enum(literal.enum_pos)),
+            NamedEntity::ConstantDeclaration(constant) => {
+                // The value of a deferred constant is given by its full declaration.
+                let default_value = constant.default_value.or_else(|| {
+                    constant
+                        .deferred_declaration
+                        .and_then(|full_id| full_id.try_get(ast).ok()?.default_value)
+                });
+                match default_value {
+                    Some(default_value) => {
+                        self.eval(default_value, Some(constant.typ.into()), depth)
+                    },
+                    None => Err(EvalError::NotStatic { node }),
+                }
+            },
+            NamedEntity::InterfaceConstantDeclaration(_) => self
+                .generics
+                .get(&named_entity.downcast())
+                .cloned()
+                .ok_or(EvalError::NotStatic { node }),
+            NamedEntity::UnitDeclaration(_) => self
+                .unit_multiplier(named_entity.downcast(), depth)
+                .map(Value::Physical),
+            _ => Err(EvalError::NotStatic { node }),
+        }
+    }
+
+    /// Returns the value of the unit denoted by `unit_name`, in multiples of the primary unit.
+    fn unit_value(&self, unit_name: NameNodeId, depth: usize) -> Result<i64, EvalError> {
+        let node = GenericNodeId::from(unit_name);
+        let unit_id = unit_name
+            .try_get(self.ast)?
+            .named_entity()
+            .ok_or(EvalError::NotStatic { node })?
+            .try_downcast(self.ast)?;
+        self.unit_multiplier(unit_id, depth)
+    }
+
+    /// Returns the value of a unit in multiples of the primary unit of its type.
+    fn unit_multiplier(
+        &self,
+        unit_id: NodeId<UnitDeclaration>,
+        depth: usize,
+    ) -> Result<i64, EvalError> {
+        let ast = self.ast;
+        let node = GenericNodeId::from(unit_id);
+        if depth > MAX_DEPTH {
+            return Err(EvalError::NotStatic { node });
+        }
+        let unit = unit_id.try_get(ast)?;
+        let (value, unit_name) = match unit.physical_literal.try_get(ast)? {
+            PhysicalLiteral::PhysicalInt(literal) => (Ok(literal.value), literal.unit_name),
+            PhysicalLiteral::PhysicalFp(literal) => (Err(literal.value), literal.unit_name),
+        };
+        let base_unit_id: NodeId<UnitDeclaration> = unit_name
+            .try_get(ast)?
+            .named_entity()
+            .ok_or(EvalError::NotStatic { node })?
+            .try_downcast(ast)?;
+        // The primary unit is defined as one of itself.
+        let base_unit = if base_unit_id == unit_id {
+            1
+        } else {
+            self.unit_multiplier(base_unit_id, depth + 1)?
+        };
+        match value {
+            Ok(value) => value.checked_mul(base_unit),
+            Err(value) => real_to_integer(value * integer_to_real(base_unit)),
+        }
+        .ok_or(EvalError::Overflow { node })
+    }
+
+    /// Returns the value of a string literal of the array type `expected`.
+    fn string_value(
+        &self,
+        string: &[u8],
+        expected: Option<TypeAndSubtypeDefinitionNodeId>,
+        node: GenericNodeId,
+    ) -> Result<Value, EvalError> {
+        let ast = self.ast;
+        let not_static = || EvalError::NotStatic { node };
+        let array_type = expected
+            .and_then(|expected| ast.base_type(expected).ok())
+            .ok_or_else(not_static)?;
+        let TypeAndSubtypeDefinition::ArrayType(array) = array_type.try_get(ast)? else {
+            return Err(not_static());
+        };
+        let element_type = ast
+            .base_type(array.element_subtype)
+            .map_err(|_ignore| not_static())?;
+        let TypeAndSubtypeDefinition::EnumerationType(enumeration) = element_type.try_get(ast)?
+        else {
+            return Err(not_static());
+        };
+        let literals = enumeration
+            .enumeration_literal_list
+            .iter()
+            .map(|literal_id| literal_id.try_get(ast))
+            .collect::<Result<Vec<_>, _>>()?;
+        string
+            .iter()
+            .map(|&byte| {
+                let character = format!("'{}'", char::from(byte));
+                literals
+                    .iter()
+                    .find(|literal| *literal.identifier.normalized == *character)
+                    .map(|literal| Value::Enum(literal.enum_pos))
+                    .ok_or(EvalError::InvalidOperands { node })
+            })
+            .collect::<Result<_, _>>()
+            .map(Value::Array)
+    }
+
+    /// Returns the value of a positional array or record aggregate.
+    fn aggregate(
+        &self,
+        aggregate: &Aggregate,
+        expected: Option<TypeAndSubtypeDefinitionNodeId>,
+        node: GenericNodeId,
+        depth: usize,
+    ) -> Result<Value, EvalError> {
+        let ast = self.ast;
+        let not_static = || EvalError::NotStatic { node };
+        let typ = aggregate
+            .typ
+            .map(TypeAndSubtypeDefinitionNodeId::from)
+            .or(expected)
+            .ok_or_else(not_static)?;
+        let base_type = ast.base_type(typ).map_err(|_ignore| not_static())?;
+        let mut values = Vec::with_capacity(aggregate.associations.len());
+        match base_type.try_get(ast)? {
+            TypeAndSubtypeDefinition::ArrayType(array) => {
+                for &choice_id in &aggregate.associations {
+                    let Choice::ByNone(choice) = choice_id.try_get(ast)? else {
+                        return Err(EvalError::NotStatic {
+                            node: choice_id.into(),
+                        });
+                    };
+                    if choice.element_type_flag {
+                        values.push(self.eval(
+                            choice.expression,
+                            Some(array.element_subtype.into()),
+                            depth,
+                        )?);
+                    } else {
+                        match self.eval(choice.expression, Some(typ), depth)? {
+                            Value::Array(elements) => values.extend(elements),
+                            _ => return Err(EvalError::InvalidOperands { node }),
+                        }
+                    }
+                }
+                Ok(Value::Array(values))
+            },
+            TypeAndSubtypeDefinition::RecordType(record) => {
+                if aggregate.associations.len() != record.elements_declaration_list.len() {
+                    return Err(EvalError::InvalidOperands { node });
+                }
+                for (&choice_id, element_id) in aggregate
+                    .associations
+                    .iter()
+                    .zip(&record.elements_declaration_list)
+                {
+                    let Choice::ByNone(choice) = choice_id.try_get(ast)? else {
+                        return Err(EvalError::NotStatic {
+                            node: choice_id.into(),
+                        });
+                    };
+                    let element_type = element_id.try_get(ast)?.typ;
+                    values.push(self.eval(choice.expression, element_type, depth)?);
+                }
+                Ok(Value::Record(values))
+            },
+            _ => Err(not_static()),
+        }
+    }
+
+    /// Converts `value` to the type `target` (LRM §9.3.6).
+    fn convert(
+        &self,
+        value: Value,
+        target: TypeAndSubtypeDefinitionNodeId,
+        node: GenericNodeId,
+    ) -> Result<Value, EvalError> {
+        let ast = self.ast;
+        let base_type = ast
+            .base_type(target)
+            .map_err(|_ignore| EvalError::NotStatic { node })?;
+        match (value, base_type.try_get(ast)?) {
+            (Value::Integer(value), TypeAndSubtypeDefinition::FloatingType(_)) => {
+                Ok(Value::Real(integer_to_real(value)))
+            },
+            (Value::Real(value), TypeAndSubtypeDefinition::IntegerType(_)) => {
+                real_to_integer(value)
+                    .map(Value::Integer)
+                    .ok_or(EvalError::Overflow { node })
+            },
+            (value, _) => Ok(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+
+    /// Chunk following [`FIXTURE`] with a package of constants, a generic and implicit operators:
+    ///
+    /// ```vhdl
+    /// package pkg is
+    ///   constant c : integer := 40 + g;  -- g is a generic
+    ///   constant d : integer := c / 0;
+    ///   constant e : integer := clk;
+    /// end package;
+    /// ```
+    const CONSTANTS_CHUNK: &str = r#"{"first_id":20,"last_id":38,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"constants.vhd","start":20,"end":39}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"package_declaration":{"id":23,"identifier":["pkg",null],"parent":22,"declarations":[24,27,31,32,36,37]}}
+{"function_declaration":{"identifier":["\"+\"",null],"implicit_definition":"IIR_PREDEFINED_INTEGER_PLUS","interface_declarations":[25,26],"return_type":10}}
+{"interface_constant_declaration":{"identifier":["left",null],"type":10}}
+{"interface_constant_declaration":{"identifier":["right",null],"type":10}}
+{"constant_declaration":{"identifier":["c",null],"type":10,"default_value":28}}
+{"binary_operator":{"kind":"+","left":29,"right":30,"implementation":24}}
+{"integer_literal":{"value":40}}
+{"simple_name":{"identifier":["g",null],"named_entity":31}}
+{"interface_constant_declaration":{"identifier":["g",null],"type":10}}
+{"constant_declaration":{"identifier":["d",null],"type":10,"default_value":33}}
+{"binary_operator":{"kind":"/","left":34,"right":35,"implementation":36}}
+{"simple_name":{"identifier":["c",null],"named_entity":27}}
+{"integer_literal":{"value":0}}
+{"function_declaration":{"identifier":["\"/\"",null],"implicit_definition":"IIR_PREDEFINED_INTEGER_DIV","return_type":10}}
+{"constant_declaration":{"identifier":["e",null],"type":10,"default_value":38}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9}}
+
+"#;
+
+    /// Chunk following [`FIXTURE`] with enumeration, physical and floating point types, and
+    /// expressions using them:
+    ///
+    /// ```vhdl
+    /// package pkg is
+    ///   type state is (idle, busy);
+    ///   type time_t is range … units fs; ps = 1000 fs; end units;
+    ///   type my_real is range …;
+    ///   type my_int is range …;
+    ///   -- implicit function "+" (left, right : integer) return integer;
+    /// end package;
+    ///
+    /// 5 ps;  busy;  state'(idle);  my_real(3);  my_int(2.5);  1 + clk;
+    /// ```
+    const TYPES_CHUNK: &str = r##"{"first_id":20,"last_id":55,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"types.vhd","start":20,"end":56}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"package_declaration":{"id":23,"identifier":["pkg",null],"parent":22,"declarations":[24,28,46,51,52]}}
+{"type_declaration":{"identifier":["state",null],"type_definition":25}}
+{"enumeration_type_definition":{"enumeration_literal_list":[26,27]}}
+{"enumeration_literal":{"enum_pos":0,"identifier":["idle",null]}}
+{"enumeration_literal":{"enum_pos":1,"identifier":["busy",null]}}
+{"type_declaration":{"identifier":["time_t",null],"type_definition":29}}
+{"physical_type_definition":{"units":[30,33]}}
+{"unit_declaration":{"identifier":["fs",null],"physical_literal":31}}
+{"physical_int_literal":{"value":1,"unit_name":32}}
+{"simple_name":{"identifier":["fs",null],"named_entity":30}}
+{"unit_declaration":{"identifier":["ps",null],"physical_literal":34}}
+{"physical_int_literal":{"value":1000,"unit_name":35}}
+{"simple_name":{"identifier":["fs",null],"named_entity":30}}
+{"physical_int_literal":{"value":5,"unit_name":37}}
+{"simple_name":{"identifier":["ps",null],"named_entity":33}}
+{"simple_name":{"identifier":["busy",null],"named_entity":27}}
+{"qualified_expression":{"type_mark":40,"expression":41,"type":25}}
+{"simple_name":{"identifier":["state",null],"named_entity":24}}
+{"simple_name":{"identifier":["idle",null],"named_entity":26}}
+{"type_conversion":{"type_mark":43,"expression":44,"type":45}}
+{"simple_name":{"identifier":["my_real",null],"named_entity":46}}
+{"integer_literal":{"value":3}}
+{"floating_type_definition":{}}
+{"type_declaration":{"identifier":["my_real",null],"type_definition":45}}
+{"type_conversion":{"type_mark":48,"expression":49,"type":50}}
+{"simple_name":{"identifier":["my_int",null],"named_entity":51}}
+{"floating_point_literal":{"fp_value":"#4004000000000000"}}
+{"integer_type_definition":{}}
+{"type_declaration":{"identifier":["my_int",null],"type_definition":50}}
+{"function_declaration":{"identifier":["\"+\"",null],"implicit_definition":"IIR_PREDEFINED_INTEGER_PLUS","return_type":10}}
+{"binary_operator":{"kind":"+","left":54,"right":55,"implementation":52}}
+{"integer_literal":{"value":1}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9}}
+
+"##;
+
+    fn load_chunk(chunk: &str) -> Ast {
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut chunk.as_bytes(), next_line_number)
+            .unwrap();
+        builder.finish()
+    }
+
+    fn id(raw: u32) -> IdPrimitive {
+        IdPrimitive::new(raw).unwrap()
+    }
+
+    fn expression(raw: u32) -> ExpressionNodeId {
+        ExpressionNodeId::from(id(raw))
+    }
+
+    #[test]
+    fn test_evaluate_constants() {
+        let ast = load_chunk(CONSTANTS_CHUNK);
+        assert_eq!(
+            ast.evaluate_static(expression(29)).unwrap(),
+            Value::Integer(40)
+        );
+        // Generics are only static once bound.
+        let error = ast.evaluate_static(expression(28)).unwrap_err();
+        assert!(matches!(error, EvalError::NotStatic { node } if node.to_raw() == id(30)));
+
+        let mut evaluator = Evaluator::new(&ast);
+        assert_eq!(
+            evaluator.bind_generic(NodeId::from_raw(id(31)), Value::Integer(2)),
+            None
+        );
+        assert_eq!(
+            evaluator.evaluate(expression(28)).unwrap(),
+            Value::Integer(42)
+        );
+        assert_eq!(
+            evaluator.evaluate(expression(34)).unwrap(),
+            Value::Integer(42)
+        );
+        assert!(matches!(
+            evaluator.evaluate(expression(33)).unwrap_err(),
+            EvalError::DivisionByZero { node } if node.to_raw() == id(33)));
+    }
+
+    #[test]
+    fn test_evaluate_enumeration_literal() {
+        let ast = load_chunk(TYPES_CHUNK);
+        assert_eq!(ast.evaluate_static(expression(38)).unwrap(), Value::Enum(1));
+        assert_eq!(ast.evaluate_static(expression(41)).unwrap(), Value::Enum(0));
+    }
+
+    #[test]
+    fn test_evaluate_physical_literal() {
+        let ast = load_chunk(TYPES_CHUNK);
+        assert_eq!(
+            ast.evaluate_static(expression(36)).unwrap(),
+            Value::Physical(5000)
+        );
+        // A unit name alone denotes one of that unit.
+        assert_eq!(
+            ast.evaluate_static(expression(37)).unwrap(),
+            Value::Physical(1000)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_qualified_expression_and_type_conversion() {
+        let ast = load_chunk(TYPES_CHUNK);
+        assert_eq!(ast.evaluate_static(expression(39)).unwrap(), Value::Enum(0));
+        assert_eq!(
+            ast.evaluate_static(expression(42)).unwrap(),
+            Value::Real(3.0)
+        );
+        // Conversions to integer types round to the nearest integer.
+        assert_eq!(
+            ast.evaluate_static(expression(47)).unwrap(),
+            Value::Integer(3)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_signal_is_not_static() {
+        let constants = load_chunk(CONSTANTS_CHUNK);
+        assert!(matches!(
+            constants.evaluate_static(expression(38)).unwrap_err(),
+            EvalError::NotStatic { node } if node.to_raw() == id(38)));
+
+        // The error points at the signal reference, not at the enclosing operator.
+        let types = load_chunk(TYPES_CHUNK);
+        assert!(matches!(
+            types.evaluate_static(expression(53)).unwrap_err(),
+            EvalError::NotStatic { node } if node.to_raw() == id(55)));
+    }
+}
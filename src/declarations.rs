@@ -18,7 +18,7 @@ subset_declaration!(Declaration DeclarationOwned DeclarationNodeId {
     NonObjectAlias(NonObjectAliasDeclaration),
 
     SuspendState(SuspendStateDeclaration),
-});
+} overlaps [ObjectDeclaration, FunctionImplementation, ProcedureImplementation, NamedEntity]);
 
 subset_declaration!(InterfaceDeclaration InterfaceDeclarationOwned InterfaceDeclarationNodeId {
     Constant(InterfaceConstantDeclaration),
@@ -32,7 +32,7 @@ subset_declaration!(InterfaceDeclaration InterfaceDeclarationOwned InterfaceDecl
     Package(InterfacePackageDeclaration),
     Function(InterfaceFunctionDeclaration),
     Procedure(InterfaceProcedureDeclaration),
-});
+} overlaps [ObjectDeclaration, FunctionImplementation, ProcedureImplementation, NamedEntity]);
 
 subset_declaration!(InterfaceObjectDeclaration InterfaceObjectDeclarationOwned InterfaceObjectDeclarationNodeId {
     Constant(InterfaceConstantDeclaration),
@@ -40,14 +40,25 @@ subset_declaration!(InterfaceObjectDeclaration InterfaceObjectDeclarationOwned I
     Signal(InterfaceSignalDeclaration),
     View(InterfaceViewDeclaration),
     File(InterfaceFileDeclaration),
-});
+} subset_of [
+    InterfaceDeclaration InterfaceDeclarationNodeId,
+    ObjectDeclaration ObjectDeclarationNodeId,
+    NamedEntity NamedEntityNodeId,
+] overlaps [
+    PortInterfaceDeclaration,
+]);
 
 subset_declaration!(PortInterfaceDeclaration PortInterfaceDeclarationOwned PortInterfaceDeclarationNodeId {
     Signal(InterfaceSignalDeclaration),
     View(InterfaceViewDeclaration),
     Terminal(InterfaceTerminalDeclaration),
     Quantity(InterfaceQuantityDeclaration),
-});
+} subset_of [
+    InterfaceDeclaration InterfaceDeclarationNodeId,
+] overlaps [
+    ObjectDeclaration,
+    NamedEntity,
+]);
 
 subset_declaration!(ObjectDeclaration ObjectDeclarationOwned ObjectDeclarationNodeId {
     ObjectAlias(ObjectAliasDeclaration),
@@ -62,433 +73,489 @@ subset_declaration!(ObjectDeclaration ObjectDeclarationOwned ObjectDeclarationNo
     InterfaceSignal(InterfaceSignalDeclaration),
     InterfaceView(InterfaceViewDeclaration),
     InterfaceFile(InterfaceFileDeclaration),
-});
+} overlaps [NamedEntity]);
 
 subset_declaration!(FunctionImplementation FunctionImplementationOwned FunctionImplementationNodeId {
     Function(FunctionDeclaration),
     InterfaceFunction(InterfaceFunctionDeclaration),
     FunctionInstantiation(FunctionInstantiationDeclaration),
-});
+} subset_of [NamedEntity NamedEntityNodeId]);
 
 subset_declaration!(ProcedureImplementation ProcedureImplementationOwned ProcedureImplementationNodeId {
     Procedure(ProcedureDeclaration),
     InterfaceProcedure(InterfaceProcedureDeclaration),
     ProcedureInstantiation(ProcedureInstantiationDeclaration),
-});
+} subset_of [NamedEntity NamedEntityNodeId]);
 
 subset_declaration!(SubprogramBody SubprogramBodyOwned SubprogramBodyNodeId {
     Function(FunctionBody),
     Procedure(ProcedureBody),
-});
+} subset_of [Declaration DeclarationNodeId]);
 
-/// Subtype declaration (`subtype … is …`).
-///
-/// ```vhdl
-/// subtype byte is std_logic_vector(7 downto 0);
-/// subtype natural is integer range 0 to integer'high;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SubtypeDeclaration {
-    /// Subtype identifier.
-    pub identifier: Identifier,
-    /// Subtype indication after the `is` keyword.
-    pub subtype_indication: SubtypeDefinitionNodeId,
+node_struct! {
+    /// Subtype declaration (`subtype … is …`).
+    ///
+    /// ```vhdl
+    /// subtype byte is std_logic_vector(7 downto 0);
+    /// subtype natural is integer range 0 to integer'high;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SubtypeDeclaration {
+        /// Subtype identifier.
+        pub identifier: Identifier,
+        /// Subtype indication after the `is` keyword.
+        pub subtype_indication: SubtypeDefinitionNodeId,
+    }
 }
 
-/// Anonymous type declaration synthesized with a first subtype.
-///
-/// Integer/floating/physical/array type declarations are represented as an
-/// anonymous base type plus a named subtype; this node holds that pairing.
-///
-/// ```vhdl
-/// type my_int is range 0 to 255;
-/// -- AnonymousTypeDeclaration owns the IntegerTypeDefinition;
-/// -- a SubtypeDeclaration / IntegerSubtypeDefinition is the named object.
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AnonymousTypeDeclaration {
-    /// Anonymous base type definition.
-    pub type_definition: AnonymousTypeDefinitionNodeId,
-    /// First subtype of the anonymous type, when present.
-    pub subtype_definition: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Anonymous type declaration synthesized with a first subtype.
+    ///
+    /// Integer/floating/physical/array type declarations are represented as an
+    /// anonymous base type plus a named subtype; this node holds that pairing.
+    ///
+    /// ```vhdl
+    /// type my_int is range 0 to 255;
+    /// -- AnonymousTypeDeclaration owns the IntegerTypeDefinition;
+    /// -- a SubtypeDeclaration / IntegerSubtypeDefinition is the named object.
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AnonymousTypeDeclaration {
+        /// Anonymous base type definition.
+        pub type_definition: AnonymousTypeDefinitionNodeId,
+        /// First subtype of the anonymous type, when present.
+        pub subtype_definition: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Type declaration (`type … is …` / incomplete `type …;`).
-///
-/// ```vhdl
-/// type state_t is (IDLE, RUN, DONE);
-/// type ptr_t is access token_t;
-/// type token_t;  -- incomplete
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct TypeDeclaration {
-    /// Type identifier.
-    pub identifier: Identifier,
-    /// Type definition (`is …`), or incomplete when deferred.
-    pub type_definition: TypeDefinitionNodeId,
+node_struct! {
+    /// Type declaration (`type … is …` / incomplete `type …;`).
+    ///
+    /// ```vhdl
+    /// type state_t is (IDLE, RUN, DONE);
+    /// type ptr_t is access token_t;
+    /// type token_t;  -- incomplete
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct TypeDeclaration {
+        /// Type identifier.
+        pub identifier: Identifier,
+        /// Type definition (`is …`), or incomplete when deferred.
+        pub type_definition: TypeDefinitionNodeId,
+    }
 }
 
-/// Attribute declaration (`attribute … : …`).
-///
-/// Declares an attribute designator of a given type; values are supplied later
-/// by attribute specifications.
-///
-/// ```vhdl
-/// attribute keep : boolean;
-/// attribute max_delay : time;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AttributeDeclaration {
-    /// Attribute identifier.
-    pub identifier: Identifier,
-    /// Analyzed attribute type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Type mark from the declaration.
-    pub type_mark: Option<NameNodeId>,
+node_struct! {
+    /// Attribute declaration (`attribute … : …`).
+    ///
+    /// Declares an attribute designator of a given type; values are supplied later
+    /// by attribute specifications.
+    ///
+    /// ```vhdl
+    /// attribute keep : boolean;
+    /// attribute max_delay : time;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AttributeDeclaration {
+        /// Attribute identifier.
+        pub identifier: Identifier,
+        /// Analyzed attribute type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Type mark from the declaration.
+        pub type_mark: Option<NameNodeId>,
+    }
 }
 
-/// Interface file declaration in a parameter list (`file f : …`).
-///
-/// ```vhdl
-/// procedure dump(file f : text);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceFileDeclaration {
-    /// File parameter identifier.
-    pub identifier: Identifier,
-    /// Analyzed file type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Subtype indication of the file type mark.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Mode when explicitly written.
-    pub mode: Option<Mode>,
+node_struct! {
+    /// Interface file declaration in a parameter list (`file f : …`).
+    ///
+    /// ```vhdl
+    /// procedure dump(file f : text);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceFileDeclaration {
+        /// File parameter identifier.
+        pub identifier: Identifier,
+        /// Analyzed file type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Subtype indication of the file type mark.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Mode when explicitly written.
+        pub mode: Option<Mode>,
+    }
 }
 
-/// Secondary unit declaration inside a physical type (`units … end units`).
-///
-/// ```vhdl
-/// type time is range … units
-///   fs;
-///   ps = 1000 fs;
-/// end units;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct UnitDeclaration {
-    /// Unit identifier.
-    pub identifier: Option<Identifier>,
-    /// Physical literal giving this unit in terms of a previously declared unit
-    /// (primary units still carry a literal node in GHDL's representation).
-    pub physical_literal: PhysicalLiteralNodeId,
-    /// Analyzed physical type of the unit.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Secondary unit declaration inside a physical type (`units … end units`).
+    ///
+    /// ```vhdl
+    /// type time is range … units
+    ///   fs;
+    ///   ps = 1000 fs;
+    /// end units;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct UnitDeclaration {
+        /// Unit identifier.
+        pub identifier: Option<Identifier>,
+        /// Physical literal giving this unit in terms of a previously declared unit
+        /// (primary units still carry a literal node in GHDL's representation).
+        pub physical_literal: PhysicalLiteralNodeId,
+        /// Analyzed physical type of the unit.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Interface constant declaration (generic or `constant` parameter).
-///
-/// The identifier may be omitted in some GHDL-internal / interface positions;
-/// keep [`identifier`](Self::identifier) as `Option`.
-///
-/// ```vhdl
-/// generic (WIDTH : positive := 8);
-/// procedure p(constant n : natural);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceConstantDeclaration {
-    /// Constant interface identifier (`None` in rare unnamed positions).
-    pub identifier: Option<Identifier>,
-
-    /// Analyzed subtype of the interface constant.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-    /// Subtype indication as written / analyzed.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Mode (`in` by default for constants; may be unknown).
-    pub mode: Option<Mode>,
-    /// Default expression when present.
-    pub default_value: Option<ExpressionNodeId>,
+node_struct! {
+    /// Interface constant declaration (generic or `constant` parameter).
+    ///
+    /// The identifier may be omitted in some GHDL-internal / interface positions;
+    /// keep [`identifier`](Self::identifier) as `Option`.
+    ///
+    /// ```vhdl
+    /// generic (WIDTH : positive := 8);
+    /// procedure p(constant n : natural);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceConstantDeclaration {
+        /// Constant interface identifier (`None` in rare unnamed positions).
+        pub identifier: Option<Identifier>,
+
+        /// Analyzed subtype of the interface constant.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+        /// Subtype indication as written / analyzed.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Mode (`in` by default for constants; may be unknown).
+        pub mode: Option<Mode>,
+        /// Default expression when present.
+        pub default_value: Option<ExpressionNodeId>,
+    }
 }
 
-/// Constant declaration (`constant … : … := …`).
-///
-/// Deferred constants omit the value in a package declaration and complete it
-/// in the package body ([`deferred_declaration`](Self::deferred_declaration)).
-///
-/// ```vhdl
-/// constant WIDTH : natural := 8;
-/// -- package: constant C : integer;        -- deferred
-/// -- body:    constant C : integer := 42;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ConstantDeclaration {
-    /// Constant identifier.
-    pub identifier: Identifier,
-
-    /// Analyzed subtype of the constant.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-    /// Subtype indication as written / analyzed.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Initial / deferred value expression.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Whether this is the deferred declaration (no value yet).
-    #[serde(default)]
-    pub deferred_declaration_flag: bool,
-    /// Link between deferred declaration and its completion.
-    pub deferred_declaration: Option<NodeId<ConstantDeclaration>>,
+node_struct! {
+    /// Constant declaration (`constant … : … := …`).
+    ///
+    /// Deferred constants omit the value in a package declaration and complete it
+    /// in the package body ([`deferred_declaration`](Self::deferred_declaration)).
+    ///
+    /// ```vhdl
+    /// constant WIDTH : natural := 8;
+    /// -- package: constant C : integer;        -- deferred
+    /// -- body:    constant C : integer := 42;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ConstantDeclaration {
+        /// Constant identifier.
+        pub identifier: Identifier,
+
+        /// Analyzed subtype of the constant.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+        /// Subtype indication as written / analyzed.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Initial / deferred value expression.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Whether this is the deferred declaration (no value yet).
+        #[serde(default)]
+        pub deferred_declaration_flag: bool,
+        /// Link between deferred declaration and its completion.
+        @reference
+        pub deferred_declaration: Option<NodeId<ConstantDeclaration>>,
+    }
 }
 
-/// Interface signal declaration (port or `signal` parameter).
-///
-/// ```vhdl
-/// port (clk : in std_logic; q : out std_logic := '0');
-/// procedure sample(signal s : in std_logic);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceSignalDeclaration {
-    /// Signal interface identifier.
-    pub identifier: Identifier,
-
-    /// Analyzed subtype of the interface signal.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-
-    /// Port / parameter mode.
-    pub mode: Mode,
-    /// Subtype indication as written / analyzed.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Default expression when present.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Whether this is a guarded signal interface.
-    #[serde(default)]
-    pub guarded_signal_flag: bool,
-    /// `register` / `bus` kind when guarded.
-    pub signal_kind: Option<SignalKind>,
+node_struct! {
+    /// Interface signal declaration (port or `signal` parameter).
+    ///
+    /// ```vhdl
+    /// port (clk : in std_logic; q : out std_logic := '0');
+    /// procedure sample(signal s : in std_logic);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceSignalDeclaration {
+        /// Signal interface identifier.
+        pub identifier: Identifier,
+
+        /// Analyzed subtype of the interface signal.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+
+        /// Port / parameter mode.
+        pub mode: Mode,
+        /// Subtype indication as written / analyzed.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Default expression when present.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Whether this is a guarded signal interface.
+        #[serde(default)]
+        pub guarded_signal_flag: bool,
+        /// `register` / `bus` kind when guarded.
+        pub signal_kind: Option<SignalKind>,
+    }
 }
 
-/// Signal declaration (`signal … : …`).
-///
-/// ```vhdl
-/// signal clk : std_logic := '0';
-/// signal bus_s : std_logic bus;
-/// signal reg_s : std_logic register;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SignalDeclaration {
-    /// Signal identifier.
-    pub identifier: Identifier,
-
-    /// Analyzed subtype of the signal.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-    /// Subtype indication as written / analyzed.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Default / initial value expression.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Whether this is a guarded signal (`bus` / `register`).
-    #[serde(default)]
-    pub guarded_signal_flag: bool,
-    /// `register` / `bus` kind (meaningful when guarded).
-    pub signal_kind: Option<SignalKind>,
+node_struct! {
+    /// Signal declaration (`signal … : …`).
+    ///
+    /// ```vhdl
+    /// signal clk : std_logic := '0';
+    /// signal bus_s : std_logic bus;
+    /// signal reg_s : std_logic register;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SignalDeclaration {
+        /// Signal identifier.
+        pub identifier: Identifier,
+
+        /// Analyzed subtype of the signal.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+        /// Subtype indication as written / analyzed.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Default / initial value expression.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Whether this is a guarded signal (`bus` / `register`).
+        #[serde(default)]
+        pub guarded_signal_flag: bool,
+        /// `register` / `bus` kind (meaningful when guarded).
+        pub signal_kind: Option<SignalKind>,
+    }
 }
 
-/// Interface variable declaration (`variable` parameter).
-///
-/// ```vhdl
-/// procedure incr(variable x : inout integer);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceVariableDeclaration {
-    /// Variable interface identifier.
-    pub identifier: Identifier,
-
-    /// Analyzed subtype of the interface variable.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-
-    /// Default expression when present.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Subtype indication as written / analyzed.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Mode of the variable parameter.
-    pub mode: Option<Mode>,
+node_struct! {
+    /// Interface variable declaration (`variable` parameter).
+    ///
+    /// ```vhdl
+    /// procedure incr(variable x : inout integer);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceVariableDeclaration {
+        /// Variable interface identifier.
+        pub identifier: Identifier,
+
+        /// Analyzed subtype of the interface variable.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+
+        /// Default expression when present.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Subtype indication as written / analyzed.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Mode of the variable parameter.
+        pub mode: Option<Mode>,
+    }
 }
 
-/// Variable declaration (`variable …` / `shared variable …`).
-///
-/// ```vhdl
-/// variable i : integer := 0;
-/// shared variable counter : shared_counter;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct VariableDeclaration {
-    /// Variable identifier.
-    pub identifier: Identifier,
-
-    /// Analyzed subtype of the variable.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-
-    /// Initial value expression.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Subtype indication as written / analyzed.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Whether this is a `shared variable`.
-    #[serde(default)]
-    pub shared_flag: bool,
+node_struct! {
+    /// Variable declaration (`variable …` / `shared variable …`).
+    ///
+    /// ```vhdl
+    /// variable i : integer := 0;
+    /// shared variable counter : shared_counter;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct VariableDeclaration {
+        /// Variable identifier.
+        pub identifier: Identifier,
+
+        /// Analyzed subtype of the variable.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+
+        /// Initial value expression.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Subtype indication as written / analyzed.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Whether this is a `shared variable`.
+        #[serde(default)]
+        pub shared_flag: bool,
+    }
 }
 
-/// Non-object alias declaration (`alias … is …` for types, subprograms, …).
-///
-/// Object aliases use [`ObjectAliasDeclaration`] instead.
-///
-/// ```vhdl
-/// alias my_vec is std_logic_vector;
-/// alias "+" is work.pkg."+" [integer, integer return integer];
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct NonObjectAliasDeclaration {
-    /// Alias identifier.
-    pub identifier: Identifier,
-    /// Name being aliased.
-    pub name: Option<NameNodeId>,
-    /// Optional signature selecting an overloaded subprogram.
-    pub alias_signature: Option<NodeId<Signature>>,
+node_struct! {
+    /// Non-object alias declaration (`alias … is …` for types, subprograms, …).
+    ///
+    /// Object aliases use [`ObjectAliasDeclaration`] instead.
+    ///
+    /// ```vhdl
+    /// alias my_vec is std_logic_vector;
+    /// alias "+" is work.pkg."+" [integer, integer return integer];
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct NonObjectAliasDeclaration {
+        /// Alias identifier.
+        pub identifier: Identifier,
+        /// Name being aliased.
+        pub name: Option<NameNodeId>,
+        /// Optional signature selecting an overloaded subprogram.
+        pub alias_signature: Option<NodeId<Signature>>,
+    }
 }
 
-/// Subprogram signature (`[type_marks return type_mark]`).
-///
-/// Disambiguates overloaded subprograms in aliases, attributes, and similar.
-///
-/// ```vhdl
-/// alias add is "+" [integer, integer return integer];
-/// -- type_marks = [integer, integer], return_type_mark = integer
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Signature {
-    /// Parameter type marks in order.
-    #[serde(default, rename = "type_marks_list")]
-    pub type_marks: Vec<NameNodeId>,
-    /// Return type mark for functions.
-    pub return_type_mark: Option<NameNodeId>,
-    /// Prefix name the signature is attached to, when present.
-    pub signature_prefix: Option<NameNodeId>,
-    /// Named entity selected by the signature after resolution.
-    pub named_entity: Option<NamedEntityNodeId>,
+node_struct! {
+    /// Subprogram signature (`[type_marks return type_mark]`).
+    ///
+    /// Disambiguates overloaded subprograms in aliases, attributes, and similar.
+    ///
+    /// ```vhdl
+    /// alias add is "+" [integer, integer return integer];
+    /// -- type_marks = [integer, integer], return_type_mark = integer
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct Signature {
+        /// Parameter type marks in order.
+        #[serde(default, rename = "type_marks_list")]
+        pub type_marks: Vec<NameNodeId>,
+        /// Return type mark for functions.
+        pub return_type_mark: Option<NameNodeId>,
+        /// Prefix name the signature is attached to, when present.
+        pub signature_prefix: Option<NameNodeId>,
+        /// Named entity selected by the signature after resolution.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+    }
 }
 
-/// Internal suspend-state declaration inserted for sequential suspension.
-///
-/// Simulation artifact for processes/procedures that can wait; usually not
-/// present as user-written VHDL. Linked from [`SuspendStateStatement`] nodes.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SuspendStateDeclaration {
-    /// Last suspend-state statement in the chain owned by this declaration.
-    pub suspend_state_last: Option<NodeId<SuspendStateStatement>>,
+node_struct! {
+    /// Internal suspend-state declaration inserted for sequential suspension.
+    ///
+    /// Simulation artifact for processes/procedures that can wait; usually not
+    /// present as user-written VHDL. Linked from [`SuspendStateStatement`] nodes.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SuspendStateDeclaration {
+        /// Last suspend-state statement in the chain owned by this declaration.
+        @reference
+        pub suspend_state_last: Option<NodeId<SuspendStateStatement>>,
+    }
 }
-/// Function declaration (`function … return …`).
-///
-/// May be a specification only, or linked to a [`FunctionBody`] via
-/// [`subprogram_body`](Self::subprogram_body). Predefined operators set
-/// [`implicit_definition`](Self::implicit_definition).
-///
-/// ```vhdl
-/// function add(a, b : integer) return integer;
-/// pure function id(x : T) return T;
-/// impure function rnd return real;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct FunctionDeclaration {
-    /// Function identifier or operator symbol.
-    pub identifier: Identifier,
-    /// Predefined operator/function kind when this is an implicit declaration.
-    pub implicit_definition: Option<ImplicitDefinition>,
-    /// Parameter interface list.
-    #[serde(default)]
-    pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
-    /// Return subtype after analysis.
-    pub return_type: Option<SubtypeDefinitionNodeId>,
-    /// Function body when present in the same analysis unit.
-    pub subprogram_body: Option<NodeId<FunctionBody>>,
-    /// Whether the function is pure (`true`) or impure (`false`).
-    #[serde(default)]
-    pub pure_flag: bool,
-    /// Generic interface list for generic functions (VHDL-2008).
-    #[serde(default)]
-    pub generics: Vec<InterfaceDeclarationNodeId>,
-    /// Return type mark as written.
-    pub return_type_mark: Option<NameNodeId>,
+node_struct! {
+    /// Function declaration (`function … return …`).
+    ///
+    /// May be a specification only, or linked to a [`FunctionBody`] via
+    /// [`subprogram_body`](Self::subprogram_body). Predefined operators set
+    /// [`implicit_definition`](Self::implicit_definition).
+    ///
+    /// ```vhdl
+    /// function add(a, b : integer) return integer;
+    /// pure function id(x : T) return T;
+    /// impure function rnd return real;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct FunctionDeclaration {
+        /// Function identifier or operator symbol.
+        pub identifier: Identifier,
+        /// Predefined operator/function kind when this is an implicit declaration.
+        pub implicit_definition: Option<ImplicitDefinition>,
+        /// Parameter interface list.
+        #[serde(default)]
+        pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
+        /// Return subtype after analysis.
+        pub return_type: Option<SubtypeDefinitionNodeId>,
+        /// Function body when present in the same analysis unit.
+        @reference
+        pub subprogram_body: Option<NodeId<FunctionBody>>,
+        /// Whether the function is pure (`true`) or impure (`false`).
+        #[serde(default)]
+        pub pure_flag: bool,
+        /// Generic interface list for generic functions (VHDL-2008).
+        #[serde(default)]
+        pub generics: Vec<InterfaceDeclarationNodeId>,
+        /// Return type mark as written.
+        pub return_type_mark: Option<NameNodeId>,
+    }
 }
 
-/// Function body (`function … is … begin … end`).
-///
-/// ```vhdl
-/// function add(a, b : integer) return integer is
-/// begin
-///   return a + b;
-/// end function;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct FunctionBody {
-    /// Matching function declaration / specification.
-    pub subprogram_specification: NodeId<FunctionDeclaration>,
-    /// Declarations in the function declarative part.
-    #[serde(default)]
-    pub declarations: Vec<DeclarationNodeId>,
-    /// Sequential statements of the function body.
-    #[serde(default)]
-    pub sequential_statements: Vec<SequentialStatementNodeId>,
+node_struct! {
+    /// Function body (`function … is … begin … end`).
+    ///
+    /// ```vhdl
+    /// function add(a, b : integer) return integer is
+    /// begin
+    ///   return a + b;
+    /// end function;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct FunctionBody {
+        /// Matching function declaration / specification.
+        @reference
+        pub subprogram_specification: NodeId<FunctionDeclaration>,
+        /// Declarations in the function declarative part.
+        #[serde(default)]
+        pub declarations: Vec<DeclarationNodeId>,
+        /// Sequential statements of the function body.
+        #[serde(default)]
+        pub sequential_statements: Vec<SequentialStatementNodeId>,
+    }
 }
 
-/// Procedure declaration (`procedure …`).
-///
-/// ```vhdl
-/// procedure pulse(signal s : out std_logic; t : time);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ProcedureDeclaration {
-    /// Procedure identifier.
-    pub identifier: Identifier,
-    /// Predefined procedure kind when this is an implicit declaration.
-    pub implicit_definition: Option<ImplicitDefinition>,
-    /// Parameter interface list.
-    #[serde(default)]
-    pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
-    /// Procedure body when present in the same analysis unit.
-    pub subprogram_body: Option<NodeId<ProcedureBody>>,
-    /// Generic interface list for generic procedures (VHDL-2008).
-    #[serde(default)]
-    pub generics: Vec<InterfaceDeclarationNodeId>,
+node_struct! {
+    /// Procedure declaration (`procedure …`).
+    ///
+    /// ```vhdl
+    /// procedure pulse(signal s : out std_logic; t : time);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ProcedureDeclaration {
+        /// Procedure identifier.
+        pub identifier: Identifier,
+        /// Predefined procedure kind when this is an implicit declaration.
+        pub implicit_definition: Option<ImplicitDefinition>,
+        /// Parameter interface list.
+        #[serde(default)]
+        pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
+        /// Procedure body when present in the same analysis unit.
+        @reference
+        pub subprogram_body: Option<NodeId<ProcedureBody>>,
+        /// Generic interface list for generic procedures (VHDL-2008).
+        #[serde(default)]
+        pub generics: Vec<InterfaceDeclarationNodeId>,
+    }
 }
 
-/// Procedure body (`procedure … is … begin … end`).
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ProcedureBody {
-    /// Matching procedure declaration / specification.
-    pub subprogram_specification: NodeId<ProcedureDeclaration>,
-    /// Declarations in the procedure declarative part.
-    #[serde(default)]
-    pub declarations: Vec<DeclarationNodeId>,
-    /// Sequential statements of the procedure body.
-    #[serde(default)]
-    pub sequential_statements: Vec<SequentialStatementNodeId>,
+node_struct! {
+    /// Procedure body (`procedure … is … begin … end`).
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ProcedureBody {
+        /// Matching procedure declaration / specification.
+        @reference
+        pub subprogram_specification: NodeId<ProcedureDeclaration>,
+        /// Declarations in the procedure declarative part.
+        #[serde(default)]
+        pub declarations: Vec<DeclarationNodeId>,
+        /// Sequential statements of the procedure body.
+        #[serde(default)]
+        pub sequential_statements: Vec<SequentialStatementNodeId>,
+    }
 }
 
-/// Array element resolution indication (`(resolution_function element_subtype)`).
-///
-/// ```vhdl
-/// type resolved_vec is array (natural range <>) of resolved std_ulogic;
-/// -- element resolution may appear in subtype indications as:
-/// -- (resolved std_ulogic)
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ArrayElementResolution {
-    /// Resolution indication applied to each element.
-    pub resolution_indication: Option<GenericNodeId>,
-    /// Element subtype indication.
-    pub element_subtype_indication: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Array element resolution indication (`(resolution_function element_subtype)`).
+    ///
+    /// ```vhdl
+    /// type resolved_vec is array (natural range <>) of resolved std_ulogic;
+    /// -- element resolution may appear in subtype indications as:
+    /// -- (resolved std_ulogic)
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ArrayElementResolution {
+        /// Resolution indication applied to each element.
+        pub resolution_indication: Option<GenericNodeId>,
+        /// Element subtype indication.
+        pub element_subtype_indication: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
 /// Kind of predefined/implicit operator or function synthesized by analysis.
@@ -3418,551 +3485,634 @@ pub enum ImplicitDefinition {
     #[serde(rename = "IIR_PREDEFINED_IEEE_NUMERIC_BIT_MAX_INT_SGN")]
     IeeeNumericBitMaxIntSgn,
     /// Unrecognized or `Iir_Predefined_None`.
+    #[serde(alias = "$unknown")]
     Unknown,
 }
 
-/// Record element declaration (`identifier_list : subtype_indication`).
-///
-/// ```vhdl
-/// type rec_t is record
-///   a, b : integer;           -- two ElementDeclaration nodes
-///   data : std_logic_vector(7 downto 0);
-/// end record;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ElementDeclaration {
-    /// Element identifier.
-    pub identifier: Identifier,
-    /// Subtype indication of the element.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Analyzed type of the element.
-    #[serde(rename = "type")]
-    pub typ: Option<TypeAndSubtypeDefinitionNodeId>,
-    /// Zero-based position in the record type.
-    pub element_position: Option<i32>,
+node_struct! {
+    /// Record element declaration (`identifier_list : subtype_indication`).
+    ///
+    /// ```vhdl
+    /// type rec_t is record
+    ///   a, b : integer;           -- two ElementDeclaration nodes
+    ///   data : std_logic_vector(7 downto 0);
+    /// end record;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ElementDeclaration {
+        /// Element identifier.
+        pub identifier: Identifier,
+        /// Subtype indication of the element.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Analyzed type of the element.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<TypeAndSubtypeDefinitionNodeId>,
+        /// Zero-based position in the record type.
+        pub element_position: Option<i32>,
+    }
 }
 
-/// File declaration (`file … : … is …`).
-///
-/// ```vhdl
-/// file f : text open read_mode is "input.txt";
-/// file g : text is in "data.txt";
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct FileDeclaration {
-    /// File identifier.
-    pub identifier: Option<Identifier>,
-    /// Analyzed file type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Subtype indication of the file type mark.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Explicit mode when written (`in` / `out`).
-    pub mode: Option<Mode>,
-    /// Logical name expression (file path string expression).
-    pub file_logical_name: Option<ExpressionNodeId>,
-    /// Open-kind expression (`read_mode`, `write_mode`, …).
-    pub file_open_kind: Option<ExpressionNodeId>,
+node_struct! {
+    /// File declaration (`file … : … is …`).
+    ///
+    /// ```vhdl
+    /// file f : text open read_mode is "input.txt";
+    /// file g : text is in "data.txt";
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct FileDeclaration {
+        /// File identifier.
+        pub identifier: Option<Identifier>,
+        /// Analyzed file type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Subtype indication of the file type mark.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Explicit mode when written (`in` / `out`).
+        pub mode: Option<Mode>,
+        /// Logical name expression (file path string expression).
+        pub file_logical_name: Option<ExpressionNodeId>,
+        /// Open-kind expression (`read_mode`, `write_mode`, …).
+        pub file_open_kind: Option<ExpressionNodeId>,
+    }
 }
 
-/// Object alias declaration (`alias … : … is object_name`).
-///
-/// Creates another name for an existing object (signal, variable, constant, …).
-///
-/// ```vhdl
-/// alias msb : std_logic is data(7);
-/// alias all_bits : std_logic_vector(7 downto 0) is data;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ObjectAliasDeclaration {
-    /// Alias identifier.
-    pub identifier: Option<Identifier>,
-    /// Name of the aliased object (possibly sliced / selected).
-    pub name: Option<NameNodeId>,
-    /// Optional subtype indication of the alias.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Analyzed type of the alias.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Object alias declaration (`alias … : … is object_name`).
+    ///
+    /// Creates another name for an existing object (signal, variable, constant, …).
+    ///
+    /// ```vhdl
+    /// alias msb : std_logic is data(7);
+    /// alias all_bits : std_logic_vector(7 downto 0) is data;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ObjectAliasDeclaration {
+        /// Alias identifier.
+        pub identifier: Option<Identifier>,
+        /// Name of the aliased object (possibly sliced / selected).
+        pub name: Option<NameNodeId>,
+        /// Optional subtype indication of the alias.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Analyzed type of the alias.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Component declaration (`component … is … end component`).
-///
-/// ```vhdl
-/// component nand2 is
-///   generic (Tpd : time := 1 ns);
-///   port (a, b : in std_logic; y : out std_logic);
-/// end component;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ComponentDeclaration {
-    /// Component identifier.
-    pub identifier: Option<Identifier>,
-    /// Generic interface list.
-    #[serde(default)]
-    pub generics: Vec<InterfaceDeclarationNodeId>,
-    /// Port interface list.
-    #[serde(default)]
-    pub ports: Vec<PortInterfaceDeclarationNodeId>,
+node_struct! {
+    /// Component declaration (`component … is … end component`).
+    ///
+    /// ```vhdl
+    /// component nand2 is
+    ///   generic (Tpd : time := 1 ns);
+    ///   port (a, b : in std_logic; y : out std_logic);
+    /// end component;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ComponentDeclaration {
+        /// Component identifier.
+        pub identifier: Option<Identifier>,
+        /// Generic interface list.
+        #[serde(default)]
+        pub generics: Vec<InterfaceDeclarationNodeId>,
+        /// Port interface list.
+        #[serde(default)]
+        pub ports: Vec<PortInterfaceDeclarationNodeId>,
+    }
 }
 
-/// Iterator / parameter specification of a `for` loop or `for` generate.
-///
-/// ```vhdl
-/// for i in 0 to 7 loop … end loop;
-/// for g in generate_range generate … end generate;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct IteratorDeclaration {
-    /// Iterator identifier.
-    pub identifier: Option<Identifier>,
-    /// Discrete range the iterator covers.
-    pub discrete_range: Option<RangeConstraintNodeId>,
-    /// Subtype indication of the iterator.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Analyzed type of the iterator.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Iterator / parameter specification of a `for` loop or `for` generate.
+    ///
+    /// ```vhdl
+    /// for i in 0 to 7 loop … end loop;
+    /// for g in generate_range generate … end generate;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct IteratorDeclaration {
+        /// Iterator identifier.
+        pub identifier: Option<Identifier>,
+        /// Discrete range the iterator covers.
+        pub discrete_range: Option<RangeConstraintNodeId>,
+        /// Subtype indication of the iterator.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Analyzed type of the iterator.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Implicit guard signal of a guarded block (`block (guard_expr)`).
-///
-/// ```vhdl
-/// b: block (en = '1')
-/// begin
-///   s <= guarded d;
-/// end block;
-/// -- GuardSignalDeclaration holds the implicit GUARD signal
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GuardSignalDeclaration {
-    /// Guard signal identifier (typically `guard`).
-    pub identifier: Option<Identifier>,
-    /// Analyzed type (boolean).
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Guard expression from the block header.
-    pub guard_expression: Option<ExpressionNodeId>,
-    /// Signals read by the guard expression.
-    #[serde(default)]
-    pub guard_sensitivity_list: Vec<ExpressionNodeId>,
-    /// Whether the guard is treated as a guarded signal.
-    #[serde(default)]
-    pub guarded_signal_flag: bool,
-    /// `register` / `bus` kind when applicable.
-    pub signal_kind: Option<SignalKind>,
-    /// Owning block statement.
-    pub block_statement: Option<GenericNodeId>,
+node_struct! {
+    /// Implicit guard signal of a guarded block (`block (guard_expr)`).
+    ///
+    /// ```vhdl
+    /// b: block (en = '1')
+    /// begin
+    ///   s <= guarded d;
+    /// end block;
+    /// -- GuardSignalDeclaration holds the implicit GUARD signal
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct GuardSignalDeclaration {
+        /// Guard signal identifier (typically `guard`).
+        pub identifier: Option<Identifier>,
+        /// Analyzed type (boolean).
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Guard expression from the block header.
+        pub guard_expression: Option<ExpressionNodeId>,
+        /// Signals read by the guard expression.
+        #[serde(default)]
+        @reference
+        pub guard_sensitivity_list: Vec<ExpressionNodeId>,
+        /// Whether the guard is treated as a guarded signal.
+        #[serde(default)]
+        pub guarded_signal_flag: bool,
+        /// `register` / `bus` kind when applicable.
+        pub signal_kind: Option<SignalKind>,
+        /// Owning block statement.
+        @reference
+        pub block_statement: Option<GenericNodeId>,
+    }
 }
 
-/// Chain of implicitly declared attribute objects for a design region.
-///
-/// GHDL inserts these to hold predefined attribute state; user code does not
-/// declare them explicitly.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AttributeImplicitDeclaration {
-    /// Linked implicit attribute objects.
-    #[serde(default)]
-    pub attribute_implicits: Vec<GenericNodeId>,
+node_struct! {
+    /// Chain of implicitly declared attribute objects for a design region.
+    ///
+    /// GHDL inserts these to hold predefined attribute state; user code does not
+    /// declare them explicitly.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AttributeImplicitDeclaration {
+        /// Linked implicit attribute objects.
+        #[serde(default)]
+        pub attribute_implicits: Vec<GenericNodeId>,
+    }
 }
 
-/// Nature declaration (`nature … is …`) for VHDL-AMS.
-///
-/// ```vhdl
-/// nature electrical is
-///   voltage across
-///   current through
-///   ground reference;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct NatureDeclaration {
-    /// Nature identifier.
-    pub identifier: Option<Identifier>,
-    /// Nature definition.
-    pub nature_definition: Option<GenericNodeId>,
+node_struct! {
+    /// Nature declaration (`nature … is …`) for VHDL-AMS.
+    ///
+    /// ```vhdl
+    /// nature electrical is
+    ///   voltage across
+    ///   current through
+    ///   ground reference;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct NatureDeclaration {
+        /// Nature identifier.
+        pub identifier: Option<Identifier>,
+        /// Nature definition.
+        pub nature_definition: Option<GenericNodeId>,
+    }
 }
 
-/// Subnature declaration (`subnature … is …`).
-///
-/// ```vhdl
-/// subnature small_elec is electrical;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SubnatureDeclaration {
-    /// Subnature identifier.
-    pub identifier: Option<Identifier>,
-    /// Subnature indication.
-    pub subnature_indication: Option<GenericNodeId>,
-    /// Analyzed nature.
-    pub nature: Option<GenericNodeId>,
+node_struct! {
+    /// Subnature declaration (`subnature … is …`).
+    ///
+    /// ```vhdl
+    /// subnature small_elec is electrical;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SubnatureDeclaration {
+        /// Subnature identifier.
+        pub identifier: Option<Identifier>,
+        /// Subnature indication.
+        pub subnature_indication: Option<GenericNodeId>,
+        /// Analyzed nature.
+        @reference
+        pub nature: Option<GenericNodeId>,
+    }
 }
 
-/// Group template declaration (`group … is (…)`).
-///
-/// ```vhdl
-/// group pin2pin is (signal, signal);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GroupTemplateDeclaration {
-    /// Template identifier.
-    pub identifier: Option<Identifier>,
-    /// Entity-class entries of the template.
-    #[serde(default)]
-    pub entity_class_entries: Vec<NodeId<EntityClass>>,
+node_struct! {
+    /// Group template declaration (`group … is (…)`).
+    ///
+    /// ```vhdl
+    /// group pin2pin is (signal, signal);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct GroupTemplateDeclaration {
+        /// Template identifier.
+        pub identifier: Option<Identifier>,
+        /// Entity-class entries of the template.
+        #[serde(default)]
+        pub entity_class_entries: Vec<NodeId<EntityClass>>,
+    }
 }
 
-/// Group declaration (`group … : template (…)`).
-///
-/// ```vhdl
-/// group g : pin2pin (a, b);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct GroupDeclaration {
-    /// Group identifier.
-    pub identifier: Option<Identifier>,
-    /// Constituents of the group.
-    #[serde(default)]
-    pub group_constituent_list: Vec<GenericNodeId>,
-    /// Name of the group template.
-    pub group_template_name: Option<NameNodeId>,
+node_struct! {
+    /// Group declaration (`group … : template (…)`).
+    ///
+    /// ```vhdl
+    /// group g : pin2pin (a, b);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct GroupDeclaration {
+        /// Group identifier.
+        pub identifier: Option<Identifier>,
+        /// Constituents of the group.
+        #[serde(default)]
+        pub group_constituent_list: Vec<GenericNodeId>,
+        /// Name of the group template.
+        pub group_template_name: Option<NameNodeId>,
+    }
 }
 
-/// Element of a record nature (`identifier : subnature`).
-#[derive(Debug, Deserialize, Serialize)]
-pub struct NatureElementDeclaration {
-    /// Element identifier.
-    pub identifier: Option<Identifier>,
-    /// Subnature indication of the element.
-    pub subnature_indication: Option<GenericNodeId>,
-    /// Analyzed nature of the element.
-    pub nature: Option<GenericNodeId>,
-    /// Zero-based position in the record nature.
-    pub element_position: Option<i32>,
+node_struct! {
+    /// Element of a record nature (`identifier : subnature`).
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct NatureElementDeclaration {
+        /// Element identifier.
+        pub identifier: Option<Identifier>,
+        /// Subnature indication of the element.
+        pub subnature_indication: Option<GenericNodeId>,
+        /// Analyzed nature of the element.
+        @reference
+        pub nature: Option<GenericNodeId>,
+        /// Zero-based position in the record nature.
+        pub element_position: Option<i32>,
+    }
 }
 
-/// Mode-view declaration (VHDL-2019) (`view … of … is … end view`).
-///
-/// ```vhdl
-/// view master_view of bus_t is
-///   req  : out;
-///   ack  : in;
-///   data : out;
-/// end view;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ModeViewDeclaration {
-    /// Mode-view identifier.
-    pub identifier: Option<Identifier>,
-    /// Subtype indication of the viewed type.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Mode-view element definitions (list form).
-    #[serde(default)]
-    pub elements_definition_list: Vec<GenericNodeId>,
-    /// Mode-view element definitions (chain form).
-    #[serde(default)]
-    pub elements_definitions: Vec<GenericNodeId>,
+node_struct! {
+    /// Mode-view declaration (VHDL-2019) (`view … of … is … end view`).
+    ///
+    /// ```vhdl
+    /// view master_view of bus_t is
+    ///   req  : out;
+    ///   ack  : in;
+    ///   data : out;
+    /// end view;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ModeViewDeclaration {
+        /// Mode-view identifier.
+        pub identifier: Option<Identifier>,
+        /// Subtype indication of the viewed type.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Mode-view element definitions (list form).
+        #[serde(default)]
+        pub elements_definition_list: Vec<GenericNodeId>,
+        /// Mode-view element definitions (chain form).
+        #[serde(default)]
+        pub elements_definitions: Vec<GenericNodeId>,
+    }
 }
 
-/// Body of a subprogram instantiation.
-///
-/// Completes an instantiated function/procedure with declarations and
-/// statements when an instance body is required.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SubprogramInstantiationBody {
-    /// Declarations in the instance body.
-    #[serde(default)]
-    pub declarations: Vec<DeclarationNodeId>,
-    /// Sequential statements of the instance body.
-    #[serde(default)]
-    pub sequential_statements: Vec<SequentialStatementNodeId>,
-    /// Matching subprogram specification / instantiation.
-    pub subprogram_specification: Option<GenericNodeId>,
+node_struct! {
+    /// Body of a subprogram instantiation.
+    ///
+    /// Completes an instantiated function/procedure with declarations and
+    /// statements when an instance body is required.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SubprogramInstantiationBody {
+        /// Declarations in the instance body.
+        #[serde(default)]
+        pub declarations: Vec<DeclarationNodeId>,
+        /// Sequential statements of the instance body.
+        #[serde(default)]
+        pub sequential_statements: Vec<SequentialStatementNodeId>,
+        /// Matching subprogram specification / instantiation.
+        @reference
+        pub subprogram_specification: Option<GenericNodeId>,
+    }
 }
 
-/// Function instantiation declaration (`function … is new …`).
-///
-/// ```vhdl
-/// function my_id is new id_fn
-///   generic map (T => integer);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct FunctionInstantiationDeclaration {
-    /// Instantiated function identifier.
-    pub identifier: Option<Identifier>,
-    /// Whether the instance is pure.
-    #[serde(default)]
-    pub pure_flag: bool,
-    /// Generic interface list of the instance.
-    #[serde(default)]
-    pub generics: Vec<InterfaceDeclarationNodeId>,
-    /// Parameter interface list of the instance.
-    #[serde(default)]
-    pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
-    /// Return subtype after analysis.
-    pub return_type: Option<SubtypeDefinitionNodeId>,
-    /// Uninstantiated function being instantiated.
-    pub uninstantiated_subprogram_name: Option<NameNodeId>,
-    /// Generic map associations.
-    #[serde(default)]
-    pub generic_map_aspects: Vec<AssociationElementNodeId>,
-    /// Instance body, when present.
-    pub instance_subprogram_body: Option<NodeId<SubprogramInstantiationBody>>,
+node_struct! {
+    /// Function instantiation declaration (`function … is new …`).
+    ///
+    /// ```vhdl
+    /// function my_id is new id_fn
+    ///   generic map (T => integer);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct FunctionInstantiationDeclaration {
+        /// Instantiated function identifier.
+        pub identifier: Option<Identifier>,
+        /// Whether the instance is pure.
+        #[serde(default)]
+        pub pure_flag: bool,
+        /// Generic interface list of the instance.
+        #[serde(default)]
+        pub generics: Vec<InterfaceDeclarationNodeId>,
+        /// Parameter interface list of the instance.
+        #[serde(default)]
+        pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
+        /// Return subtype after analysis.
+        pub return_type: Option<SubtypeDefinitionNodeId>,
+        /// Uninstantiated function being instantiated.
+        pub uninstantiated_subprogram_name: Option<NameNodeId>,
+        /// Generic map associations.
+        #[serde(default)]
+        pub generic_map_aspects: Vec<AssociationElementNodeId>,
+        /// Instance body, when present.
+        pub instance_subprogram_body: Option<NodeId<SubprogramInstantiationBody>>,
+    }
 }
 
-/// Procedure instantiation declaration (`procedure … is new …`).
-///
-/// ```vhdl
-/// procedure my_pulse is new pulse_proc
-///   generic map (T => std_logic);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ProcedureInstantiationDeclaration {
-    /// Instantiated procedure identifier.
-    pub identifier: Option<Identifier>,
-    /// Generic interface list of the instance.
-    #[serde(default)]
-    pub generics: Vec<InterfaceDeclarationNodeId>,
-    /// Parameter interface list of the instance.
-    #[serde(default)]
-    pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
-    /// Uninstantiated procedure being instantiated.
-    pub uninstantiated_subprogram_name: Option<NameNodeId>,
-    /// Generic map associations.
-    #[serde(default)]
-    pub generic_map_aspects: Vec<AssociationElementNodeId>,
-    /// Instance body, when present.
-    pub instance_subprogram_body: Option<NodeId<SubprogramInstantiationBody>>,
+node_struct! {
+    /// Procedure instantiation declaration (`procedure … is new …`).
+    ///
+    /// ```vhdl
+    /// procedure my_pulse is new pulse_proc
+    ///   generic map (T => std_logic);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ProcedureInstantiationDeclaration {
+        /// Instantiated procedure identifier.
+        pub identifier: Option<Identifier>,
+        /// Generic interface list of the instance.
+        #[serde(default)]
+        pub generics: Vec<InterfaceDeclarationNodeId>,
+        /// Parameter interface list of the instance.
+        #[serde(default)]
+        pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
+        /// Uninstantiated procedure being instantiated.
+        pub uninstantiated_subprogram_name: Option<NameNodeId>,
+        /// Generic map associations.
+        #[serde(default)]
+        pub generic_map_aspects: Vec<AssociationElementNodeId>,
+        /// Instance body, when present.
+        pub instance_subprogram_body: Option<NodeId<SubprogramInstantiationBody>>,
+    }
 }
 
-/// Terminal declaration (VHDL-AMS) (`terminal … : …`).
-///
-/// ```vhdl
-/// terminal n1, n2 : electrical;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct TerminalDeclaration {
-    /// Terminal identifier.
-    pub identifier: Option<Identifier>,
-    /// Subnature indication.
-    pub subnature_indication: Option<GenericNodeId>,
-    /// Analyzed nature.
-    pub nature: Option<GenericNodeId>,
-    /// Whether this is a reference terminal.
-    #[serde(default)]
-    pub reference_terminal_flag: bool,
+node_struct! {
+    /// Terminal declaration (VHDL-AMS) (`terminal … : …`).
+    ///
+    /// ```vhdl
+    /// terminal n1, n2 : electrical;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct TerminalDeclaration {
+        /// Terminal identifier.
+        pub identifier: Option<Identifier>,
+        /// Subnature indication.
+        pub subnature_indication: Option<GenericNodeId>,
+        /// Analyzed nature.
+        @reference
+        pub nature: Option<GenericNodeId>,
+        /// Whether this is a reference terminal.
+        #[serde(default)]
+        pub reference_terminal_flag: bool,
+    }
 }
 
-/// Free quantity declaration (`quantity … : …`).
-///
-/// ```vhdl
-/// quantity q : real;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct FreeQuantityDeclaration {
-    /// Quantity identifier.
-    pub identifier: Option<Identifier>,
-    /// Subtype indication.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Default / initial value.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Free quantity declaration (`quantity … : …`).
+    ///
+    /// ```vhdl
+    /// quantity q : real;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct FreeQuantityDeclaration {
+        /// Quantity identifier.
+        pub identifier: Option<Identifier>,
+        /// Subtype indication.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Default / initial value.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Spectrum quantity declaration (`quantity … : … spectrum …, …`).
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SpectrumQuantityDeclaration {
-    /// Quantity identifier.
-    pub identifier: Option<Identifier>,
-    /// Subtype indication.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Magnitude expression.
-    pub magnitude_expression: Option<ExpressionNodeId>,
-    /// Phase expression.
-    pub phase_expression: Option<ExpressionNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Spectrum quantity declaration (`quantity … : … spectrum …, …`).
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SpectrumQuantityDeclaration {
+        /// Quantity identifier.
+        pub identifier: Option<Identifier>,
+        /// Subtype indication.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Magnitude expression.
+        pub magnitude_expression: Option<ExpressionNodeId>,
+        /// Phase expression.
+        pub phase_expression: Option<ExpressionNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Noise quantity declaration (`quantity … : … noise …`).
-#[derive(Debug, Deserialize, Serialize)]
-pub struct NoiseQuantityDeclaration {
-    /// Quantity identifier.
-    pub identifier: Option<Identifier>,
-    /// Subtype indication.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Power expression.
-    pub power_expression: Option<ExpressionNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Noise quantity declaration (`quantity … : … noise …`).
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct NoiseQuantityDeclaration {
+        /// Quantity identifier.
+        pub identifier: Option<Identifier>,
+        /// Subtype indication.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Power expression.
+        pub power_expression: Option<ExpressionNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Across quantity declaration (`quantity … across … to …`).
-///
-/// ```vhdl
-/// quantity v across n1 to n2;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AcrossQuantityDeclaration {
-    /// Quantity identifier.
-    pub identifier: Option<Identifier>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Default value expression.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Tolerance expression.
-    pub tolerance: Option<ExpressionNodeId>,
-    /// Plus terminal name.
-    pub plus_terminal_name: Option<NameNodeId>,
-    /// Minus terminal name.
-    pub minus_terminal_name: Option<NameNodeId>,
-    /// Resolved plus terminal.
-    pub plus_terminal: Option<GenericNodeId>,
-    /// Resolved minus terminal.
-    pub minus_terminal: Option<GenericNodeId>,
+node_struct! {
+    /// Across quantity declaration (`quantity … across … to …`).
+    ///
+    /// ```vhdl
+    /// quantity v across n1 to n2;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AcrossQuantityDeclaration {
+        /// Quantity identifier.
+        pub identifier: Option<Identifier>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Default value expression.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Tolerance expression.
+        pub tolerance: Option<ExpressionNodeId>,
+        /// Plus terminal name.
+        pub plus_terminal_name: Option<NameNodeId>,
+        /// Minus terminal name.
+        pub minus_terminal_name: Option<NameNodeId>,
+        /// Resolved plus terminal.
+        @reference
+        pub plus_terminal: Option<GenericNodeId>,
+        /// Resolved minus terminal.
+        @reference
+        pub minus_terminal: Option<GenericNodeId>,
+    }
 }
 
-/// Through quantity declaration (`quantity … through … to …`).
-///
-/// ```vhdl
-/// quantity i through n1 to n2;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ThroughQuantityDeclaration {
-    /// Quantity identifier.
-    pub identifier: Option<Identifier>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Default value expression.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Tolerance expression.
-    pub tolerance: Option<ExpressionNodeId>,
-    /// Plus terminal name.
-    pub plus_terminal_name: Option<NameNodeId>,
-    /// Minus terminal name.
-    pub minus_terminal_name: Option<NameNodeId>,
-    /// Resolved plus terminal.
-    pub plus_terminal: Option<GenericNodeId>,
-    /// Resolved minus terminal.
-    pub minus_terminal: Option<GenericNodeId>,
+node_struct! {
+    /// Through quantity declaration (`quantity … through … to …`).
+    ///
+    /// ```vhdl
+    /// quantity i through n1 to n2;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ThroughQuantityDeclaration {
+        /// Quantity identifier.
+        pub identifier: Option<Identifier>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Default value expression.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Tolerance expression.
+        pub tolerance: Option<ExpressionNodeId>,
+        /// Plus terminal name.
+        pub plus_terminal_name: Option<NameNodeId>,
+        /// Minus terminal name.
+        pub minus_terminal_name: Option<NameNodeId>,
+        /// Resolved plus terminal.
+        @reference
+        pub plus_terminal: Option<GenericNodeId>,
+        /// Resolved minus terminal.
+        @reference
+        pub minus_terminal: Option<GenericNodeId>,
+    }
 }
 
-/// Interface view declaration (VHDL-2019 mode-view port).
-///
-/// ```vhdl
-/// port (bus_if : view master_view of bus_t);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceViewDeclaration {
-    /// View interface identifier.
-    pub identifier: Option<Identifier>,
-    /// Mode when present.
-    pub mode: Option<Mode>,
-    /// Subtype indication of the viewed object.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Mode-view indication.
-    pub mode_view_indication: Option<GenericNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Interface view declaration (VHDL-2019 mode-view port).
+    ///
+    /// ```vhdl
+    /// port (bus_if : view master_view of bus_t);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceViewDeclaration {
+        /// View interface identifier.
+        pub identifier: Option<Identifier>,
+        /// Mode when present.
+        pub mode: Option<Mode>,
+        /// Subtype indication of the viewed object.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Mode-view indication.
+        pub mode_view_indication: Option<GenericNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Interface quantity declaration (AMS generic/port quantity).
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceQuantityDeclaration {
-    /// Quantity interface identifier.
-    pub identifier: Option<Identifier>,
-    /// Mode of the quantity interface.
-    pub mode: Option<Mode>,
-    /// Subtype indication.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Default value.
-    pub default_value: Option<ExpressionNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Interface quantity declaration (AMS generic/port quantity).
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceQuantityDeclaration {
+        /// Quantity interface identifier.
+        pub identifier: Option<Identifier>,
+        /// Mode of the quantity interface.
+        pub mode: Option<Mode>,
+        /// Subtype indication.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Default value.
+        pub default_value: Option<ExpressionNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Interface terminal declaration (AMS).
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceTerminalDeclaration {
-    /// Terminal interface identifier.
-    pub identifier: Option<Identifier>,
-    /// Subnature indication.
-    pub subnature_indication: Option<GenericNodeId>,
-    /// Analyzed nature.
-    pub nature: Option<GenericNodeId>,
+node_struct! {
+    /// Interface terminal declaration (AMS).
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceTerminalDeclaration {
+        /// Terminal interface identifier.
+        pub identifier: Option<Identifier>,
+        /// Subnature indication.
+        pub subnature_indication: Option<GenericNodeId>,
+        /// Analyzed nature.
+        @reference
+        pub nature: Option<GenericNodeId>,
+    }
 }
 
-/// Interface type declaration (`generic (type T)`).
-///
-/// ```vhdl
-/// generic (type element_t);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceTypeDeclaration {
-    /// Type generic identifier.
-    pub identifier: Option<Identifier>,
-    /// Interface type definition (associated actual after map).
-    pub interface_type_definition: Option<NodeId<InterfaceTypeDefinition>>,
-    /// Analyzed type when associated.
-    #[serde(rename = "type")]
-    pub typ: Option<TypeAndSubtypeDefinitionNodeId>,
-    /// Implicit subprograms associated with the type generic.
-    #[serde(default)]
-    pub interface_type_subprograms: Vec<GenericNodeId>,
+node_struct! {
+    /// Interface type declaration (`generic (type T)`).
+    ///
+    /// ```vhdl
+    /// generic (type element_t);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceTypeDeclaration {
+        /// Type generic identifier.
+        pub identifier: Option<Identifier>,
+        /// Interface type definition (associated actual after map).
+        pub interface_type_definition: Option<NodeId<InterfaceTypeDefinition>>,
+        /// Analyzed type when associated.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<TypeAndSubtypeDefinitionNodeId>,
+        /// Implicit subprograms associated with the type generic.
+        #[serde(default)]
+        pub interface_type_subprograms: Vec<GenericNodeId>,
+    }
 }
 
-/// Interface function declaration (subprogram generic).
-///
-/// ```vhdl
-/// generic (
-///   function "=" (l, r : element_t) return boolean is <>
-/// );
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceFunctionDeclaration {
-    /// Function interface identifier or operator symbol.
-    pub identifier: Option<Identifier>,
-    /// Whether the interface function is pure.
-    #[serde(default)]
-    pub pure_flag: bool,
-    /// Parameter interface list.
-    #[serde(default)]
-    pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
-    /// Return subtype after analysis.
-    pub return_type: Option<SubtypeDefinitionNodeId>,
-    /// Return type mark as written.
-    pub return_type_mark: Option<NameNodeId>,
-    /// Default subprogram (`<>` or an explicit name).
-    pub default_subprogram: Option<GenericNodeId>,
-    /// Associated actual subprogram after generic map.
-    pub associated_subprogram: Option<GenericNodeId>,
+node_struct! {
+    /// Interface function declaration (subprogram generic).
+    ///
+    /// ```vhdl
+    /// generic (
+    ///   function "=" (l, r : element_t) return boolean is <>
+    /// );
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceFunctionDeclaration {
+        /// Function interface identifier or operator symbol.
+        pub identifier: Option<Identifier>,
+        /// Whether the interface function is pure.
+        #[serde(default)]
+        pub pure_flag: bool,
+        /// Parameter interface list.
+        #[serde(default)]
+        pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
+        /// Return subtype after analysis.
+        pub return_type: Option<SubtypeDefinitionNodeId>,
+        /// Return type mark as written.
+        pub return_type_mark: Option<NameNodeId>,
+        /// Default subprogram (`<>` or an explicit name).
+        pub default_subprogram: Option<GenericNodeId>,
+        /// Associated actual subprogram after generic map.
+        @reference
+        pub associated_subprogram: Option<GenericNodeId>,
+    }
 }
 
-/// Interface procedure declaration (subprogram generic).
-///
-/// ```vhdl
-/// generic (
-///   procedure dump(x : element_t) is <>
-/// );
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct InterfaceProcedureDeclaration {
-    /// Procedure interface identifier.
-    pub identifier: Option<Identifier>,
-    /// Parameter interface list.
-    #[serde(default)]
-    pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
-    /// Return type mark (unused for procedures; may appear in GHDL export).
-    pub return_type_mark: Option<NameNodeId>,
-    /// Default subprogram (`<>` or an explicit name).
-    pub default_subprogram: Option<GenericNodeId>,
-    /// Associated actual subprogram after generic map.
-    pub associated_subprogram: Option<GenericNodeId>,
+node_struct! {
+    /// Interface procedure declaration (subprogram generic).
+    ///
+    /// ```vhdl
+    /// generic (
+    ///   procedure dump(x : element_t) is <>
+    /// );
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct InterfaceProcedureDeclaration {
+        /// Procedure interface identifier.
+        pub identifier: Option<Identifier>,
+        /// Parameter interface list.
+        #[serde(default)]
+        pub interface_declarations: Vec<InterfaceDeclarationNodeId>,
+        /// Return type mark (unused for procedures; may appear in GHDL export).
+        pub return_type_mark: Option<NameNodeId>,
+        /// Default subprogram (`<>` or an explicit name).
+        pub default_subprogram: Option<GenericNodeId>,
+        /// Associated actual subprogram after generic map.
+        @reference
+        pub associated_subprogram: Option<GenericNodeId>,
+    }
 }
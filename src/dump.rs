@@ -0,0 +1,203 @@
+//! Human-readable dumps of an [`Ast`] for debugging.
+
+use std::io;
+use std::io::Write;
+
+use smallvec::SmallVec;
+
+use super::*;
+
+/// Selects the nodes written by [`Ast::dump`], and how they're laid out.
+#[derive(Clone, Copy, Debug)]
+pub enum DumpMode {
+    /// One `{index}: {node:?}` line per slot of the node table.
+    Debug,
+    /// The subtree rooted at the given node, indented by depth and labeled with the owning field of each node.
+    Tree(GenericNodeId),
+    /// Like [`DumpMode::Debug`], but only the nodes in the subtree of the given design unit.
+    DesignUnit(NodeId<DesignUnit>),
+}
+
+/// Options for [`Ast::dump`].
+#[derive(Clone, Copy, Debug)]
+pub struct DumpOptions {
+    /// Nodes to write and their layout.
+    pub mode: DumpMode,
+    /// Whether to write nodes of the standard libraries, `std` and `ieee`.
+    pub include_standard_libraries: bool,
+}
+
+impl Default for DumpOptions {
+    fn default() -> Self {
+        Self {
+            mode: DumpMode::Debug,
+            include_standard_libraries: true,
+        }
+    }
+}
+
+/// Writes a dump of `ast`, see [`Ast::dump`].
+pub(crate) fn dump(ast: &Ast, mut writer: impl Write, options: DumpOptions) -> io::Result<()> {
+    let excluded_libraries = if options.include_standard_libraries {
+        SmallVec::new()
+    } else {
+        top_level::STANDARD_LIBRARIES
+            .into_iter()
+            .filter_map(|name| ast.lookup_library(&NormalizedIdentifier::static_normalized(name)))
+            .map(GenericNodeId::from)
+            .collect::<SmallVec<[_; 2]>>()
+    };
+    let is_included = |id: GenericNodeId| {
+        let root = ast.ancestors(id).last().unwrap_or(id);
+        !excluded_libraries.contains(&root)
+    };
+
+    match options.mode {
+        DumpMode::Debug => {
+            for (index, node) in ast.nodes.iter().enumerate() {
+                let id = u32::try_from(index).ok().and_then(IdPrimitive::new);
+                if id.is_none_or(|raw| is_included(GenericNodeId::from_raw(raw))) {
+                    writeln!(writer, "{index:6}: {node:?}")?;
+                }
+            }
+        },
+        DumpMode::Tree(root) => {
+            if is_included(root) {
+                write_tree(ast, &mut writer, &is_included, None, root, 0)?;
+            }
+        },
+        DumpMode::DesignUnit(design_unit_id) => {
+            let mut subtree = vec![GenericNodeId::from(design_unit_id)];
+            let mut next = 0;
+            while let Some(&id) = subtree.get(next) {
+                next += 1;
+                subtree.extend(owned_children(ast, id).map(|(_, child_id)| child_id));
+            }
+            subtree.sort_unstable_by_key(|id| id.to_raw());
+            subtree.dedup();
+            for id in subtree.into_iter().filter(|&id| is_included(id)) {
+                if let Some(node) = ast.nodes.get(id.to_raw().get() as usize) {
+                    writeln!(writer, "{id:6}: {node:?}")?;
+                }
+            }
+        },
+    }
+    writer.flush()
+}
+
+/// Iterates over the owning fields of a node which refer to nodes whose parent it is.
+///
+/// Checking the parent index keeps malformed ASTs with ownership cycles from being dumped endlessly.
+fn owned_children(
+    ast: &Ast,
+    id: GenericNodeId,
+) -> impl Iterator<Item = (&'static str, GenericNodeId)> {
+    ast.nodes
+        .get(id.to_raw().get() as usize)
+        .into_iter()
+        .flat_map(Node::owned_children)
+        .filter(move |&(_, child_id)| ast.parent(child_id) == Some(id))
+}
+
+fn write_tree(
+    ast: &Ast,
+    writer: &mut impl Write,
+    is_included: &impl Fn(GenericNodeId) -> bool,
+    field: Option<&str>,
+    id: GenericNodeId,
+    depth: usize,
+) -> io::Result<()> {
+    let indent = depth * 2;
+    let Some(node) = ast.nodes.get(id.to_raw().get() as usize) else {
+        return writeln!(writer, "{:indent$}{}#{id}: <missing>", "", Label(field));
+    };
+    writeln!(writer, "{:indent$}{}#{id}: {node:?}", "", Label(field))?;
+    for (child_field, child_id) in owned_children(ast, id) {
+        if is_included(child_id) {
+            write_tree(
+                ast,
+                writer,
+                is_included,
+                Some(child_field),
+                child_id,
+                depth + 1,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Field name prefix of a tree line.
+struct Label<'field>(Option<&'field str>);
+
+impl fmt::Display for Label<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(field) => write!(f, "{field}: "),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+    use crate::tests::load_fixture;
+
+    #[test]
+    fn test_dump() {
+        let ast = load_fixture();
+        let dump = |mode, include_standard_libraries| {
+            let mut output = Vec::new();
+            let options = DumpOptions {
+                mode,
+                include_standard_libraries,
+            };
+            ast.dump(&mut output, options).unwrap();
+            String::from_utf8(output).unwrap()
+        };
+
+        let debug = dump(DumpMode::Debug, true);
+        assert_eq!(debug.lines().count(), 20);
+        assert!(debug.starts_with("     0: <empty>\n     1: Library {"));
+
+        let process = GenericNodeId::from_raw(IdPrimitive::new(14).unwrap());
+        let tree = dump(DumpMode::Tree(process), true);
+        let labels = tree
+            .lines()
+            .map(|line| line.split_once('#').unwrap().0)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            [
+                "",
+                "  sequential_statements: ",
+                "    target: ",
+                "    waveforms: ",
+                "      we_value: ",
+                "  sensitivity_list: ",
+            ]
+        );
+
+        let design_unit = NodeId::<DesignUnit>::from_raw(IdPrimitive::new(4).unwrap());
+        let unit = dump(DumpMode::DesignUnit(design_unit), true);
+        let ids = unit
+            .lines()
+            .map(|line| line.split_once(':').unwrap().0.trim())
+            .collect::<Vec<_>>();
+        assert_eq!(ids, ["4", "5"]);
+
+        let as_std = FIXTURE.replace(r#"["work",null]"#, r#"["std",null]"#);
+        let std_ast = Ast::from_json(&mut as_std.as_bytes(), 1).unwrap().ast;
+        let mut output = Vec::new();
+        let options = DumpOptions {
+            mode: DumpMode::Debug,
+            include_standard_libraries: false,
+        };
+        std_ast.dump(&mut output, options).unwrap();
+        let without_std = String::from_utf8(output).unwrap();
+        assert_eq!(without_std, "     0: <empty>\n     2: Error\n");
+    }
+}
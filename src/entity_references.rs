@@ -0,0 +1,333 @@
+//! Index of the names referring to each named entity, see [`Ast::references_to`].
+
+use super::*;
+
+/// How a name referring to a named entity is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum ReferenceKind {
+    /// Any use not covered by the other kinds, such as an operand of an expression.
+    Read,
+    /// Assignment target, possibly indexed, sliced or selected (`a(i).f <= …`).
+    Write,
+    /// Actual of a port map association.
+    PortMapActual,
+    /// Formal of an association.
+    Formal,
+    /// Element of the sensitivity list of a process or `wait on` statement.
+    Sensitivity,
+}
+
+/// A denoting name which resolves to a named entity, see [`Ast::references_to`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EntityReference {
+    /// The referring name, e.g. a [`SimpleName`] or [`SelectedName`].
+    pub name: GenericNodeId,
+    /// How the name is used.
+    pub kind: ReferenceKind,
+}
+
+/// Adds the names from `first_index` on to the index of the names referring to each named entity.
+pub(crate) fn extend(
+    ast: &Ast,
+    first_index: usize,
+    index: &mut Map<NamedEntityNodeId, Vec<EntityReference>>,
+) {
+    for (position, node) in ast.nodes.iter().enumerate().skip(first_index) {
+        let named_entity = match node {
+            Node::SimpleName(name) => Some(name.named_entity),
+            Node::SelectedName(name) => Some(name.named_entity),
+            Node::AttributeName(name) => Some(name.named_entity),
+            Node::SelectedElement(name) => Some(name.named_entity),
+            Node::CharacterLiteral(literal) => Some(literal.named_entity),
+            Node::OperatorSymbol(name) => name.named_entity,
+            Node::ReferenceName(name) => name.named_entity,
+            _ => None,
+        };
+        let Some(named_entity) = named_entity else {
+            continue;
+        };
+        if matches!(
+            named_entity.try_get(ast),
+            Ok(NamedEntity::Unresolved(_)) | Err(_)
+        ) {
+            continue;
+        }
+        let Some(name) = u32::try_from(position).ok().and_then(IdPrimitive::new) else {
+            continue;
+        };
+        let name = GenericNodeId::from_raw(name);
+        index
+            .entry(named_entity)
+            .or_default()
+            .push(EntityReference {
+                name,
+                kind: classify(ast, name),
+            });
+    }
+}
+
+/// Classifies the use of a name by the fields through which its ancestors refer to it.
+fn classify(ast: &Ast, name: GenericNodeId) -> ReferenceKind {
+    let mut child = name;
+    while let Some(parent) = ast.parent(child) {
+        let Some(parent_node) = ast.nodes.get(parent.to_raw().get() as usize) else {
+            break;
+        };
+        let is_child = |id: GenericNodeId| id == child;
+        // Indexing, slicing and selecting an element use the prefix like the whole name.
+        if name_prefix(parent_node).is_some_and(|prefix| is_child(prefix.into())) {
+            child = parent;
+            continue;
+        }
+        if assignment_target(parent_node).is_some_and(|target| is_child(target.into())) {
+            return ReferenceKind::Write;
+        }
+        if let Some(SensitivityList::Signals(signals)) = sensitivity_list(parent_node)
+            && signals.iter().any(|&signal| is_child(signal.into()))
+        {
+            return ReferenceKind::Sensitivity;
+        }
+        if let Some((formal, actual)) = association(parent_node) {
+            if formal.is_some_and(|formal| is_child(formal.into())) {
+                return ReferenceKind::Formal;
+            }
+            if actual.is_some_and(is_child) && is_port_map_association(ast, parent) {
+                return ReferenceKind::PortMapActual;
+            }
+        }
+        break;
+    }
+    ReferenceKind::Read
+}
+
+/// Returns the prefix of a name which uses its prefix like the whole name.
+fn name_prefix(node: &Node) -> Option<PrefixNodeId> {
+    match node {
+        Node::IndexedName(name) => Some(name.prefix),
+        Node::SliceName(name) => Some(name.prefix),
+        Node::SelectedElement(name) => Some(name.prefix),
+        Node::Dereference(name) => Some(name.prefix),
+        Node::ImplicitDereference(name) => Some(name.prefix),
+        _ => None,
+    }
+}
+
+/// Returns the target of an assignment statement.
+fn assignment_target(node: &Node) -> Option<ExpressionNodeId> {
+    match node {
+        Node::SimpleSignalAssignmentStatement(statement) => Some(statement.target),
+        Node::VariableAssignmentStatement(statement) => Some(statement.target),
+        Node::ConditionalSignalAssignmentStatement(statement) => Some(statement.target),
+        Node::SelectedWaveformAssignmentStatement(statement) => Some(statement.target),
+        Node::SignalForceAssignmentStatement(statement) => Some(statement.target),
+        Node::SignalReleaseAssignmentStatement(statement) => Some(statement.target),
+        Node::ConditionalVariableAssignmentStatement(statement) => Some(statement.target),
+        Node::SelectedVariableAssignmentStatement(statement) => Some(statement.target),
+        Node::ConcurrentSimpleSignalAssignment(statement) => statement.target,
+        Node::ConcurrentSelectedSignalAssignment(statement) => Some(statement.target),
+        Node::ConcurrentConditionalSignalAssignment(statement) => Some(statement.target),
+        _ => None,
+    }
+}
+
+/// Returns the sensitivity list of a process, `wait` or `break` statement.
+fn sensitivity_list(node: &Node) -> Option<&SensitivityList> {
+    match node {
+        Node::WaitStatement(statement) => statement.sensitivity_list.as_ref(),
+        Node::SensitizedProcessStatement(statement) => Some(&statement.sensitivity_list),
+        Node::ConcurrentBreakStatement(statement) => statement.sensitivity_list.as_ref(),
+        _ => None,
+    }
+}
+
+/// Returns the formal and actual of an association element.
+fn association(node: &Node) -> Option<(Option<NameNodeId>, Option<GenericNodeId>)> {
+    match node {
+        Node::AssociationElementByExpression(element) => {
+            Some((element.formal, Some(element.actual.into())))
+        },
+        Node::AssociationElementByName(element) => {
+            Some((element.formal, Some(element.actual.into())))
+        },
+        Node::AssociationElementOpen(element) => Some((element.formal, None)),
+        Node::AssociationElementByIndividual(element) => Some((element.formal, None)),
+        Node::AssociationElementPackage(element) => {
+            Some((element.formal, Some(element.actual.into())))
+        },
+        Node::AssociationElementType(element) => {
+            Some((element.formal, Some(element.actual.into())))
+        },
+        Node::AssociationElementSubprogram(element) => {
+            Some((element.formal, Some(element.actual.into())))
+        },
+        Node::AssociationElementTerminal(element) => {
+            Some((element.formal, Some(element.actual.into())))
+        },
+        _ => None,
+    }
+}
+
+/// Returns whether the association element `id` is part of a port map aspect.
+fn is_port_map_association(ast: &Ast, id: GenericNodeId) -> bool {
+    let port_map_aspects = match ast
+        .parent(id)
+        .and_then(|parent| ast.nodes.get(parent.to_raw().get() as usize))
+    {
+        Some(Node::BindingIndication(binding)) => &binding.port_map_aspects,
+        Some(Node::BlockHeader(header)) => &header.port_map_aspects,
+        Some(Node::ComponentInstantiationStatement(instance)) => &instance.port_map_aspects,
+        _ => return false,
+    };
+    port_map_aspects
+        .iter()
+        .any(|&element| GenericNodeId::from(element) == id)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+
+    #[test]
+    fn test_references_to() {
+        const INSTANCE_CHUNK: &str = r#"{"first_id":20,"last_id":37,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"tb.vhd","start":20,"end":38}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"architecture_body":{"identifier":["tb",null],"entity_name":24,"parent":22,"declarations":[25],"concurrent_statements":[26,32]}}
+{"simple_name":{"identifier":["top",null],"named_entity":5}}
+{"signal_declaration":{"identifier":["s",null],"type":10}}
+{"component_instantiation_statement":{"label":["u",null],"instantiated_unit":27,"port_map_aspects":[29]}}
+{"entity_aspect_entity":{"entity_name":28}}
+{"simple_name":{"identifier":["top",null],"named_entity":5}}
+{"association_element_by_expression":{"formal":30,"actual":31,"inertial_flag":false}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9}}
+{"simple_name":{"identifier":["s",null],"named_entity":25}}
+{"concurrent_simple_signal_assignment":{"postponed_flag":false,"target":33,"delay_mechanism":"inertial","waveforms":[35]}}
+{"indexed_name":{"prefix":34,"index_list":[37],"type":10}}
+{"simple_name":{"identifier":["s",null],"named_entity":25}}
+{"waveform_element":{"we_value":36}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9}}
+{"integer_literal":{"value":0}}
+
+"#;
+
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut INSTANCE_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        let ast = builder.finish();
+        let references = |raw| {
+            ast.references_to(NamedEntityNodeId::from(IdPrimitive::new(raw).unwrap()))
+                .iter()
+                .map(|reference| (reference.name.to_raw().get(), reference.kind))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            references(9),
+            [
+                (16, ReferenceKind::Write),
+                (17, ReferenceKind::Sensitivity),
+                (30, ReferenceKind::Formal),
+                (36, ReferenceKind::Read),
+            ]
+        );
+        assert_eq!(
+            references(25),
+            [
+                (31, ReferenceKind::PortMapActual),
+                (34, ReferenceKind::Write)
+            ]
+        );
+        assert_eq!(
+            references(5),
+            [
+                (8, ReferenceKind::Read),
+                (24, ReferenceKind::Read),
+                (28, ReferenceKind::Read),
+            ]
+        );
+        assert!(references(14).is_empty());
+
+        let mut cache = Vec::new();
+        ast.save_binary(&mut cache).unwrap();
+        let cached = Ast::load_binary(cache.as_slice()).unwrap();
+        let signal_id = NamedEntityNodeId::from(IdPrimitive::new(25).unwrap());
+        assert_eq!(
+            cached.references_to(signal_id),
+            ast.references_to(signal_id)
+        );
+    }
+
+    #[test]
+    fn test_reference_kinds() {
+        const STATEMENTS_CHUNK: &str = r#"{"first_id":20,"last_id":46,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"tb.vhd","start":20,"end":47}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"architecture_body":{"identifier":["tb",null],"entity_name":24,"parent":22,"declarations":[25,26],"concurrent_statements":[29,37]}}
+{"simple_name":{"identifier":["top",null],"named_entity":5}}
+{"signal_declaration":{"identifier":["s",null],"type":10}}
+{"signal_declaration":{"identifier":["r",null],"type":27}}
+{"record_type_definition":{"elements_declaration_list":[28]}}
+{"element_declaration":{"identifier":["f",null],"type":10}}
+{"process_statement":{"postponed_flag":false,"sequential_statements":[30,35]}}
+{"simple_signal_assignment_statement":{"target":31,"delay_mechanism":"inertial","waveforms":[33]}}
+{"selected_element":{"prefix":32,"named_entity":28}}
+{"simple_name":{"identifier":["r",null],"named_entity":26}}
+{"waveform_element":{"we_value":34}}
+{"integer_literal":{"value":1}}
+{"wait_statement":{"sensitivity_list":[36]}}
+{"simple_name":{"identifier":["s",null],"named_entity":25}}
+{"component_instantiation_statement":{"label":["u",null],"instantiated_unit":38,"generic_map_aspects":[40],"port_map_aspects":[42]}}
+{"entity_aspect_entity":{"entity_name":39}}
+{"simple_name":{"identifier":["top",null],"named_entity":5}}
+{"association_element_by_expression":{"actual":41,"inertial_flag":false}}
+{"simple_name":{"identifier":["s",null],"named_entity":25}}
+{"association_element_by_expression":{"formal":43,"actual":45,"inertial_flag":false}}
+{"indexed_name":{"prefix":44,"index_list":[46],"type":10}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9}}
+{"simple_name":{"identifier":["s",null],"named_entity":25}}
+{"integer_literal":{"value":0}}
+
+"#;
+
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut STATEMENTS_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        let ast = builder.finish();
+        let references = |raw| {
+            ast.references_to(NamedEntityNodeId::from(IdPrimitive::new(raw).unwrap()))
+                .iter()
+                .map(|reference| (reference.name.to_raw().get(), reference.kind))
+                .collect::<Vec<_>>()
+        };
+
+        // `r.f <= 1;` writes both the record signal and its element.
+        assert_eq!(references(26), [(32, ReferenceKind::Write)]);
+        assert_eq!(references(28), [(31, ReferenceKind::Write)]);
+        // `wait on s;`, an actual in the generic map and one in the port map.
+        assert_eq!(
+            references(25),
+            [
+                (36, ReferenceKind::Sensitivity),
+                (41, ReferenceKind::Read),
+                (45, ReferenceKind::PortMapActual),
+            ]
+        );
+        // The indexed formal `clk(0) => s`.
+        assert_eq!(
+            references(9),
+            [
+                (16, ReferenceKind::Write),
+                (17, ReferenceKind::Sensitivity),
+                (44, ReferenceKind::Formal),
+            ]
+        );
+    }
+}
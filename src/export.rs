@@ -0,0 +1,125 @@
+//! Writing an [`Ast`] back as JSONL, see [`Ast::to_json`].
+
+use std::io;
+use std::io::Write;
+
+use super::*;
+use crate::nodes::LocatedNodeRef;
+
+/// Borrowed counterpart of [`AstMetadata`], written by [`Ast::to_json`].
+#[derive(Serialize)]
+struct AstMetadataRef<'ast> {
+    first_id: u32,
+    last_id: u32,
+    files: &'ast [FileMetadata],
+    libraries: &'ast [NodeId<Library>],
+}
+
+/// Writes `ast` as a single JSONL chunk, see [`Ast::to_json`].
+pub(crate) fn to_json(ast: &Ast, mut writer: impl Write) -> io::Result<()> {
+    let metadata = AstMetadataRef {
+        first_id: ast.first_id,
+        last_id: u32::try_from(ast.nodes.len().saturating_sub(1)).map_err(io::Error::other)?,
+        files: &ast.files,
+        libraries: &ast.library_ids,
+    };
+    serde_json::to_writer(&mut writer, &metadata)?;
+    writer.write_all(b"\n")?;
+    let first_index = ast.first_id as usize;
+    for (node, location) in iter::zip(&ast.nodes, &ast.locations).skip(first_index) {
+        if let Some(record) = LocatedNodeRef::new(node, *location) {
+            serde_json::to_writer(&mut writer, &record)?;
+        } else if let Node::Unknown { kind, raw } = node {
+            serde_json::to_writer(&mut writer, &Map::from_iter([(kind, raw)]))?;
+        } else {
+            writer.write_all(b"null")?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    writer.write_all(b"\n")?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+    use crate::tests::SECOND_CHUNK;
+
+    #[test]
+    fn test_json_round_trip() {
+        let input = FIXTURE
+            .replace(
+                r#"{"integer_literal":{"value":0}}"#,
+                r##"{"floating_point_literal":{"fp_value":"#3ff8000000000000"}}"##,
+            )
+            .replace(r#""waveform_element":"#, r#""waveform_element_2077":"#);
+        let options = LoadOptions { strict: false };
+        let ast = Ast::from_json_with_options(&mut input.as_bytes(), 1, options)
+            .unwrap()
+            .ast;
+
+        let mut json = Vec::new();
+        ast.to_json(&mut json).unwrap();
+        let loaded = Ast::from_json_with_options(&mut json.as_slice(), 1, options)
+            .unwrap()
+            .ast;
+
+        assert_eq!(format!("{:?}", loaded.nodes), format!("{:?}", ast.nodes));
+        assert_eq!(loaded.locations, ast.locations);
+        assert_eq!(loaded.parents, ast.parents);
+        assert_eq!(format!("{:?}", loaded.files), format!("{:?}", ast.files));
+        assert_eq!(loaded.library_ids, ast.library_ids);
+
+        let mut json_again = Vec::new();
+        loaded.to_json(&mut json_again).unwrap();
+        assert_eq!(
+            String::from_utf8(json_again),
+            String::from_utf8(json.clone())
+        );
+
+        // Exports can be concatenated like the chunks of a GHDL export.
+        let mut builder = AstBuilder::new().with_options(options);
+        let next_line_number = builder.append_json(&mut input.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut SECOND_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        let extended = builder.finish();
+        let mut extended_json = Vec::new();
+        extended.to_json(&mut extended_json).unwrap();
+        let concatenated = [json.as_slice(), extended_json.as_slice()].concat();
+        let mut reader = concatenated.as_slice();
+        let mut reloading_builder = AstBuilder::new().with_options(options);
+        let second_line_number = reloading_builder.append_json(&mut reader, 1).unwrap();
+        reloading_builder
+            .append_json(&mut reader, second_line_number)
+            .unwrap();
+        assert!(reader.is_empty());
+        let reloaded = reloading_builder.finish();
+        assert_eq!(
+            format!("{:?}", reloaded.nodes),
+            format!("{:?}", extended.nodes)
+        );
+        assert_eq!(reloaded.locations, extended.locations);
+        assert_eq!(reloaded.parents, extended.parents);
+        assert_eq!(
+            format!("{:?}", reloaded.files),
+            format!("{:?}", extended.files)
+        );
+        assert_eq!(reloaded.library_ids, extended.library_ids);
+    }
+
+    #[test]
+    fn test_json_round_trip_first_id() {
+        let input = r#"{"first_id":2,"last_id":3,"files":[],"libraries":[]}
+{"error":{}}
+{"integer_literal":{"value":7}}
+
+"#;
+        let ast = Ast::from_json(&mut input.as_bytes(), 1).unwrap().ast;
+        let mut json = Vec::new();
+        ast.to_json(&mut json).unwrap();
+        assert_eq!(String::from_utf8(json).unwrap(), input);
+    }
+}
@@ -45,7 +45,7 @@ subset_declaration!(Expression ExpressionOwned ExpressionNodeId {
     Attribute(Attribute),
     Dereference(Dereference),
     ImplicitDereference(ImplicitDereference),
-});
+} overlaps [DenotingName, Name, AnySelectedName, Prefix, RangeConstraint]);
 
 subset_declaration!(Literal LiteralOwned LiteralNodeId {
     Integer(IntegerLiteral),
@@ -54,26 +54,29 @@ subset_declaration!(Literal LiteralOwned LiteralNodeId {
     String(StringLiteral),
     PhysicalInt(PhysicalIntLiteral),
     PhysicalFp(PhysicalFpLiteral),
-});
+} subset_of [Expression ExpressionNodeId]);
 
 subset_declaration!(Allocator AllocatorOwned AllocatorNodeId {
     ByExpression(AllocatorByExpression),
     BySubtype(AllocatorBySubtype),
-});
-
-/// A unary operator application.
-///
-/// ```vhdl
-/// -a;  not en;  abs x;  ?? cond;  and vec;   -- reduction (VHDL-2008)
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct UnaryOperator {
-    /// Operator kind (identity, negation, `abs`, `not`, condition, reductions).
-    pub kind: UnaryOperatorKind,
-    /// Operand expression.
-    pub operand: ExpressionNodeId,
-    /// Resolved operator function implementation.
-    pub implementation: FunctionImplementationNodeId,
+} subset_of [Expression ExpressionNodeId]);
+
+node_struct! {
+    /// A unary operator application.
+    ///
+    /// ```vhdl
+    /// -a;  not en;  abs x;  ?? cond;  and vec;   -- reduction (VHDL-2008)
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct UnaryOperator {
+        /// Operator kind (identity, negation, `abs`, `not`, condition, reductions).
+        pub kind: UnaryOperatorKind,
+        /// Operand expression.
+        pub operand: ExpressionNodeId,
+        /// Resolved operator function implementation.
+        @reference
+        pub implementation: FunctionImplementationNodeId,
+    }
 }
 
 /// Kind of a unary operator.
@@ -112,23 +115,29 @@ pub enum UnaryOperatorKind {
     /// Reduction `xnor` (VHDL-2008).
     #[serde(rename = "xnor")]
     ReductionXnor,
-}
-
-/// A binary operator application.
-///
-/// ```vhdl
-/// a and b;  x + y;  srl 2;  left & right;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct BinaryOperator {
-    /// Operator kind (logical, relational, shifting, arithmetic, …).
-    pub kind: BinaryOperatorKind,
-    /// Left operand.
-    pub left: ExpressionNodeId,
-    /// Right operand.
-    pub right: ExpressionNodeId,
-    /// Resolved operator function implementation.
-    pub implementation: FunctionImplementationNodeId,
+    /// Operator not known to this crate (only produced by lenient loading).
+    #[serde(alias = "$unknown")]
+    Unknown,
+}
+
+node_struct! {
+    /// A binary operator application.
+    ///
+    /// ```vhdl
+    /// a and b;  x + y;  srl 2;  left & right;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct BinaryOperator {
+        /// Operator kind (logical, relational, shifting, arithmetic, …).
+        pub kind: BinaryOperatorKind,
+        /// Left operand.
+        pub left: ExpressionNodeId,
+        /// Right operand.
+        pub right: ExpressionNodeId,
+        /// Resolved operator function implementation.
+        @reference
+        pub implementation: FunctionImplementationNodeId,
+    }
 }
 
 /// Kind of a binary operator.
@@ -230,158 +239,194 @@ pub enum BinaryOperatorKind {
     /// Exponentiation (`**`).
     #[serde(rename = "**")]
     Exponentiation,
+    /// Operator not known to this crate (only produced by lenient loading).
+    #[serde(alias = "$unknown")]
+    Unknown,
+}
+
+node_struct! {
+    /// A function call expression.
+    ///
+    /// ```vhdl
+    /// rising_edge(clk);
+    /// ieee.numeric_std.to_integer(unsigned(a));
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct FunctionCall {
+        /// Prefix naming the called function (possibly a selected name).
+        pub prefix: PrefixNodeId,
+        /// Resolved function declaration or interface function.
+        @reference
+        pub implementation: FunctionImplementationNodeId,
+        /// Parameter associations of the call.
+        #[serde(default)]
+        pub parameter_associations: Vec<AssociationElementNodeId>,
+        /// Protected-type method object when this call is a method invocation.
+        @reference
+        pub method_object: Option<GenericNodeId>,
+        /// Return type of the call.
+        #[serde(rename = "type")]
+        @reference
+        pub return_type: SubtypeDefinitionNodeId,
+    }
 }
 
-/// A function call expression.
-///
-/// ```vhdl
-/// rising_edge(clk);
-/// ieee.numeric_std.to_integer(unsigned(a));
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct FunctionCall {
-    /// Prefix naming the called function (possibly a selected name).
-    pub prefix: PrefixNodeId,
-    /// Resolved function declaration or interface function.
-    pub implementation: FunctionImplementationNodeId,
-    /// Parameter associations of the call.
-    #[serde(default)]
-    pub parameter_associations: Vec<AssociationElementNodeId>,
-    /// Protected-type method object when this call is a method invocation.
-    pub method_object: Option<GenericNodeId>,
-    /// Return type of the call.
-    #[serde(rename = "type")]
-    pub return_type: SubtypeDefinitionNodeId,
-}
-
-/// An integer literal.
-///
-/// ```vhdl
-/// 42;  1_024;  16#FF#;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct IntegerLiteral {
-    /// Integer value after analysis.
-    pub value: i64,
+node_struct! {
+    /// An integer literal.
+    ///
+    /// ```vhdl
+    /// 42;  1_024;  16#FF#;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct IntegerLiteral {
+        /// Integer value after analysis.
+        pub value: i64,
+    }
 }
 
-/// A floating-point literal.
-///
-/// ```vhdl
-/// 3.14;  1.0e-3;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct FloatingPointLiteral {
-    /// Floating-point value (JSON encodes raw bits as `#` + hex).
-    #[serde(rename = "fp_value", deserialize_with = "deserialize_f64")]
-    pub value: f64,
+node_struct! {
+    /// A floating-point literal.
+    ///
+    /// ```vhdl
+    /// 3.14;  1.0e-3;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct FloatingPointLiteral {
+        /// Floating-point value (JSON encodes raw bits as `#` + hex).
+        #[serde(
+            rename = "fp_value",
+            serialize_with = "serialize_f64",
+            deserialize_with = "deserialize_f64"
+        )]
+        pub value: f64,
+    }
 }
 
-/// A physical literal with an integer abstract literal.
-///
-/// ```vhdl
-/// 10 ns;  1 sec;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct PhysicalIntLiteral {
-    /// Integer abstract literal.
-    pub value: i64,
-    /// Unit name (`ns`, `sec`, …).
-    pub unit_name: NameNodeId,
+node_struct! {
+    /// A physical literal with an integer abstract literal.
+    ///
+    /// ```vhdl
+    /// 10 ns;  1 sec;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct PhysicalIntLiteral {
+        /// Integer abstract literal.
+        pub value: i64,
+        /// Unit name (`ns`, `sec`, …).
+        pub unit_name: NameNodeId,
+    }
 }
 
-/// A physical literal with a floating-point abstract literal.
-///
-/// ```vhdl
-/// 0.5 ns;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct PhysicalFpLiteral {
-    /// Floating-point abstract literal.
-    #[serde(rename = "fp_value", deserialize_with = "deserialize_f64")]
-    pub value: f64,
-    /// Unit name (`ns`, `sec`, …).
-    pub unit_name: NameNodeId,
+node_struct! {
+    /// A physical literal with a floating-point abstract literal.
+    ///
+    /// ```vhdl
+    /// 0.5 ns;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct PhysicalFpLiteral {
+        /// Floating-point abstract literal.
+        #[serde(
+            rename = "fp_value",
+            serialize_with = "serialize_f64",
+            deserialize_with = "deserialize_f64"
+        )]
+        pub value: f64,
+        /// Unit name (`ns`, `sec`, …).
+        pub unit_name: NameNodeId,
+    }
 }
 
 subset_declaration!(PhysicalLiteral PhysicalLiteralOwned PhysicalLiteralNodeId {
     PhysicalInt(PhysicalIntLiteral),
     PhysicalFp(PhysicalFpLiteral),
-});
+} subset_of [Expression ExpressionNodeId, Literal LiteralNodeId]);
+
+node_struct! {
+    /// Wrapper for a literal known to overflow its target type.
+    ///
+    /// GHDL keeps the original literal under `literal_origin` so diagnostics and
+    /// tools can still inspect the written value.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct OverflowLiteral {
+        /// Original literal expression that overflowed.
+        pub literal_origin: ExpressionNodeId,
+    }
+}
 
-/// Wrapper for a literal known to overflow its target type.
-///
-/// GHDL keeps the original literal under `literal_origin` so diagnostics and
-/// tools can still inspect the written value.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct OverflowLiteral {
-    /// Original literal expression that overflowed.
-    pub literal_origin: ExpressionNodeId,
+node_struct! {
+    /// A range expression (`A to B` / `A downto B`).
+    ///
+    /// ```vhdl
+    /// 0 to 7;  7 downto 0;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct RangeExpression {
+        /// Range direction (`to` or `downto`).
+        pub direction: Direction,
+        /// Left bound of the range.
+        pub left_limit: ExpressionNodeId,
+        /// Right bound of the range.
+        pub right_limit: ExpressionNodeId,
+        /// Analyzed type of the range (typically the discrete/floating subtype).
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// A range expression (`A to B` / `A downto B`).
-///
-/// ```vhdl
-/// 0 to 7;  7 downto 0;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RangeExpression {
-    /// Range direction (`to` or `downto`).
-    pub direction: Direction,
-    /// Left bound of the range.
-    pub left_limit: ExpressionNodeId,
-    /// Right bound of the range.
-    pub right_limit: ExpressionNodeId,
-    /// Analyzed type of the range (typically the discrete/floating subtype).
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-}
-
-/// An aggregate expression.
-///
-/// Choices and associated expressions are the simulation-relevant content;
-/// optional GHDL [`AggregateInfo`] analysis metadata may hang off the node in
-/// the export but is not required to evaluate the aggregate.
-///
-/// ```vhdl
-/// (0 => '0', others => '1');
-/// (addr => x"00", data => x"FF");
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Aggregate {
-    /// Association choices of the aggregate.
-    #[serde(rename = "association_choices")]
-    pub associations: Vec<ChoiceNodeId>,
-    /// Type of the aggregate after analysis, when determined.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// An aggregate expression.
+    ///
+    /// Choices and associated expressions are the simulation-relevant content;
+    /// optional GHDL [`AggregateInfo`] analysis metadata may hang off the node in
+    /// the export but is not required to evaluate the aggregate.
+    ///
+    /// ```vhdl
+    /// (0 => '0', others => '1');
+    /// (addr => x"00", data => x"FF");
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct Aggregate {
+        /// Association choices of the aggregate.
+        #[serde(rename = "association_choices")]
+        pub associations: Vec<ChoiceNodeId>,
+        /// Type of the aggregate after analysis, when determined.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// A string literal (including bit-string literals after analysis).
-///
-/// ```vhdl
-/// "hello";  x"FF";  b"1010";
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct StringLiteral {
-    /// Latin-1 contents of the string.
-    #[serde(rename = "string8_id")]
-    pub value: Latin1String,
-    /// Optional origin expression when this literal was derived (e.g. expanded).
-    pub literal_origin: Option<ExpressionNodeId>,
+node_struct! {
+    /// A string literal (including bit-string literals after analysis).
+    ///
+    /// ```vhdl
+    /// "hello";  x"FF";  b"1010";
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct StringLiteral {
+        /// Latin-1 contents of the string.
+        #[serde(rename = "string8_id")]
+        pub value: Latin1String,
+        /// Optional origin expression when this literal was derived (e.g. expanded).
+        pub literal_origin: Option<ExpressionNodeId>,
+    }
 }
 
-/// An enumeration literal (including character enumeration values of a type).
-///
-/// ```vhdl
-/// true;  red;  '1';   -- '1' may also appear as CharacterLiteral in name contexts
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct EnumerationLiteral {
-    /// Position number of the literal in its enumeration type.
-    pub enum_pos: u32,
-    /// Identifier of the enumeration literal (may be a character literal name).
-    pub identifier: Identifier,
+node_struct! {
+    /// An enumeration literal (including character enumeration values of a type).
+    ///
+    /// ```vhdl
+    /// true;  red;  '1';   -- '1' may also appear as CharacterLiteral in name contexts
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct EnumerationLiteral {
+        /// Position number of the literal in its enumeration type.
+        pub enum_pos: u32,
+        /// Identifier of the enumeration literal (may be a character literal name).
+        pub identifier: Identifier,
+    }
 }
 
 /// Owned ISO-8859-1 (Latin-1) string as exported by GHDL string literals.
@@ -399,7 +444,9 @@ impl Serialize for Latin1String {
     where
         S: Serializer,
     {
-        let utf8 = if let Ok(string) = str::from_utf8(&self.characters) {
+        let utf8 = if self.characters.is_ascii()
+            && let Ok(string) = str::from_utf8(&self.characters)
+        {
             Cow::Borrowed(string)
         } else {
             Cow::Owned(
@@ -547,167 +594,197 @@ impl fmt::Debug for Latin1Str {
     }
 }
 
-/// A character literal used as a name or expression (`'X'`).
-///
-/// The identifier holds the character name (including the quotes in GHDL's
-/// identifier encoding). The named entity usually resolves to the corresponding
-/// enumeration literal of the character / enumeration type.
-///
-/// ```vhdl
-/// '1';  'Z';  'X';
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct CharacterLiteral {
-    /// Character name as written (e.g. `'1'`).
-    pub identifier: Identifier,
-    /// Resolved enumeration literal or other named entity.
-    pub named_entity: NamedEntityNodeId,
-    /// Type of the character literal.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
+node_struct! {
+    /// A character literal used as a name or expression (`'X'`).
+    ///
+    /// The identifier holds the character name (including the quotes in GHDL's
+    /// identifier encoding). The named entity usually resolves to the corresponding
+    /// enumeration literal of the character / enumeration type.
+    ///
+    /// ```vhdl
+    /// '1';  'Z';  'X';
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct CharacterLiteral {
+        /// Character name as written (e.g. `'1'`).
+        pub identifier: Identifier,
+        /// Resolved enumeration literal or other named entity.
+        @reference
+        pub named_entity: NamedEntityNodeId,
+        /// Type of the character literal.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+    }
 }
 
-/// A qualified expression (`type_mark'(expression)`).
-///
-/// Qualification states the type (or subtype) of an expression without
-/// converting its value. Contrast with [`TypeConversion`], which changes type.
-///
-/// ```vhdl
-/// integer'(1 + 2)          -- qualified: type is integer, value unchanged
-/// std_logic_vector'("01")  -- qualifies a bit-string / aggregate
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct QualifiedExpression {
-    /// Type mark naming the type or subtype.
-    pub type_mark: NameNodeId,
-    /// Qualified operand expression.
-    pub expression: ExpressionNodeId,
-    /// Type of the qualified expression after analysis.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
+node_struct! {
+    /// A qualified expression (`type_mark'(expression)`).
+    ///
+    /// Qualification states the type (or subtype) of an expression without
+    /// converting its value. Contrast with [`TypeConversion`], which changes type.
+    ///
+    /// ```vhdl
+    /// integer'(1 + 2)          -- qualified: type is integer, value unchanged
+    /// std_logic_vector'("01")  -- qualifies a bit-string / aggregate
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct QualifiedExpression {
+        /// Type mark naming the type or subtype.
+        pub type_mark: NameNodeId,
+        /// Qualified operand expression.
+        pub expression: ExpressionNodeId,
+        /// Type of the qualified expression after analysis.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+    }
 }
 
-/// The null access-value literal (`null`).
-///
-/// ```vhdl
-/// ptr := null;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct NullLiteral {
-    /// Access type of the null literal.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
+node_struct! {
+    /// The null access-value literal (`null`).
+    ///
+    /// ```vhdl
+    /// ptr := null;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct NullLiteral {
+        /// Access type of the null literal.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+    }
 }
 
-/// An allocator that initializes from a qualified expression (`new type_mark'(…)`).
-///
-/// ```vhdl
-/// ptr := new string'("hello");
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AllocatorByExpression {
-    /// Initial-value expression (typically a qualified expression).
-    pub expression: ExpressionNodeId,
-    /// Access type of the allocator result.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-    /// Designated (element) type of the allocated object.
-    pub allocator_designated_type: SubtypeDefinitionNodeId,
+node_struct! {
+    /// An allocator that initializes from a qualified expression (`new type_mark'(…)`).
+    ///
+    /// ```vhdl
+    /// ptr := new string'("hello");
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AllocatorByExpression {
+        /// Initial-value expression (typically a qualified expression).
+        pub expression: ExpressionNodeId,
+        /// Access type of the allocator result.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+        /// Designated (element) type of the allocated object.
+        @reference
+        pub allocator_designated_type: SubtypeDefinitionNodeId,
+    }
 }
 
-/// An allocator that creates an uninitialized object of a given subtype (`new subtype`).
-///
-/// ```vhdl
-/// ptr := new string(1 to 10);
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AllocatorBySubtype {
-    /// Subtype indication of the allocated object.
-    pub subtype_indication: SubtypeDefinitionNodeId,
-    /// Analyzed allocator subtype (often the same as `subtype_indication`).
-    pub allocator_subtype: SubtypeDefinitionNodeId,
-    /// Access type of the allocator result.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-    /// Designated (element) type of the allocated object.
-    pub allocator_designated_type: SubtypeDefinitionNodeId,
-}
-
-/// GHDL aggregate analysis helper attached to an [`Aggregate`].
-///
-/// Records bounds and choice-shape flags computed during analysis. Simulation
-/// should evaluate the aggregate from [`Aggregate::associations`]; these fields
-/// are optional analysis aids and may be incomplete depending on the export.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AggregateInfo {
-    /// Minimum length implied by positional / named choices.
-    #[serde(default)]
-    pub aggr_min_length: i32,
-    /// Whether an `others` choice is present.
-    #[serde(default)]
-    pub aggr_others_flag: bool,
-    /// Whether any choice bound is non-static (dynamic).
-    #[serde(default)]
-    pub aggr_dynamic_flag: bool,
-    /// Whether any named (choice => value) associations are present.
-    #[serde(default)]
-    pub aggr_named_flag: bool,
-}
-
-/// A parenthesized expression that preserves source parentheses in the AST.
-///
-/// ```vhdl
-/// (a + b) * c;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ParenthesisExpression {
-    /// Expression inside the parentheses.
-    pub expression: ExpressionNodeId,
-    /// Type of the parenthesized expression.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
+node_struct! {
+    /// An allocator that creates an uninitialized object of a given subtype (`new subtype`).
+    ///
+    /// ```vhdl
+    /// ptr := new string(1 to 10);
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AllocatorBySubtype {
+        /// Subtype indication of the allocated object.
+        pub subtype_indication: SubtypeDefinitionNodeId,
+        /// Analyzed allocator subtype (often the same as `subtype_indication`).
+        pub allocator_subtype: SubtypeDefinitionNodeId,
+        /// Access type of the allocator result.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+        /// Designated (element) type of the allocated object.
+        @reference
+        pub allocator_designated_type: SubtypeDefinitionNodeId,
+    }
 }
 
-/// A type conversion (`type_mark(expression)`).
-///
-/// Converts a value from one closely related type to another. This is distinct
-/// from a [`QualifiedExpression`], which only asserts a type without converting.
-///
-/// ```vhdl
-/// integer(1.5)                 -- type conversion: real → integer
-/// integer'(1 + 2)              -- qualified expression (not a conversion)
-/// std_logic_vector(unsigned_a) -- conversion between closely related array types
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct TypeConversion {
-    /// Target type mark of the conversion.
-    pub type_mark: NameNodeId,
-    /// Operand expression being converted.
-    pub expression: ExpressionNodeId,
-    /// Result type of the conversion.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-    /// Optional subtype produced for the conversion result.
-    pub type_conversion_subtype: Option<SubtypeDefinitionNodeId>,
-}
-
-/// A simple aggregate used by GHDL for expanded string / bit-string literals.
-///
-/// The list holds element literals (typically [`EnumerationLiteral`] nodes for
-/// character values). Prefer [`StringLiteral`] / [`Aggregate`] for source-level
-/// forms; this node appears when GHDL expands a literal into element-wise form.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SimpleAggregate {
-    /// Element literals of the expanded aggregate.
-    #[serde(default)]
-    pub simple_aggregate_list: Vec<NodeId<EnumerationLiteral>>,
-    /// Type of the simple aggregate.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
-    /// Optional origin expression (e.g. the original string literal).
-    pub literal_origin: Option<ExpressionNodeId>,
-    /// Optional literal subtype computed during analysis.
-    pub literal_subtype: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// GHDL aggregate analysis helper attached to an [`Aggregate`].
+    ///
+    /// Records bounds and choice-shape flags computed during analysis. Simulation
+    /// should evaluate the aggregate from [`Aggregate::associations`]; these fields
+    /// are optional analysis aids and may be incomplete depending on the export.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AggregateInfo {
+        /// Minimum length implied by positional / named choices.
+        #[serde(default)]
+        pub aggr_min_length: i32,
+        /// Whether an `others` choice is present.
+        #[serde(default)]
+        pub aggr_others_flag: bool,
+        /// Whether any choice bound is non-static (dynamic).
+        #[serde(default)]
+        pub aggr_dynamic_flag: bool,
+        /// Whether any named (choice => value) associations are present.
+        #[serde(default)]
+        pub aggr_named_flag: bool,
+    }
+}
+
+node_struct! {
+    /// A parenthesized expression that preserves source parentheses in the AST.
+    ///
+    /// ```vhdl
+    /// (a + b) * c;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ParenthesisExpression {
+        /// Expression inside the parentheses.
+        pub expression: ExpressionNodeId,
+        /// Type of the parenthesized expression.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+    }
+}
+
+node_struct! {
+    /// A type conversion (`type_mark(expression)`).
+    ///
+    /// Converts a value from one closely related type to another. This is distinct
+    /// from a [`QualifiedExpression`], which only asserts a type without converting.
+    ///
+    /// ```vhdl
+    /// integer(1.5)                 -- type conversion: real → integer
+    /// integer'(1 + 2)              -- qualified expression (not a conversion)
+    /// std_logic_vector(unsigned_a) -- conversion between closely related array types
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct TypeConversion {
+        /// Target type mark of the conversion.
+        pub type_mark: NameNodeId,
+        /// Operand expression being converted.
+        pub expression: ExpressionNodeId,
+        /// Result type of the conversion.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+        /// Optional subtype produced for the conversion result.
+        pub type_conversion_subtype: Option<SubtypeDefinitionNodeId>,
+    }
+}
+
+node_struct! {
+    /// A simple aggregate used by GHDL for expanded string / bit-string literals.
+    ///
+    /// The list holds element literals (typically [`EnumerationLiteral`] nodes for
+    /// character values). Prefer [`StringLiteral`] / [`Aggregate`] for source-level
+    /// forms; this node appears when GHDL expands a literal into element-wise form.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SimpleAggregate {
+        /// Element literals of the expanded aggregate.
+        #[serde(default)]
+        @reference
+        pub simple_aggregate_list: Vec<NodeId<EnumerationLiteral>>,
+        /// Type of the simple aggregate.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+        /// Optional origin expression (e.g. the original string literal).
+        pub literal_origin: Option<ExpressionNodeId>,
+        /// Optional literal subtype computed during analysis.
+        pub literal_subtype: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
 #[cfg(test)]
@@ -65,6 +65,12 @@ impl fmt::Debug for NormalizedIdentifier {
     }
 }
 
+impl Borrow<str> for NormalizedIdentifier {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
 impl PartialEq<str> for NormalizedIdentifier {
     fn eq(&self, other: &str) -> bool {
         debug_assert!(
@@ -11,31 +11,57 @@
 
 mod associations;
 mod attributes;
+mod builder;
+mod cache;
 mod choices;
 mod common;
 mod concurrent_statements;
 mod configuration;
+mod const_eval;
 mod declarations;
+mod dump;
+mod entity_references;
+mod export;
 mod expressions;
 mod identifier;
 mod libraries;
+mod lookups;
 mod names;
 mod nodes;
+mod predefined;
 mod psl;
+mod references;
+mod scope;
 mod sequential_statements;
 mod specifications;
+mod subtypes;
+mod symbols;
+mod top_level;
 mod types;
+pub mod visit;
+pub mod visit_mut;
 mod waveforms;
 
-use std::env;
 use std::fmt;
+#[cfg(feature = "parallel")]
+use std::fs::File;
+use std::io;
 use std::io::BufRead;
+use std::io::Read;
+use std::io::Write;
+use std::iter;
+use std::mem;
+use std::path::Path;
 use std::path::PathBuf;
 
-use anyhow::Context as _;
 use anyhow::Result;
 use anyhow::bail;
 use compact_str::CompactString;
+// Only used by the benchmarks.
+#[cfg(test)]
+use criterion as _;
+#[cfg(feature = "parallel")]
+use memmap2::Mmap;
 use serde::Deserialize;
 use serde::Deserializer;
 use serde::Serialize;
@@ -44,11 +70,22 @@ use tracing::debug;
 
 pub use self::associations::*;
 pub use self::attributes::*;
+pub use self::builder::AstBuilder;
+pub use self::builder::LoadError;
+pub use self::builder::LoadOptions;
+pub use self::builder::LoadWarning;
+pub use self::cache::CacheError;
 pub use self::choices::*;
 pub use self::common::*;
 pub use self::concurrent_statements::*;
 pub use self::configuration::*;
+pub use self::const_eval::EvalError;
+pub use self::const_eval::Evaluator;
 pub use self::declarations::*;
+pub use self::dump::DumpMode;
+pub use self::dump::DumpOptions;
+pub use self::entity_references::EntityReference;
+pub use self::entity_references::ReferenceKind;
 pub use self::expressions::*;
 pub use self::identifier::Identifier;
 pub use self::identifier::NormalizedIdentifier;
@@ -58,20 +95,47 @@ pub use self::nodes::AstNodeId;
 pub use self::nodes::DowncastNodeId;
 pub use self::nodes::GenericNodeId;
 pub use self::nodes::IdPrimitive;
+use self::nodes::LocatedNode;
+pub use self::nodes::LookupNodeError;
 pub use self::nodes::Node;
 pub use self::nodes::NodeId;
+pub use self::nodes::NodeKind;
+#[doc(hidden)]
+pub use self::nodes::SubsetRef;
 pub use self::nodes::TryFromNodeError;
 pub use self::nodes::deserialize_optional_node_id;
+pub use self::predefined::PredefinedError;
+pub use self::predefined::Value;
 pub use self::psl::*;
+#[doc(hidden)]
+pub use self::references::NodeReference;
+pub use self::references::ReferenceViolation;
+use self::references::node_struct;
+pub use self::scope::Scope;
 pub use self::sequential_statements::*;
 pub use self::specifications::*;
+pub use self::subtypes::TypeMark;
+pub use self::symbols::Symbol;
+pub use self::symbols::SymbolTable;
+pub use self::top_level::TopLevelCandidate;
+pub use self::top_level::TopLevelOptions;
 pub use self::types::*;
+pub use self::visit::Visitor;
+pub use self::visit_mut::VisitorMut;
 pub use self::waveforms::*;
 
+/// Items used by the expansion of [`subset_declaration!`] in other crates.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::nodes::contains_kinds;
+    pub use crate::nodes::shares_kind;
+}
+
 type Map<K, V> = rustc_hash::FxHashMap<K, V>;
+type Set<K> = rustc_hash::FxHashSet<K>;
 
 /// Source location of an AST node within a GHDL design file.
-#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(from = "(u32, u32, u32)", into = "(u32, u32, u32)")]
 pub struct Location {
     /// Index of the source file in GHDL's file table.
@@ -108,11 +172,26 @@ struct AstMetadata {
     libraries: Vec<NodeId<Library>>,
 }
 
+/// Entry of GHDL's source file table.
+///
+/// Nodes created while analyzing the file occupy the node ID range
+/// `start..end`.
 #[derive(Deserialize, Serialize)]
-struct FileMetadata {
-    source: GhdlSource,
-    start: usize,
-    end: usize,
+pub struct FileMetadata {
+    /// Origin of the file.
+    pub source: GhdlSource,
+    /// First node ID allocated for this file.
+    pub start: usize,
+    /// Node ID following the last node allocated for this file.
+    pub end: usize,
+}
+
+impl FileMetadata {
+    /// Returns whether the given node was created while analyzing this file.
+    #[must_use]
+    pub fn contains(&self, id: impl Into<GenericNodeId>) -> bool {
+        (self.start..self.end).contains(&(id.into().to_raw().get() as usize))
+    }
 }
 
 impl fmt::Debug for FileMetadata {
@@ -127,166 +206,522 @@ impl fmt::Debug for FileMetadata {
     }
 }
 
+/// Origin of an entry in GHDL's source file table.
 #[derive(Debug, Deserialize, Serialize)]
-enum GhdlSource {
+pub enum GhdlSource {
+    /// Pseudo file for nodes created when loading libraries.
     #[serde(rename = "*libraries*")]
     Libraries,
+    /// Pseudo file for nodes created from command line options.
     #[serde(rename = "*command line*")]
     CommandLine,
+    /// Pseudo file for the predefined `std.standard` package.
     #[serde(rename = "*std_standard*")]
     StdStandard,
+    /// An actual VHDL source file.
     #[serde(untagged)]
     File(PathBuf),
 }
 
+impl GhdlSource {
+    /// Returns the path of the source file, or `None` for GHDL's pseudo files.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            GhdlSource::File(path) => Some(path),
+            GhdlSource::Libraries | GhdlSource::CommandLine | GhdlSource::StdStandard => None,
+        }
+    }
+}
+
+impl fmt::Display for GhdlSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GhdlSource::Libraries => f.write_str("*libraries*"),
+            GhdlSource::CommandLine => f.write_str("*command line*"),
+            GhdlSource::StdStandard => f.write_str("*std_standard*"),
+            GhdlSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
 /// Result of loading an AST from a JSON stream.
+#[derive(Debug)]
 pub struct AstLoadingOutput {
     /// Loaded AST.
     pub ast: Ast,
-    /// Next 1-based line number after the last consumed JSONL record.
+    /// 1-based line number following the consumed input, see [`AstBuilder::append_json`].
     pub next_line_number: u32,
+    /// Input accepted despite not being understood (lenient mode only).
+    pub warnings: Vec<LoadWarning>,
+}
+
+/// Error returned by [`Ast::default_architecture`] for an entity without architectures.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error("entity {identifier} has no architecture")]
+pub struct NoArchitectureError {
+    /// Entity declaration which was looked up.
+    pub entity_declaration_id: NodeId<EntityDeclaration>,
+    /// Name of the entity.
+    pub identifier: Identifier,
 }
 
 /// Analyzed GHDL AST with typed node storage and design-unit lookup maps.
 #[derive(Debug)]
 pub struct Ast {
     nodes: Vec<Node>,
+    /// Source locations of the nodes, indexed like `nodes`.
+    locations: Vec<Option<Location>>,
+    /// GHDL's source file table.
+    files: Vec<FileMetadata>,
+    /// Lowest `first_id` of the loaded chunks, written back by [`Ast::to_json`].
+    first_id: u32,
+    /// Owning node of each node, indexed like `nodes`.
+    parents: Vec<Option<GenericNodeId>>,
+    /// Interned normalized identifiers of all nodes.
+    symbols: SymbolTable,
+    /// Symbol of the identifier or label of each node, indexed like `nodes`.
+    node_symbols: Vec<Option<Symbol>>,
+    /// Map from named entity node ID to the names referring to it, in node order.
+    entity_references: Map<NamedEntityNodeId, Vec<EntityReference>>,
+    /// Hash of the JSONL lines the AST was loaded from, see [`Ast::source_hash`].
+    source_hash: u64,
+    /// Library nodes listed in the metadata, in export order.
+    library_ids: Vec<NodeId<Library>>,
     /// Map from library identifier to library node ID.
-    libraries: Map<NormalizedIdentifier, NodeId<Library>>,
+    libraries: Map<Symbol, NodeId<Library>>,
     /// Map from (library node ID, package name) to package declaration node IDs.
-    package_declarations: Map<(NodeId<Library>, NormalizedIdentifier), NodeId<PackageDeclaration>>,
+    package_declarations: Map<(NodeId<Library>, Symbol), NodeId<PackageDeclaration>>,
     /// Map from (library node ID, entity name) to entity declaration node IDs.
-    entity_declarations: Map<(NodeId<Library>, NormalizedIdentifier), NodeId<EntityDeclaration>>,
+    entity_declarations: Map<(NodeId<Library>, Symbol), NodeId<EntityDeclaration>>,
+    /// Map from (library node ID, package name) to package body node IDs.
+    package_bodies: Map<(NodeId<Library>, Symbol), NodeId<PackageBody>>,
+    /// Map from (library node ID, package name) to package instantiation node IDs.
+    package_instantiations: Map<(NodeId<Library>, Symbol), NodeId<PackageInstantiationDeclaration>>,
+    /// Map from (library node ID, configuration name) to configuration declaration node IDs.
+    configuration_declarations: Map<(NodeId<Library>, Symbol), NodeId<ConfigurationDeclaration>>,
+    /// Map from (library node ID, context name) to context declaration node IDs.
+    context_declarations: Map<(NodeId<Library>, Symbol), NodeId<ContextDeclaration>>,
     /// Map from entity declaration node ID to their architectures.
     architecture_bodies: Map<NodeId<EntityDeclaration>, Vec<NodeId<ArchitectureBody>>>,
+    /// Map from entity declaration node ID to their configurations.
+    configurations: Map<NodeId<EntityDeclaration>, Vec<NodeId<ConfigurationDeclaration>>>,
 }
 
 impl Ast {
     /// Constructs an `Ast` from a JSON stream.
     ///
+    /// Reads a single chunk; use [`AstBuilder`] to combine several chunks into one AST.
+    ///
     /// # Errors
     ///
-    /// Returns an error if reading from the buffer or parsing the JSON fails.
+    /// Returns an error if reading from the buffer or parsing the JSON fails, or if
+    /// the chunk is inconsistent with its metadata.
     pub fn from_json(
         reader: &mut dyn BufRead,
-        mut next_line_number: u32,
-    ) -> Result<AstLoadingOutput> {
-        let mut line_buffer = String::new();
-        reader.read_line(&mut line_buffer)?;
-        let metadata: AstMetadata =
-            serde_json::from_str(&line_buffer).context("could not parse AST metadata")?;
-        debug!("AST metadata: {metadata:#?}");
-
-        let mut nodes = Vec::with_capacity(metadata.last_id as usize + 1);
-        for _ in 0..metadata.first_id {
-            nodes.push(Node::Empty);
-        }
-
-        loop {
-            next_line_number += 1;
-            line_buffer.clear();
-            reader.read_line(&mut line_buffer)?;
-            let line = line_buffer.trim();
-            if line.is_empty() {
-                break;
-            }
+        next_line_number: u32,
+    ) -> Result<AstLoadingOutput, LoadError> {
+        Self::from_json_with_options(reader, next_line_number, LoadOptions::default())
+    }
 
-            let node_opt = serde_json::from_str::<Option<Node>>(line)
-                .with_context(|| format!("parse error in line {next_line_number}: {line}"))?;
-            nodes.push(node_opt.unwrap_or(Node::Empty));
-        }
+    /// Constructs an `Ast` from a JSON stream, using the given options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the buffer or parsing the JSON fails, or if
+    /// the chunk is inconsistent with its metadata.
+    pub fn from_json_with_options(
+        reader: &mut dyn BufRead,
+        next_line_number: u32,
+        options: LoadOptions,
+    ) -> Result<AstLoadingOutput, LoadError> {
+        let mut builder = AstBuilder::new().with_options(options);
+        let next_line_number = builder.append_json(reader, next_line_number)?;
+        let warnings = builder.take_warnings();
+        Ok(AstLoadingOutput {
+            ast: builder.finish(),
+            next_line_number,
+            warnings,
+        })
+    }
 
-        if let Ok(path) = env::var("RISIM_DUMP_AST") {
-            use std::fs::File;
-            use std::io::BufWriter;
-            use std::io::Write as _;
+    /// Constructs an `Ast` from all JSONL chunks of an export file.
+    ///
+    /// The file is memory-mapped and its node records are parsed in parallel; see
+    /// [`AstBuilder::append_json_str`]. It must not be modified while loading.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be read or isn't valid UTF-8, if parsing
+    /// the JSON fails, or if a chunk is inconsistent with its metadata.
+    #[cfg(feature = "parallel")]
+    pub fn from_json_file(
+        path: impl AsRef<Path>,
+        options: LoadOptions,
+    ) -> Result<AstLoadingOutput, LoadError> {
+        let file = File::open(path)?;
+        // SAFETY: the file is only read, and callers must not modify it while it's
+        // being loaded (as documented above).
+        let map = unsafe { Mmap::map(&file)? };
+        let input = str::from_utf8(&map)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
 
-            let file = File::create(&path)
-                .with_context(|| format!("failed to create AST dump file at {path}"))?;
-            let mut writer = BufWriter::new(file);
-            for (index, node) in nodes.iter().enumerate() {
-                writeln!(writer, "{index:6}: {node:?}")?;
-            }
-        }
+        let mut builder = AstBuilder::new().with_options(options);
+        let next_line_number = builder.append_json_str(input, 1)?;
+        let warnings = builder.take_warnings();
+        Ok(AstLoadingOutput {
+            ast: builder.finish(),
+            next_line_number,
+            warnings,
+        })
+    }
 
-        let mut ast = Self {
-            nodes,
+    fn empty() -> Self {
+        Self {
+            nodes: Vec::new(),
+            locations: Vec::new(),
+            files: Vec::new(),
+            parents: Vec::new(),
+            symbols: SymbolTable::default(),
+            node_symbols: Vec::new(),
+            entity_references: Map::default(),
+            source_hash: 0,
+            first_id: 1,
+            library_ids: Vec::new(),
             libraries: Map::default(),
             package_declarations: Map::default(),
             entity_declarations: Map::default(),
+            package_bodies: Map::default(),
+            package_instantiations: Map::default(),
+            configuration_declarations: Map::default(),
+            context_declarations: Map::default(),
             architecture_bodies: Map::default(),
-        };
-        ast.build_maps(&metadata.libraries);
-        debug_assert!(
-            Error::GLOBAL_ID.try_get(&ast).is_ok(),
-            "sanity check for global error node failed",
-        );
+            configurations: Map::default(),
+        }
+    }
 
-        Ok(AstLoadingOutput {
-            ast,
-            next_line_number,
-        })
+    /// Stores a node at the given index, growing the node table as needed.
+    fn set_node(&mut self, index: usize, node: Node, location: Option<Location>) {
+        if index >= self.nodes.len() {
+            self.nodes.resize_with(index + 1, || Node::Empty);
+            self.locations.resize(index + 1, None);
+        }
+        self.nodes[index] = node;
+        self.locations[index] = location;
     }
 
-    fn build_maps(&mut self, libraries_list: &[NodeId<Library>]) {
-        let mut libraries = Map::<NormalizedIdentifier, NodeId<Library>>::default();
-        let mut package_declarations =
-            Map::<(NodeId<Library>, NormalizedIdentifier), NodeId<PackageDeclaration>>::default();
-        let mut entity_declarations =
-            Map::<(NodeId<Library>, NormalizedIdentifier), NodeId<EntityDeclaration>>::default();
-        let mut architecture_bodies =
-            Map::<NodeId<EntityDeclaration>, Vec<NodeId<ArchitectureBody>>>::default();
-
-        for &library_id in libraries_list {
-            let library = library_id.get(self);
-            libraries.insert(library.identifier.normalized.clone(), library_id);
-
-            for (library_unit_id, library_unit) in library.library_units_iter(self) {
-                match library_unit {
-                    LibraryUnit::PackageDeclaration(package) => {
-                        package_declarations.insert(
-                            (library_id, package.identifier.normalized.clone()),
-                            library_unit_id.downcast(),
-                        );
-                    },
-
-                    LibraryUnit::EntityDeclaration(entity_declaration) => {
-                        entity_declarations.insert(
-                            (library_id, entity_declaration.identifier.normalized.clone()),
-                            library_unit_id.downcast(),
-                        );
-                    },
-
-                    LibraryUnit::ArchitectureBody(architecture) => {
-                        let entity_name = architecture.entity_name.get(self);
-                        // TODO shouldn't we check that the id is of the correct variant rather than just down-casting?
-                        let entity_declaration_id = entity_name
-                            .named_entity()
-                            .expect("architecture entity_name should resolve") // TODO return Err(_) instead
-                            .downcast();
-                        architecture_bodies
-                            .entry(entity_declaration_id)
-                            .or_default()
-                            .push(library_unit_id.downcast());
-                    },
-
-                    _ => {
-                        // TODO
-                    },
+    /// Updates the parent index, the node symbols and the entity references for a newly loaded chunk.
+    ///
+    /// `chunk_start` is the index of the first node of the chunk. Only the nodes which
+    /// aren't indexed yet are visited, interning new identifiers; if the chunk replaced
+    /// nodes which are already indexed, all indexes are rebuilt. Returns the index of
+    /// the first node which has been indexed.
+    fn build_indexes(&mut self, chunk_start: usize) -> usize {
+        /// Collects the parent and symbol index entries of one node.
+        struct IndexSink<'index> {
+            node_id: GenericNodeId,
+            parents: &'index mut [Option<GenericNodeId>],
+            symbols: &'index mut SymbolTable,
+            symbol: Option<Symbol>,
+        }
+
+        impl references::FieldSink for IndexSink<'_> {
+            fn reference(&mut self, reference: references::Reference) {
+                if !reference.field.owning {
+                    return;
+                }
+                if let Some(parent @ None) =
+                    self.parents.get_mut(reference.id.to_raw().get() as usize)
+                {
+                    *parent = Some(self.node_id);
                 }
             }
+
+            fn identifier(&mut self, field: references::Field, identifier: &Identifier) {
+                let symbol = self.symbols.intern(&identifier.normalized);
+                if matches!(field.name, "identifier" | "label") {
+                    self.symbol.get_or_insert(symbol);
+                }
+            }
+        }
+
+        if chunk_start < self.parents.len() {
+            self.parents.clear();
+            self.node_symbols.clear();
+            self.entity_references.clear();
+        }
+        let first_index = self.parents.len();
+        self.parents.resize(self.nodes.len(), None);
+        self.node_symbols.resize(self.nodes.len(), None);
+
+        let nodes = self.nodes.iter().zip(&mut self.node_symbols).enumerate();
+        for (index, (node, node_symbol)) in nodes.skip(first_index) {
+            let Some(node_id) = u32::try_from(index).ok().and_then(IdPrimitive::new) else {
+                continue;
+            };
+            let mut sink = IndexSink {
+                node_id: GenericNodeId::from_raw(node_id),
+                parents: &mut self.parents,
+                symbols: &mut self.symbols,
+                symbol: None,
+            };
+            node.visit_fields(&mut sink);
+            *node_symbol = sink.symbol;
+        }
+
+        let mut references = mem::take(&mut self.entity_references);
+        entity_references::extend(self, first_index, &mut references);
+        self.entity_references = references;
+        first_index
+    }
+
+    /// Looks up a library unit by library and normalized name in one of the lookup maps.
+    fn lookup_library_unit<T>(
+        &self,
+        map: &Map<(NodeId<Library>, Symbol), NodeId<T>>,
+        library_id: NodeId<Library>,
+        identifier: &NormalizedIdentifier,
+    ) -> Option<NodeId<T>> {
+        let symbol = self.symbols.get(identifier)?;
+        map.get(&(library_id, symbol)).copied()
+    }
+
+    /// Iterates over all nodes in ID order, skipping empty slots.
+    pub fn iter_nodes(&self) -> impl Iterator<Item = (GenericNodeId, &Node)> {
+        self.nodes.iter().enumerate().filter_map(|(index, node)| {
+            let id = u32::try_from(index).ok().and_then(IdPrimitive::new)?;
+            (!matches!(node, Node::Empty)).then_some((GenericNodeId::from_raw(id), node))
+        })
+    }
+
+    /// Iterates over all nodes of a node type or subset in ID order.
+    ///
+    /// ```ignore
+    /// for (id, signal) in ast.nodes_of::<SignalDeclaration>() { /* … */ }
+    /// for (id, statement) in ast.nodes_of::<ConcurrentStatement<'_>>() { /* … */ }
+    /// ```
+    pub fn nodes_of<'ast, K>(
+        &'ast self,
+    ) -> impl Iterator<Item = (K::Id, <K::Id as AstNodeId>::NodeType<'ast>)>
+    where
+        K: NodeKind,
+        <K::Id as AstNodeId>::NodeType<'ast>: TryFrom<&'ast Node>,
+    {
+        self.iter_nodes().filter_map(|(id, node)| {
+            let typed = node.try_into().ok()?;
+            Some((K::Id::from(id.to_raw()), typed))
+        })
+    }
+
+    /// Returns the source location of the given node, if GHDL recorded one.
+    #[must_use]
+    pub fn location(&self, id: impl Into<GenericNodeId>) -> Option<Location> {
+        self.locations
+            .get(id.into().to_raw().get() as usize)
+            .copied()
+            .flatten()
+    }
+
+    /// Returns the node owning the given node.
+    ///
+    /// Only owning fields (e.g. `concurrent_statements`, `left_limit`) are taken into
+    /// account, not references such as `type` or `named_entity`. Returns `None` for
+    /// libraries and for nodes which aren't owned by any node, e.g. some implicitly
+    /// created anonymous types.
+    #[must_use]
+    pub fn parent(&self, id: impl Into<GenericNodeId>) -> Option<GenericNodeId> {
+        self.parents
+            .get(id.into().to_raw().get() as usize)
+            .copied()
+            .flatten()
+    }
+
+    /// Iterates over the ancestors of the given node, starting with its parent.
+    pub fn ancestors(&self, id: impl Into<GenericNodeId>) -> impl Iterator<Item = GenericNodeId> {
+        iter::successors(self.parent(id), |&id| self.parent(id))
+    }
+
+    /// Returns the named entities visible at the given node.
+    ///
+    /// The scope covers the declarative regions of entities, architectures, packages and
+    /// package bodies, blocks, processes, subprogram bodies, generate bodies and the
+    /// parameters of `for` loops and generates enclosing the node, including the node itself.
+    /// Declarations of the region containing the node are only visible up to the
+    /// declaration containing it. The context clause of the design unit (and of the primary
+    /// unit of a secondary unit) and use clauses in the regions are applied, along with the
+    /// implicit `library std, work; use std.standard.all;`.
+    #[must_use]
+    pub fn scope(&self, id: impl Into<GenericNodeId>) -> Scope<'_> {
+        scope::scope(self, id.into())
+    }
+
+    /// Returns the table of interned normalized identifiers.
+    #[must_use]
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    /// Returns the symbol of the given node's identifier or label.
+    ///
+    /// Returns `None` for nodes without an identifier or label, such as
+    /// expressions, and for unlabeled statements.
+    #[must_use]
+    pub fn symbol(&self, id: impl Into<GenericNodeId>) -> Option<Symbol> {
+        self.node_symbols
+            .get(id.into().to_raw().get() as usize)
+            .copied()
+            .flatten()
+    }
+
+    /// Returns the denoting names which resolve to the given named entity, in node order.
+    ///
+    /// Each reference is classified by how the name is used: as an assignment target, in a
+    /// port map, in a sensitivity list, as a formal, or read otherwise. Names of unresolved
+    /// entities aren't indexed.
+    #[must_use]
+    pub fn references_to(&self, named_entity: impl Into<NamedEntityNodeId>) -> &[EntityReference] {
+        self.entity_references
+            .get(&named_entity.into())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns GHDL's source file table.
+    ///
+    /// [`Location::file_name`] is a 1-based index into this table.
+    #[must_use]
+    pub fn files(&self) -> &[FileMetadata] {
+        &self.files
+    }
+
+    /// Returns the source file table entry referenced by the given location.
+    #[must_use]
+    pub fn location_file(&self, location: Location) -> Option<&FileMetadata> {
+        let index = location.file_name.checked_sub(1)?;
+        self.files.get(index as usize)
+    }
+
+    /// Returns the source file table entry from which the given design file was analyzed.
+    #[must_use]
+    pub fn design_file_source(&self, design_file_id: NodeId<DesignFile>) -> Option<&FileMetadata> {
+        self.files.iter().find(|file| file.contains(design_file_id))
+    }
+
+    /// Writes the AST as a single JSONL chunk in the format read by [`Ast::from_json`].
+    ///
+    /// The output consists of a metadata line followed by one record per node
+    /// from the lowest `first_id` of the loaded chunks, with `null` for empty
+    /// slots, and the empty line terminating the chunk. Source locations are
+    /// written back into the records, and nodes of unknown kinds are written as
+    /// they were read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn to_json(&self, writer: impl Write) -> io::Result<()> {
+        export::to_json(self, writer)
+    }
+
+    /// Writes a human-readable dump of the AST for debugging.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn dump(&self, writer: impl Write, options: DumpOptions) -> io::Result<()> {
+        dump::dump(self, writer, options)
+    }
+
+    /// Writes the AST in a compact binary format which can be read back with [`Ast::load_binary`].
+    ///
+    /// The writer should be buffered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn save_binary(&self, writer: impl Write) -> Result<(), CacheError> {
+        cache::save(self, writer)
+    }
+
+    /// Reads an AST written by [`Ast::save_binary`].
+    ///
+    /// Caches written by another version of this crate are rejected. Whether
+    /// the cache is older than the analyzed design is up to the caller to
+    /// decide, e.g. with [`Ast::load_binary_for_source`] or by comparing its
+    /// modification time with GHDL's library files. The reader should be buffered.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CacheError::NotACache`] or [`CacheError::Stale`] if the input
+    /// isn't a cache written by this crate version, and another error if
+    /// reading or decoding fails.
+    pub fn load_binary(reader: impl Read) -> Result<Self, CacheError> {
+        cache::load(reader, None)
+    }
+
+    /// Reads an AST written by [`Ast::save_binary`], provided it was loaded from the JSONL export with the given hash.
+    ///
+    /// `source_hash` is usually computed by [`Ast::hash_json`] from the current
+    /// export, which is much faster than loading it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CacheError::Outdated`] if the cached AST was loaded from another
+    /// export, and the errors of [`Ast::load_binary`] otherwise.
+    pub fn load_binary_for_source(reader: impl Read, source_hash: u64) -> Result<Self, CacheError> {
+        cache::load(reader, Some(source_hash))
+    }
+
+    /// Returns a hash of the JSONL lines the AST was loaded from, as written to binary caches.
+    ///
+    /// Blank lines and surrounding whitespace are ignored, so the hash of an AST
+    /// loaded from several chunks equals [`Ast::hash_json`] of their concatenation.
+    #[must_use]
+    pub fn source_hash(&self) -> u64 {
+        self.source_hash
+    }
+
+    /// Computes the [`Ast::source_hash`] of an AST loaded from the given JSONL input, without parsing it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the buffer fails.
+    pub fn hash_json(reader: &mut dyn BufRead) -> io::Result<u64> {
+        let mut source_hash = 0;
+        let mut line = String::new();
+        while reader.read_line(&mut line)? != 0 {
+            source_hash = cache::hash_source_line(source_hash, &line);
+            line.clear();
         }
+        Ok(source_hash)
+    }
 
-        self.libraries = libraries;
-        self.package_declarations = package_declarations;
-        self.entity_declarations = entity_declarations;
-        self.architecture_bodies = architecture_bodies;
+    /// Checks that every node reference points to a node of a kind accepted by its field.
+    ///
+    /// References from and to nodes of unknown kinds (see [`LoadOptions::strict`]) are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns all references to empty or out-of-range slots and to nodes of
+    /// the wrong kind, in node order.
+    pub fn validate(&self) -> Result<(), Vec<ReferenceViolation>> {
+        let mut violations = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let Some(raw) = u32::try_from(index).ok().and_then(IdPrimitive::new) else {
+                continue;
+            };
+            references::check_references(self, GenericNodeId::from_raw(raw), node, &mut violations);
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
     }
 
     /// Looks up a library by its normalized identifier.
     #[must_use]
     pub fn lookup_library(&self, identifier: &NormalizedIdentifier) -> Option<NodeId<Library>> {
-        self.libraries.get(identifier).copied()
+        let symbol = self.symbols.get(identifier)?;
+        self.libraries.get(&symbol).copied()
     }
 
     /// Returns the single user library, or an error if none or multiple are found.
@@ -298,9 +733,10 @@ impl Ast {
         let mut libraries = self
             .libraries
             .iter()
-            .filter(|(name, _)| !["std", "ieee"].contains(&name.as_str()));
+            .map(|(&symbol, &library_id)| (self.symbols.resolve(symbol), library_id))
+            .filter(|(name, _)| !top_level::STANDARD_LIBRARIES.contains(&name.as_str()));
 
-        let Some((library_name, &library_id)) = libraries.next() else {
+        let Some((library_name, library_id)) = libraries.next() else {
             bail!("no library found");
         };
         if libraries.next().is_some() {
@@ -311,34 +747,40 @@ impl Ast {
 
     /// Looks up a package declaration by library and normalized package name.
     #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "the name has always been taken by value"
+    )]
     pub fn lookup_package_declaration(
         &self,
         library_id: NodeId<Library>,
         identifier: NormalizedIdentifier,
     ) -> Option<NodeId<PackageDeclaration>> {
-        self.package_declarations
-            .get(&(library_id, identifier))
-            .copied()
+        self.lookup_library_unit(&self.package_declarations, library_id, &identifier)
     }
 
     /// Returns the map of all package declarations keyed by library and name.
+    ///
+    /// Use [`Ast::symbols`] to resolve the names.
     #[must_use]
     pub fn package_declarations(
         &self,
-    ) -> &Map<(NodeId<Library>, NormalizedIdentifier), NodeId<PackageDeclaration>> {
+    ) -> &Map<(NodeId<Library>, Symbol), NodeId<PackageDeclaration>> {
         &self.package_declarations
     }
 
     /// Looks up an entity declaration by library and normalized entity name.
     #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "the name has always been taken by value"
+    )]
     pub fn lookup_entity_declaration(
         &self,
         library_id: NodeId<Library>,
         identifier: NormalizedIdentifier,
     ) -> Option<NodeId<EntityDeclaration>> {
-        self.entity_declarations
-            .get(&(library_id, identifier))
-            .copied()
+        self.lookup_library_unit(&self.entity_declarations, library_id, &identifier)
     }
 
     /// Returns the single entity declaration in the given library, or an error if none or multiple are found.
@@ -366,7 +808,96 @@ impl Ast {
         Ok((&entity_declaration.identifier, entity_id))
     }
 
-    /// Returns the architecture bodies associated with the given entity declaration.
+    /// Returns the entities and configurations which aren't instantiated by any other unit.
+    ///
+    /// Only the units of the given library are candidates, or those of all libraries which
+    /// aren't ignored by `options` if `library_id` is `None`. A component instantiation counts
+    /// as an instantiation of the entity of the same name in the library of the instantiating
+    /// unit. The candidates are returned in library and analysis order, and there are none if
+    /// `library_id` isn't a library of this AST.
+    #[must_use]
+    pub fn top_level_candidates(
+        &self,
+        library_id: Option<NodeId<Library>>,
+        options: &TopLevelOptions,
+    ) -> Vec<TopLevelCandidate> {
+        top_level::top_level_candidates(self, library_id, options)
+    }
+
+    /// Returns the base type of the given type or subtype definition.
+    ///
+    /// Follows the `parent_type` of subtype definitions, and the completion of incomplete
+    /// type definitions, up to an integer, floating, physical, enumeration, array, record,
+    /// access or file type definition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a node of the chain is missing, a subtype has no parent type, an
+    /// incomplete type is never completed, or the chain is cyclic.
+    pub fn base_type(
+        &self,
+        definition: impl Into<TypeAndSubtypeDefinitionNodeId>,
+    ) -> Result<TypeAndSubtypeDefinitionNodeId> {
+        subtypes::base_type(self, definition.into())
+    }
+
+    /// Returns the declaration naming the given type or subtype definition, e.g. the
+    /// declaration of `std_logic_vector` for the subtype of `signal s : std_logic_vector(7 downto 0)`.
+    ///
+    /// This is the declaration owning the definition or, for anonymous subtypes, the
+    /// declaration denoted by its type mark. Otherwise the parent types are searched.
+    #[must_use]
+    pub fn type_declaration_of(
+        &self,
+        definition: impl Into<TypeAndSubtypeDefinitionNodeId>,
+    ) -> Option<TypeMark> {
+        subtypes::type_declaration_of(self, definition.into())
+    }
+
+    /// Returns whether `subtype` is the type or subtype `of`, or derived from it through its
+    /// chain of parent types.
+    ///
+    /// Returns `false` if the chain of `subtype` is broken before reaching `of`.
+    #[must_use]
+    pub fn is_subtype_of(
+        &self,
+        subtype: impl Into<TypeAndSubtypeDefinitionNodeId>,
+        of: impl Into<TypeAndSubtypeDefinitionNodeId>,
+    ) -> bool {
+        subtypes::is_subtype_of(self, subtype.into(), of.into())
+    }
+
+    /// Returns the type designated by the given access type or subtype.
+    ///
+    /// An incomplete designated type is replaced by its completion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the definition isn't an access type or subtype, or if its
+    /// designated type is missing or never completed.
+    pub fn designated_type(
+        &self,
+        access: impl Into<TypeAndSubtypeDefinitionNodeId>,
+    ) -> Result<TypeAndSubtypeDefinitionNodeId> {
+        subtypes::designated_type(self, access.into())
+    }
+
+    /// Evaluates a static expression (LRM §9.4) without bound generics.
+    ///
+    /// Use an [`Evaluator`] to bind the values of generics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error pointing at the offending node if the expression isn't static, or if
+    /// its evaluation overflows or divides by zero.
+    pub fn evaluate_static(
+        &self,
+        expression: impl Into<ExpressionNodeId>,
+    ) -> Result<Value, EvalError> {
+        Evaluator::new(self).evaluate(expression.into())
+    }
+
+    /// Returns the architecture bodies associated with the given entity declaration, in analysis order.
     #[must_use]
     pub fn lookup_architecture_bodies(
         &self,
@@ -376,6 +907,112 @@ impl Ast {
             .get(&entity_declaration_id)
             .map_or(&[], Vec::as_slice)
     }
+
+    /// Returns the default architecture of the given entity declaration.
+    ///
+    /// This is the most recently analyzed architecture of the entity, which an entity aspect
+    /// without an architecture identifier binds to (LRM §7.3.3). The analysis order is taken
+    /// from the export: design files are assumed to be listed by their library in the order
+    /// they were analyzed, as GHDL does, and a re-analyzed architecture replaces the earlier
+    /// one of the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NoArchitectureError`] if the entity has no architecture.
+    pub fn default_architecture(
+        &self,
+        entity_declaration_id: NodeId<EntityDeclaration>,
+    ) -> Result<NodeId<ArchitectureBody>, NoArchitectureError> {
+        let Some(&architecture_id) = self
+            .lookup_architecture_bodies(entity_declaration_id)
+            .last()
+        else {
+            return Err(NoArchitectureError {
+                entity_declaration_id,
+                identifier: entity_declaration_id.get(self).identifier.clone(),
+            });
+        };
+        Ok(architecture_id)
+    }
+
+    /// Returns the configuration declarations of the given entity declaration.
+    #[must_use]
+    pub fn configurations_of(
+        &self,
+        entity_declaration_id: NodeId<EntityDeclaration>,
+    ) -> &[NodeId<ConfigurationDeclaration>] {
+        self.configurations
+            .get(&entity_declaration_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Looks up the package body of a package by library and normalized package name.
+    #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "like the other library unit lookups"
+    )]
+    pub fn lookup_package_body(
+        &self,
+        library_id: NodeId<Library>,
+        identifier: NormalizedIdentifier,
+    ) -> Option<NodeId<PackageBody>> {
+        self.lookup_library_unit(&self.package_bodies, library_id, &identifier)
+    }
+
+    /// Looks up a package instantiation declaration by library and normalized name.
+    #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "like the other library unit lookups"
+    )]
+    pub fn lookup_package_instantiation(
+        &self,
+        library_id: NodeId<Library>,
+        identifier: NormalizedIdentifier,
+    ) -> Option<NodeId<PackageInstantiationDeclaration>> {
+        self.lookup_library_unit(&self.package_instantiations, library_id, &identifier)
+    }
+
+    /// Looks up a configuration declaration by library and normalized configuration name.
+    #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "like the other library unit lookups"
+    )]
+    pub fn lookup_configuration_declaration(
+        &self,
+        library_id: NodeId<Library>,
+        identifier: NormalizedIdentifier,
+    ) -> Option<NodeId<ConfigurationDeclaration>> {
+        self.lookup_library_unit(&self.configuration_declarations, library_id, &identifier)
+    }
+
+    /// Looks up a context declaration by library and normalized context name.
+    #[must_use]
+    #[expect(
+        clippy::needless_pass_by_value,
+        reason = "like the other library unit lookups"
+    )]
+    pub fn lookup_context_declaration(
+        &self,
+        library_id: NodeId<Library>,
+        identifier: NormalizedIdentifier,
+    ) -> Option<NodeId<ContextDeclaration>> {
+        self.lookup_library_unit(&self.context_declarations, library_id, &identifier)
+    }
+}
+
+/// Serializes an `f64` as string in the format `#BITS`, the inverse of [`deserialize_f64`].
+///
+/// # Errors
+///
+/// Returns `Err(_)` if the serializer fails to serialize a string.
+pub fn serialize_f64<S>(value: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format!("#{bits:016x}", bits = value.to_bits()))
 }
 
 /// Deserializes an `f64` which has been encoded as string in the format `#BITS`,
@@ -394,10 +1031,154 @@ where
 {
     use serde::de::Error;
 
-    let string_repr = <&str>::deserialize(deserializer)?;
+    let string_repr = CompactString::deserialize(deserializer)?;
     let bits_str = string_repr
         .strip_prefix('#')
         .ok_or_else(|| D::Error::custom("missing # prefix"))?;
     let bits = u64::from_str_radix(bits_str, 16).map_err(D::Error::custom)?;
     Ok(f64::from_bits(bits))
 }
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+
+    /// Minimal GHDL export of:
+    ///
+    /// ```vhdl
+    /// entity top is
+    /// end entity top;
+    ///
+    /// architecture rtl of top is
+    ///   signal clk : integer range 0 to 1;
+    /// begin
+    ///   process (clk)
+    ///   begin
+    ///     clk <= 1;
+    ///   end process;
+    /// end architecture rtl;
+    /// ```
+    pub(crate) const FIXTURE: &str = r#"{"first_id":1,"last_id":19,"files":[{"source":"top.vhd","start":1,"end":20}],"libraries":[1]}
+{"library_declaration":{"identifier":["work",null],"design_files":[3]}}
+{"error":{}}
+{"design_file":{"design_units":[4,6]}}
+{"design_unit":{"library_unit":5,"design_file":3,"location":[1,1,1]}}
+{"entity_declaration":{"id":5,"identifier":["top",null],"parent":4,"location":[1,1,8]}}
+{"design_unit":{"library_unit":7,"design_file":3,"location":[1,4,1]}}
+{"architecture_body":{"location":[1,4,14],"identifier":["rtl",null],"entity_name":8,"parent":6,"declarations":[9],"concurrent_statements":[14]}}
+{"simple_name":{"identifier":["top",null],"named_entity":5,"location":[1,4,21]}}
+{"signal_declaration":{"identifier":["clk",null],"type":10,"subtype_indication":10,"location":[1,5,10]}}
+{"integer_subtype_definition":{"range_constraint":11}}
+{"range_expression":{"direction":"to","left_limit":12,"right_limit":13}}
+{"integer_literal":{"value":0}}
+{"integer_literal":{"value":1}}
+{"sensitized_process_statement":{"postponed_flag":false,"sequential_statements":[15],"sensitivity_list":[17],"location":[1,7,3]}}
+{"simple_signal_assignment_statement":{"target":16,"delay_mechanism":"inertial","waveforms":[18],"location":[1,9,9]}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9,"location":[1,9,5]}}
+{"simple_name":{"identifier":["clk",null],"named_entity":9,"location":[1,7,12]}}
+{"waveform_element":{"we_value":19}}
+{"integer_literal":{"value":1,"location":[1,9,12]}}
+
+"#;
+
+    /// Chunk following [`FIXTURE`], which adds a library with a single entity.
+    pub(crate) const SECOND_CHUNK: &str = r#"{"first_id":20,"last_id":23,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"leaf.vhd","start":20,"end":24}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"entity_declaration":{"id":23,"identifier":["leaf",null],"parent":22,"location":[2,1,8]}}
+
+"#;
+
+    pub(crate) fn load_fixture() -> Ast {
+        Ast::from_json(&mut FIXTURE.as_bytes(), 1).unwrap().ast
+    }
+
+    #[test]
+    fn test_node_locations() {
+        let ast = load_fixture();
+
+        let location_of = |id| ast.location(GenericNodeId::from_raw(IdPrimitive::new(id).unwrap()));
+        assert_eq!(
+            location_of(5),
+            Some(Location {
+                file_name: 1,
+                line: 1,
+                column: 8,
+            })
+        );
+        assert_eq!(
+            location_of(7),
+            Some(Location {
+                file_name: 1,
+                line: 4,
+                column: 14,
+            })
+        );
+        assert_eq!(location_of(10), None);
+        assert_eq!(location_of(1000), None);
+
+        let work = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap();
+        let top = ast
+            .lookup_entity_declaration(work, NormalizedIdentifier::new("top"))
+            .unwrap();
+        assert_eq!(ast.location(top).map(|location| location.line), Some(1));
+
+        let architecture = ast.lookup_architecture_bodies(top)[0].get(&ast);
+        assert_eq!(
+            ast.location(architecture.entity_name),
+            Some(Location {
+                file_name: 1,
+                line: 4,
+                column: 21,
+            })
+        );
+    }
+
+    #[test]
+    fn test_source_files() {
+        let ast = load_fixture();
+
+        assert_eq!(ast.files().len(), 1);
+        let top = GenericNodeId::from_raw(IdPrimitive::new(5).unwrap());
+        let file = ast.location_file(ast.location(top).unwrap()).unwrap();
+        assert_eq!(file.source.path(), Some(Path::new("top.vhd")));
+        assert!(file.contains(top));
+
+        let design_file = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap()
+            .get(&ast)
+            .design_files[0];
+        let design_file_entry = ast.design_file_source(design_file).unwrap();
+        assert_eq!(design_file_entry.source.to_string(), "top.vhd");
+
+        let location = Location {
+            file_name: 0,
+            line: 1,
+            column: 1,
+        };
+        assert!(ast.location_file(location).is_none());
+    }
+
+    #[test]
+    fn test_parents() {
+        let ast = load_fixture();
+        let id = |raw| GenericNodeId::from_raw(IdPrimitive::new(raw).unwrap());
+
+        // Owning fields.
+        assert_eq!(ast.parent(id(12)), Some(id(11)));
+        assert_eq!(ast.parent(id(10)), Some(id(9)));
+        assert_eq!(ast.parent(id(7)), Some(id(6)));
+        // References don't make a node the parent.
+        assert_eq!(ast.parent(id(5)), Some(id(4)));
+        assert_eq!(ast.parent(id(9)), Some(id(7)));
+        assert_eq!(ast.parent(id(1)), None);
+
+        let ancestors = ast.ancestors(id(19)).collect::<Vec<_>>();
+        assert_eq!(ancestors, [18, 15, 14, 7, 6, 3, 1].map(id),);
+    }
+}
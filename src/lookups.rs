@@ -0,0 +1,288 @@
+//! Lookup maps of the library units of an [`Ast`], see [`Ast::lookup_entity_declaration`].
+
+use std::mem;
+
+use super::*;
+
+/// Adds the library units from `first_index` on to the lookup maps, or rebuilds them if `first_index` is 0.
+///
+/// Returns the lookup errors of the library units which couldn't be resolved, including
+/// architectures and configurations whose entity name doesn't denote an entity
+/// declaration. These units are left out of the maps.
+#[expect(
+    clippy::too_many_lines,
+    reason = "one map and match arm per kind of library unit"
+)]
+pub(crate) fn build_maps(ast: &mut Ast, first_index: usize) -> Vec<LookupNodeError> {
+    if first_index == 0 {
+        ast.package_declarations.clear();
+        ast.entity_declarations.clear();
+        ast.package_bodies.clear();
+        ast.package_instantiations.clear();
+        ast.configuration_declarations.clear();
+        ast.context_declarations.clear();
+        ast.architecture_bodies.clear();
+        ast.configurations.clear();
+    }
+    let libraries = ast
+        .library_ids
+        .iter()
+        .filter_map(|&library_id| Some((ast.symbol(library_id)?, library_id)))
+        .collect();
+    let mut package_declarations = mem::take(&mut ast.package_declarations);
+    let mut entity_declarations = mem::take(&mut ast.entity_declarations);
+    let mut package_bodies = mem::take(&mut ast.package_bodies);
+    let mut package_instantiations = mem::take(&mut ast.package_instantiations);
+    let mut configuration_declarations = mem::take(&mut ast.configuration_declarations);
+    let mut context_declarations = mem::take(&mut ast.context_declarations);
+    let mut architecture_bodies = mem::take(&mut ast.architecture_bodies);
+    let mut configurations = mem::take(&mut ast.configurations);
+    let mut unresolved = Vec::new();
+
+    for &library_id in &ast.library_ids {
+        for library_unit in library_id.get(ast).try_library_units_iter(ast) {
+            let (library_unit_id, library_unit) = match library_unit {
+                Ok(library_unit) => library_unit,
+                Err(error) => {
+                    unresolved.push(error);
+                    continue;
+                },
+            };
+            // Units of earlier chunks are already in the maps.
+            if (library_unit_id.id_primitive().get() as usize) < first_index {
+                continue;
+            }
+            let key = ast
+                .symbol(library_unit_id)
+                .map(|symbol| (library_id, symbol));
+            match library_unit {
+                LibraryUnit::PackageDeclaration(_) => {
+                    if let Some(key) = key {
+                        package_declarations.insert(key, library_unit_id.downcast());
+                    }
+                },
+
+                LibraryUnit::EntityDeclaration(_) => {
+                    if let Some(key) = key {
+                        entity_declarations.insert(key, library_unit_id.downcast());
+                    }
+                },
+
+                LibraryUnit::PackageBody(_) => {
+                    if let Some(key) = key {
+                        package_bodies.insert(key, library_unit_id.downcast());
+                    }
+                },
+
+                LibraryUnit::PackageInstantiationDeclaration(_) => {
+                    if let Some(key) = key {
+                        package_instantiations.insert(key, library_unit_id.downcast());
+                    }
+                },
+
+                LibraryUnit::ContextDeclaration(_) => {
+                    if let Some(key) = key {
+                        context_declarations.insert(key, library_unit_id.downcast());
+                    }
+                },
+
+                LibraryUnit::ConfigurationDeclaration(configuration) => {
+                    let resolved = configuration
+                        .entity_name
+                        .map(|entity_name| resolve_entity_name(ast, entity_name))
+                        .transpose();
+                    let entity_declaration_id = match resolved {
+                        Ok(entity_declaration_id) => entity_declaration_id,
+                        Err(error) => {
+                            unresolved.push(error);
+                            continue;
+                        },
+                    };
+                    if let Some(key) = key {
+                        configuration_declarations.insert(key, library_unit_id.downcast());
+                    }
+                    if let Some(entity_declaration_id) = entity_declaration_id {
+                        configurations
+                            .entry(entity_declaration_id)
+                            .or_default()
+                            .push(library_unit_id.downcast());
+                    }
+                },
+
+                // Design files and their design units are visited in analysis order, so the
+                // architectures of each entity are, too. A re-analyzed architecture replaces
+                // the earlier one of the same name.
+                LibraryUnit::ArchitectureBody(architecture) => {
+                    let entity_declaration_id =
+                        match resolve_entity_name(ast, architecture.entity_name) {
+                            Ok(entity_declaration_id) => entity_declaration_id,
+                            Err(error) => {
+                                unresolved.push(error);
+                                continue;
+                            },
+                        };
+                    let architecture_id = library_unit_id.downcast();
+                    let symbol = ast.symbol(architecture_id);
+                    let architectures = architecture_bodies
+                        .entry(entity_declaration_id)
+                        .or_default();
+                    architectures.retain(|&earlier| ast.symbol(earlier) != symbol);
+                    architectures.push(architecture_id);
+                },
+
+                // Verification units are bound to the design by their hierarchical name
+                LibraryUnit::VunitDeclaration(_)
+                | LibraryUnit::VmodeDeclaration(_)
+                | LibraryUnit::VpropDeclaration(_) => {},
+            }
+        }
+    }
+
+    ast.libraries = libraries;
+    ast.package_declarations = package_declarations;
+    ast.entity_declarations = entity_declarations;
+    ast.package_bodies = package_bodies;
+    ast.package_instantiations = package_instantiations;
+    ast.configuration_declarations = configuration_declarations;
+    ast.context_declarations = context_declarations;
+    ast.architecture_bodies = architecture_bodies;
+    ast.configurations = configurations;
+    unresolved
+}
+
+/// Resolves the entity name of an architecture body or configuration declaration.
+fn resolve_entity_name(
+    ast: &Ast,
+    entity_name: NameNodeId,
+) -> Result<NodeId<EntityDeclaration>, LookupNodeError> {
+    let name = entity_name.try_get(ast)?;
+    let Some(named_entity) = name.named_entity() else {
+        return Err(LookupNodeError::WrongType {
+            id: entity_name.id_primitive(),
+            expected: "name denoting a named entity",
+            actual: name.type_str(),
+        });
+    };
+    named_entity.try_downcast(ast)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+
+    #[test]
+    fn test_library_unit_lookups() {
+        const UNITS_CHUNK: &str = r#"{"first_id":20,"last_id":34,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"units.vhd","start":20,"end":35}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22,24,26,29,31,33]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"package_declaration":{"id":23,"identifier":["pkg",null],"parent":22,"package_body":25}}
+{"design_unit":{"library_unit":25,"design_file":21}}
+{"package_body":{"identifier":["pkg",null],"parent":24,"package":23}}
+{"design_unit":{"library_unit":27,"design_file":21}}
+{"configuration_declaration":{"identifier":["cfg",null],"parent":26,"entity_name":28}}
+{"simple_name":{"identifier":["top",null],"named_entity":5}}
+{"design_unit":{"library_unit":30,"design_file":21}}
+{"context_declaration":{"identifier":["ctx",null],"parent":29}}
+{"design_unit":{"library_unit":32,"design_file":21}}
+{"package_instantiation_declaration":{"identifier":["inst",null],"parent":31,"uninstantiated_package_decl":23}}
+{"design_unit":{"library_unit":34,"design_file":21}}
+{"vunit_declaration":{"identifier":["vu",null]}}
+
+"#;
+
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut UNITS_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        let ast = builder.finish();
+        let id = |raw| IdPrimitive::new(raw).unwrap();
+        let name = NormalizedIdentifier::new;
+
+        let work = ast.lookup_library(&name("work")).unwrap();
+        let lib = ast.lookup_library(&name("lib")).unwrap();
+        assert_eq!(
+            ast.lookup_package_body(lib, name("pkg")),
+            Some(NodeId::from_raw(id(25)))
+        );
+        assert_eq!(
+            ast.lookup_package_instantiation(lib, name("inst")),
+            Some(NodeId::from_raw(id(32)))
+        );
+        assert_eq!(
+            ast.lookup_context_declaration(lib, name("ctx")),
+            Some(NodeId::from_raw(id(30)))
+        );
+        let configuration = ast
+            .lookup_configuration_declaration(lib, name("cfg"))
+            .unwrap();
+        assert_eq!(configuration, NodeId::from_raw(id(27)));
+        assert_eq!(
+            ast.lookup_configuration_declaration(work, name("cfg")),
+            None
+        );
+        assert_eq!(ast.lookup_package_body(lib, name("inst")), None);
+
+        let top = ast.lookup_entity_declaration(work, name("top")).unwrap();
+        assert_eq!(ast.configurations_of(top), [configuration]);
+    }
+
+    #[test]
+    fn test_default_architecture() {
+        let load = |design_units: &str, architecture_name: &str| {
+            let input = FIXTURE
+                .replace(r#""last_id":19"#, r#""last_id":22"#)
+                .replace(r#""design_units":[4,6]"#, design_units)
+                .replace(
+                    "\n\n",
+                    &[
+                        "\n",
+                        r#"{"design_unit":{"library_unit":21,"design_file":3}}"#,
+                        "\n",
+                        r#"{"architecture_body":{"identifier":[""#,
+                        architecture_name,
+                        r#"",null],"entity_name":22,"parent":20}}"#,
+                        "\n",
+                        r#"{"simple_name":{"identifier":["top",null],"named_entity":5}}"#,
+                        "\n\n",
+                    ]
+                    .concat(),
+                );
+            Ast::from_json(&mut input.as_bytes(), 1).unwrap().ast
+        };
+        let architecture = |raw| NodeId::from_raw(IdPrimitive::new(raw).unwrap());
+        let top = NodeId::from_raw(IdPrimitive::new(5).unwrap());
+
+        let ast = load(r#""design_units":[4,6,20]"#, "beh");
+        assert_eq!(
+            ast.lookup_architecture_bodies(top),
+            [architecture(7), architecture(21)]
+        );
+        assert_eq!(ast.default_architecture(top).unwrap(), architecture(21));
+
+        let reordered = load(r#""design_units":[4,20,6]"#, "beh");
+        assert_eq!(
+            reordered.default_architecture(top).unwrap(),
+            architecture(7)
+        );
+
+        // Re-analyzing `rtl` replaces the earlier architecture.
+        let reanalyzed = load(r#""design_units":[4,6,20]"#, "rtl");
+        assert_eq!(
+            reanalyzed.lookup_architecture_bodies(top),
+            [architecture(21)]
+        );
+        assert_eq!(
+            reanalyzed.default_architecture(top).unwrap(),
+            architecture(21)
+        );
+
+        let without_architectures = load(r#""design_units":[4]"#, "beh");
+        let error = without_architectures.default_architecture(top).unwrap_err();
+        assert_eq!(error.entity_declaration_id, top);
+        assert_eq!(error.to_string(), "entity top has no architecture");
+    }
+}
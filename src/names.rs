@@ -26,7 +26,7 @@ subset_declaration!(DenotingName DenotingNameOwned DenotingNameNodeId {
     SelectedName(SelectedName),
     OperatorSymbol(OperatorSymbol),
     ReferenceName(ReferenceName),
-});
+} overlaps [Name, AnySelectedName, Prefix]);
 
 subset_declaration!(Name NameOwned NameNodeId {
     AttributeName(AttributeName),
@@ -35,7 +35,7 @@ subset_declaration!(Name NameOwned NameNodeId {
     SelectedName(SelectedName),
     SimpleName(SimpleName),
     SliceName(SliceName),
-});
+} overlaps [Prefix]);
 
 impl Name<'_> {
     /// Returns the resolved named entity for name forms that bind one.
@@ -169,18 +169,7 @@ pub enum NameElement {
 subset_declaration!(AnySelectedName AnySelectedNameOwned AnySelectedNameNodeId {
     SelectedName(SelectedName),
     SelectedByAllName(SelectedByAllName),
-});
-
-impl<'node> From<AnySelectedName<'node>> for Name<'node> {
-    fn from(value: AnySelectedName<'node>) -> Self {
-        match value {
-            AnySelectedName::SelectedName(selected_name) => Name::SelectedName(selected_name),
-            AnySelectedName::SelectedByAllName(selected_by_all_name) => {
-                Name::SelectedByAllName(selected_by_all_name)
-            },
-        }
-    }
-}
+} subset_of [Name NameNodeId] overlaps [Prefix]);
 
 subset_declaration!(Prefix PrefixOwned PrefixNodeId {
     AttributeName(AttributeName),
@@ -196,160 +185,187 @@ subset_declaration!(Prefix PrefixOwned PrefixNodeId {
     OperatorSymbol(OperatorSymbol),
 });
 
-/// Attribute name (`prefix'attr`).
-///
-/// The named entity is typically an attribute declaration or an implicit
-/// attribute value/function created during analysis.
-///
-/// ```vhdl
-/// clk'event
-/// vec'length
-/// my_sig'delayed(1 ns)
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AttributeName {
-    /// Prefix to which the attribute is applied.
-    pub prefix: PrefixNodeId,
-    /// Resolved attribute (declaration, implicit attribute, …).
-    pub named_entity: NamedEntityNodeId,
+node_struct! {
+    /// Attribute name (`prefix'attr`).
+    ///
+    /// The named entity is typically an attribute declaration or an implicit
+    /// attribute value/function created during analysis.
+    ///
+    /// ```vhdl
+    /// clk'event
+    /// vec'length
+    /// my_sig'delayed(1 ns)
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AttributeName {
+        /// Prefix to which the attribute is applied.
+        pub prefix: PrefixNodeId,
+        /// Resolved attribute (declaration, implicit attribute, …).
+        @reference
+        pub named_entity: NamedEntityNodeId,
+    }
 }
 
-/// Explicit access-value dereference (`prefix.all` for an access type).
-///
-/// ```vhdl
-/// ptr.all := 5;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct Dereference {
-    /// Access-valued prefix being dereferenced.
-    pub prefix: PrefixNodeId,
+node_struct! {
+    /// Explicit access-value dereference (`prefix.all` for an access type).
+    ///
+    /// ```vhdl
+    /// ptr.all := 5;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct Dereference {
+        /// Access-valued prefix being dereferenced.
+        pub prefix: PrefixNodeId,
+    }
 }
 
-/// Implicit dereference inserted by analysis when an access prefix is used as
-/// a designated object without writing `.all`.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ImplicitDereference {
-    /// Access-valued prefix being implicitly dereferenced.
-    pub prefix: PrefixNodeId,
+node_struct! {
+    /// Implicit dereference inserted by analysis when an access prefix is used as
+    /// a designated object without writing `.all`.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ImplicitDereference {
+        /// Access-valued prefix being implicitly dereferenced.
+        pub prefix: PrefixNodeId,
+    }
 }
 
-/// Indexed name (`prefix(index, …)`).
-///
-/// ```vhdl
-/// mem(0)
-/// matrix(i, j)
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct IndexedName {
-    /// Array (or similar) prefix being indexed.
-    pub prefix: PrefixNodeId,
-    /// Index expressions, or the special `others` encoding.
-    pub index_list: IndexList,
-    /// Analyzed type of the indexed element.
-    #[serde(rename = "type")]
-    pub typ: SubtypeDefinitionNodeId,
+node_struct! {
+    /// Indexed name (`prefix(index, …)`).
+    ///
+    /// ```vhdl
+    /// mem(0)
+    /// matrix(i, j)
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct IndexedName {
+        /// Array (or similar) prefix being indexed.
+        pub prefix: PrefixNodeId,
+        /// Index expressions, or the special `others` encoding.
+        pub index_list: IndexList,
+        /// Analyzed type of the indexed element.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: SubtypeDefinitionNodeId,
+    }
 }
 
-/// Operator symbol used as a denoting name (`"+"` , `"and"`, …).
-///
-/// Appears in overload resolution, alias targets, and attribute designators for
-/// operators. The identifier holds the operator string (including quotes in the
-/// source representation GHDL stores).
-///
-/// ```vhdl
-/// function "+" (l, r : my_t) return my_t;
-/// alias add is "+" [my_t, my_t return my_t];
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct OperatorSymbol {
-    /// Operator designator string.
-    pub identifier: Option<Identifier>,
-    /// Analyzed type when the operator denotes a specific overload.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Resolved subprogram or other named entity, when known.
-    pub named_entity: Option<NamedEntityNodeId>,
+node_struct! {
+    /// Operator symbol used as a denoting name (`"+"` , `"and"`, …).
+    ///
+    /// Appears in overload resolution, alias targets, and attribute designators for
+    /// operators. The identifier holds the operator string (including quotes in the
+    /// source representation GHDL stores).
+    ///
+    /// ```vhdl
+    /// function "+" (l, r : my_t) return my_t;
+    /// alias add is "+" [my_t, my_t return my_t];
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct OperatorSymbol {
+        /// Operator designator string.
+        pub identifier: Option<Identifier>,
+        /// Analyzed type when the operator denotes a specific overload.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Resolved subprogram or other named entity, when known.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+    }
 }
 
-/// Selected element name used as an intermediate prefix after analysis.
-///
-/// Similar to [`SelectedName`], but GHDL may use this form for record-element
-/// selections that participate in longer name chains.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SelectedElement {
-    /// Prefix before the selected suffix.
-    pub prefix: PrefixNodeId,
-    /// Named entity denoted by the selection (often an element declaration).
-    pub named_entity: NamedEntityNodeId,
+node_struct! {
+    /// Selected element name used as an intermediate prefix after analysis.
+    ///
+    /// Similar to [`SelectedName`], but GHDL may use this form for record-element
+    /// selections that participate in longer name chains.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SelectedElement {
+        /// Prefix before the selected suffix.
+        pub prefix: PrefixNodeId,
+        /// Named entity denoted by the selection (often an element declaration).
+        @reference
+        pub named_entity: NamedEntityNodeId,
+    }
 }
 
-/// Selected name with `.all` (`prefix.all`).
-///
-/// For access types this is usually rewritten to a [`Dereference`]; the
-/// `.all` form also appears for package/use contexts.
-///
-/// ```vhdl
-/// use work.pkg.all;
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SelectedByAllName {
-    /// Prefix before `.all`.
-    pub prefix: PrefixNodeId,
+node_struct! {
+    /// Selected name with `.all` (`prefix.all`).
+    ///
+    /// For access types this is usually rewritten to a [`Dereference`]; the
+    /// `.all` form also appears for package/use contexts.
+    ///
+    /// ```vhdl
+    /// use work.pkg.all;
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SelectedByAllName {
+        /// Prefix before `.all`.
+        pub prefix: PrefixNodeId,
+    }
 }
 
-/// Selected name (`prefix.suffix`).
-///
-/// ```vhdl
-/// work.pkg
-/// rec.field
-/// lib.entity_name
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SelectedName {
-    /// Suffix identifier.
-    pub identifier: Identifier,
-    /// Resolved named entity of the selection.
-    pub named_entity: NamedEntityNodeId,
-    /// Prefix before the selected suffix.
-    pub prefix: PrefixNodeId,
+node_struct! {
+    /// Selected name (`prefix.suffix`).
+    ///
+    /// ```vhdl
+    /// work.pkg
+    /// rec.field
+    /// lib.entity_name
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SelectedName {
+        /// Suffix identifier.
+        pub identifier: Identifier,
+        /// Resolved named entity of the selection.
+        @reference
+        pub named_entity: NamedEntityNodeId,
+        /// Prefix before the selected suffix.
+        pub prefix: PrefixNodeId,
+    }
 }
 
-/// Simple name (a single identifier).
-///
-/// [`named_entity`](Self::named_entity) is the declaration or other named
-/// entity this identifier resolved to. When resolution failed for an explicit
-/// architecture in a component instantiation, it may be
-/// [`NamedEntity::Unresolved`].
-///
-/// ```vhdl
-/// clk
-/// std_logic
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SimpleName {
-    /// Identifier as written / stored by GHDL.
-    pub identifier: Identifier,
-    /// Resolved named entity (or the global error node when unresolved).
-    #[serde(default = "unresolved_named_entity")]
-    pub named_entity: NamedEntityNodeId,
+node_struct! {
+    /// Simple name (a single identifier).
+    ///
+    /// [`named_entity`](Self::named_entity) is the declaration or other named
+    /// entity this identifier resolved to. When resolution failed for an explicit
+    /// architecture in a component instantiation, it may be
+    /// [`NamedEntity::Unresolved`].
+    ///
+    /// ```vhdl
+    /// clk
+    /// std_logic
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SimpleName {
+        /// Identifier as written / stored by GHDL.
+        pub identifier: Identifier,
+        /// Resolved named entity (or the global error node when unresolved).
+        #[serde(default = "unresolved_named_entity")]
+        @reference
+        pub named_entity: NamedEntityNodeId,
+    }
 }
 
 fn unresolved_named_entity() -> NamedEntityNodeId {
     Error::GLOBAL_ID.into()
 }
 
-/// Slice name (`prefix(discrete_range)`).
-///
-/// ```vhdl
-/// vec(7 downto 0)
-/// str(1 to 3)
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct SliceName {
-    /// Array prefix being sliced.
-    pub prefix: PrefixNodeId,
-    /// Discrete range of the slice.
-    pub suffix: RangeConstraintNodeId,
+node_struct! {
+    /// Slice name (`prefix(discrete_range)`).
+    ///
+    /// ```vhdl
+    /// vec(7 downto 0)
+    /// str(1 to 3)
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct SliceName {
+        /// Array prefix being sliced.
+        pub prefix: PrefixNodeId,
+        /// Discrete range of the slice.
+        pub suffix: RangeConstraintNodeId,
+    }
 }
 
 subset_declaration!(NamedEntity NamedEntityOwned NamedEntityNodeId {
@@ -403,176 +419,209 @@ subset_declaration!(NamedEntity NamedEntityOwned NamedEntityNodeId {
     /// mult: entity work.Mult(parallel) ...
     /// ```
     Unresolved(Error),
-});
+} overlaps [TypeDefinition]);
 
-/// Synthetic name that re-refers to an already resolved named entity.
-///
-/// Used by GHDL when a later reference must point at the same declaration as
-/// an earlier name without re-parsing the original identifier spelling.
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ReferenceName {
-    /// Named entity being re-referenced.
-    pub named_entity: Option<NamedEntityNodeId>,
-    /// Original name this reference was created from.
-    pub referenced_name: Option<NameNodeId>,
-    /// Analyzed type of the referenced object, when applicable.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Synthetic name that re-refers to an already resolved named entity.
+    ///
+    /// Used by GHDL when a later reference must point at the same declaration as
+    /// an earlier name without re-parsing the original identifier spelling.
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ReferenceName {
+        /// Named entity being re-referenced.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+        /// Original name this reference was created from.
+        @reference
+        pub referenced_name: Option<NameNodeId>,
+        /// Analyzed type of the referenced object, when applicable.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// External constant name (`<< constant pathname : subtype >>`).
-///
-/// VHDL-2008 hierarchical reference to a constant declared elsewhere in the
-/// elaborated design.
-///
-/// ```vhdl
-/// << constant .tb.dut.WIDTH : natural >>
-/// << constant ^.sibling.C : integer >>
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ExternalConstantName {
-    /// Absolute, relative, or package pathname.
-    pub external_pathname: Option<GenericNodeId>,
-    /// Resolved constant, when bound.
-    pub named_entity: Option<NamedEntityNodeId>,
-    /// Declared subtype indication of the external name.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// External constant name (`<< constant pathname : subtype >>`).
+    ///
+    /// VHDL-2008 hierarchical reference to a constant declared elsewhere in the
+    /// elaborated design.
+    ///
+    /// ```vhdl
+    /// << constant .tb.dut.WIDTH : natural >>
+    /// << constant ^.sibling.C : integer >>
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ExternalConstantName {
+        /// Absolute, relative, or package pathname.
+        pub external_pathname: Option<GenericNodeId>,
+        /// Resolved constant, when bound.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+        /// Declared subtype indication of the external name.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// External signal name (`<< signal pathname : subtype >>`).
-///
-/// ```vhdl
-/// << signal .tb.dut.clk : std_logic >>
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ExternalSignalName {
-    /// Absolute, relative, or package pathname.
-    pub external_pathname: Option<GenericNodeId>,
-    /// Resolved signal, when bound.
-    pub named_entity: Option<NamedEntityNodeId>,
-    /// Declared subtype indication of the external name.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Whether the external signal is treated as guarded.
-    #[serde(default)]
-    pub guarded_signal_flag: bool,
-    /// `register` / `bus` kind when guarded.
-    pub signal_kind: Option<SignalKind>,
+node_struct! {
+    /// External signal name (`<< signal pathname : subtype >>`).
+    ///
+    /// ```vhdl
+    /// << signal .tb.dut.clk : std_logic >>
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ExternalSignalName {
+        /// Absolute, relative, or package pathname.
+        pub external_pathname: Option<GenericNodeId>,
+        /// Resolved signal, when bound.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+        /// Declared subtype indication of the external name.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Whether the external signal is treated as guarded.
+        #[serde(default)]
+        pub guarded_signal_flag: bool,
+        /// `register` / `bus` kind when guarded.
+        pub signal_kind: Option<SignalKind>,
+    }
 }
 
-/// External variable name (`<< variable pathname : subtype >>`).
-///
-/// ```vhdl
-/// << variable .tb.shared_pkg.COUNT : natural >>
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ExternalVariableName {
-    /// Absolute, relative, or package pathname.
-    pub external_pathname: Option<GenericNodeId>,
-    /// Resolved variable, when bound.
-    pub named_entity: Option<NamedEntityNodeId>,
-    /// Declared subtype indication of the external name.
-    pub subtype_indication: Option<SubtypeDefinitionNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
-    /// Whether the target is a shared variable.
-    #[serde(default)]
-    pub shared_flag: bool,
+node_struct! {
+    /// External variable name (`<< variable pathname : subtype >>`).
+    ///
+    /// ```vhdl
+    /// << variable .tb.shared_pkg.COUNT : natural >>
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ExternalVariableName {
+        /// Absolute, relative, or package pathname.
+        pub external_pathname: Option<GenericNodeId>,
+        /// Resolved variable, when bound.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+        /// Declared subtype indication of the external name.
+        pub subtype_indication: Option<SubtypeDefinitionNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+        /// Whether the target is a shared variable.
+        #[serde(default)]
+        pub shared_flag: bool,
+    }
 }
 
-/// Parenthesis name (`prefix(…)` before it is rewritten as an indexed name,
-/// slice, function call, or type conversion).
-///
-/// After analysis most parenthesis names are replaced by a more specific node;
-/// remaining ones still appear in incompletely rewritten trees.
-///
-/// ```vhdl
-/// foo(1)          -- may become IndexedName or FunctionCall
-/// integer(expr)   -- type conversion
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct ParenthesisName {
-    /// Prefix before the parentheses.
-    pub prefix: Option<PrefixNodeId>,
-    /// Association list inside the parentheses.
-    #[serde(default)]
-    pub associations: Vec<AssociationElementNodeId>,
-    /// Suffix expression when GHDL stores a single suffix instead of associations.
-    pub suffix: Option<ExpressionNodeId>,
-    /// Resolved named entity, when applicable.
-    pub named_entity: Option<NamedEntityNodeId>,
-    /// Analyzed type.
-    #[serde(rename = "type")]
-    pub typ: Option<SubtypeDefinitionNodeId>,
+node_struct! {
+    /// Parenthesis name (`prefix(…)` before it is rewritten as an indexed name,
+    /// slice, function call, or type conversion).
+    ///
+    /// After analysis most parenthesis names are replaced by a more specific node;
+    /// remaining ones still appear in incompletely rewritten trees.
+    ///
+    /// ```vhdl
+    /// foo(1)          -- may become IndexedName or FunctionCall
+    /// integer(expr)   -- type conversion
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct ParenthesisName {
+        /// Prefix before the parentheses.
+        pub prefix: Option<PrefixNodeId>,
+        /// Association list inside the parentheses.
+        #[serde(default)]
+        pub associations: Vec<AssociationElementNodeId>,
+        /// Suffix expression when GHDL stores a single suffix instead of associations.
+        pub suffix: Option<ExpressionNodeId>,
+        /// Resolved named entity, when applicable.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+        /// Analyzed type.
+        #[serde(rename = "type")]
+        @reference
+        pub typ: Option<SubtypeDefinitionNodeId>,
+    }
 }
 
-/// Package pathname root (`@library.package.…`) for an external name.
-///
-/// ```vhdl
-/// << constant @work.pkg.C : integer >>
-/// -- PackagePathname.identifier = work (or library), suffix continues the path
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct PackagePathname {
-    /// Library or package identifier at this pathname segment.
-    pub identifier: Option<Identifier>,
-    /// Remaining pathname suffix.
-    pub pathname_suffix: Option<GenericNodeId>,
-    /// Named entity bound at this segment, when known.
-    pub named_entity: Option<NamedEntityNodeId>,
+node_struct! {
+    /// Package pathname root (`@library.package.…`) for an external name.
+    ///
+    /// ```vhdl
+    /// << constant @work.pkg.C : integer >>
+    /// -- PackagePathname.identifier = work (or library), suffix continues the path
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct PackagePathname {
+        /// Library or package identifier at this pathname segment.
+        pub identifier: Option<Identifier>,
+        /// Remaining pathname suffix.
+        pub pathname_suffix: Option<GenericNodeId>,
+        /// Named entity bound at this segment, when known.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+    }
 }
 
-/// Absolute pathname root (`.…`) for an external name.
-///
-/// ```vhdl
-/// << signal .tb.dut.clk : std_logic >>
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AbsolutePathname {
-    /// First pathname element after the leading `.`.
-    pub pathname_suffix: Option<GenericNodeId>,
+node_struct! {
+    /// Absolute pathname root (`.…`) for an external name.
+    ///
+    /// ```vhdl
+    /// << signal .tb.dut.clk : std_logic >>
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct AbsolutePathname {
+        /// First pathname element after the leading `.`.
+        pub pathname_suffix: Option<GenericNodeId>,
+    }
 }
 
-/// Relative pathname root (`^.…` or `^.^.…`) for an external name.
-///
-/// ```vhdl
-/// << signal ^.sibling.data : std_logic_vector >>
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct RelativePathname {
-    /// Pathname continuing after the `^` segment(s).
-    pub pathname_suffix: Option<GenericNodeId>,
+node_struct! {
+    /// Relative pathname root (`^.…` or `^.^.…`) for an external name.
+    ///
+    /// ```vhdl
+    /// << signal ^.sibling.data : std_logic_vector >>
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct RelativePathname {
+        /// Pathname continuing after the `^` segment(s).
+        pub pathname_suffix: Option<GenericNodeId>,
+    }
 }
 
-/// One segment of an external pathname (`label` or `label(index)`).
-///
-/// ```vhdl
-/// .tb.gen(0).cell.clk
-/// -- PathnameElement nodes for tb, gen (with pathname_expression = 0), cell, clk
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct PathnameElement {
-    /// Pathname segment identifier (instance label, generate label, …).
-    pub identifier: Option<Identifier>,
-    /// Next pathname segment.
-    pub pathname_suffix: Option<GenericNodeId>,
-    /// Named entity bound at this segment, when known.
-    pub named_entity: Option<NamedEntityNodeId>,
-    /// Optional index expression for generate / array instance labels.
-    pub pathname_expression: Option<ExpressionNodeId>,
+node_struct! {
+    /// One segment of an external pathname (`label` or `label(index)`).
+    ///
+    /// ```vhdl
+    /// .tb.gen(0).cell.clk
+    /// -- PathnameElement nodes for tb, gen (with pathname_expression = 0), cell, clk
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct PathnameElement {
+        /// Pathname segment identifier (instance label, generate label, …).
+        pub identifier: Option<Identifier>,
+        /// Next pathname segment.
+        pub pathname_suffix: Option<GenericNodeId>,
+        /// Named entity bound at this segment, when known.
+        @reference
+        pub named_entity: Option<NamedEntityNodeId>,
+        /// Optional index expression for generate / array instance labels.
+        pub pathname_expression: Option<ExpressionNodeId>,
+    }
 }
 
-/// Box name (`<>`) used as an association actual for an interface default.
-///
-/// ```vhdl
-/// generic map (WIDTH => <>)
-/// function "=" (l, r : T) return boolean is <>
-/// ```
-#[derive(Debug, Deserialize, Serialize)]
-pub struct BoxName {}
+node_struct! {
+    /// Box name (`<>`) used as an association actual for an interface default.
+    ///
+    /// ```vhdl
+    /// generic map (WIDTH => <>)
+    /// function "=" (l, r : T) return boolean is <>
+    /// ```
+    #[derive(Debug, Deserialize, Serialize)]
+    pub struct BoxName {}
+}
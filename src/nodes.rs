@@ -1,3 +1,4 @@
+use std::any::Any;
 use std::any::type_name;
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -6,6 +7,10 @@ use std::hash::Hasher;
 use std::marker::PhantomData;
 use std::num::NonZeroU32;
 
+use serde::de::DeserializeSeed;
+use serde::de::MapAccess;
+use smallvec::SmallVec;
+
 use super::*;
 
 /// Non-zero raw AST node index used by all typed node IDs.
@@ -30,6 +35,58 @@ impl<T> NodeId<T> {
     pub fn to_raw(self) -> IdPrimitive {
         self.0
     }
+
+    /// Returns the symbol of the node's identifier or label, see [`Ast::symbol`].
+    #[must_use]
+    pub fn symbol(self, ast: &Ast) -> Option<Symbol> {
+        ast.symbol(GenericNodeId::from_raw(self.0))
+    }
+
+    /// Gets mutable access to the node from the AST.
+    ///
+    /// The parents, symbols, entity references and lookup maps of the AST are built while
+    /// loading and aren't updated by edits. Changing the node references, identifiers or
+    /// labels of a node leaves them stale, so only other fields, such as the value of a
+    /// literal, may be changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node is missing or has the wrong type.
+    #[track_caller]
+    pub fn get_mut(self, ast: &mut Ast) -> &mut T
+    where
+        T: 'static,
+        for<'ast> &'ast mut T: TryFrom<&'ast mut Node>,
+    {
+        #[expect(
+            clippy::unwrap_used,
+            reason = "this may only happen on programming errors or invalid input from GHDL"
+        )]
+        self.try_get_mut(ast).unwrap()
+    }
+
+    /// Tries to get mutable access to the node from the AST.
+    ///
+    /// Edits are restricted like those through [`NodeId::get_mut`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the node is not found or is not of the expected type.
+    pub fn try_get_mut(self, ast: &mut Ast) -> Result<&mut T, LookupNodeError>
+    where
+        T: 'static,
+        for<'ast> &'ast mut T: TryFrom<&'ast mut Node>,
+    {
+        let id = self.0;
+        match ast.nodes.get_mut(id.get() as usize) {
+            Some(Node::Empty) | None => Err(LookupNodeError::not_found::<T>(id)),
+            Some(node) => {
+                let actual = node.type_str();
+                node.try_into()
+                    .map_err(|_ignore| LookupNodeError::wrong_type::<T>(id, actual))
+            },
+        }
+    }
 }
 
 impl<'de, T> Deserialize<'de> for NodeId<T> {
@@ -213,12 +270,35 @@ impl LookupNodeError {
     }
 }
 
+/// A node type or node subset, see [`Ast::nodes_of`].
+pub trait NodeKind {
+    /// ID type of nodes of this kind.
+    type Id: AstNodeId + From<IdPrimitive>;
+}
+
 /// Converts a node ID into a more specific typed [`NodeId`].
 pub trait DowncastNodeId<T>: Into<GenericNodeId> {
     /// Downcasts this ID to `NodeId<T>` without checking the node kind.
+    ///
+    /// Use [`DowncastNodeId::try_downcast`] if the kind isn't known from context.
     fn downcast(self) -> NodeId<T> {
         NodeId(self.into().0, PhantomData)
     }
+
+    /// Downcasts this ID to `NodeId<T>` after checking that the node is a `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the node is not found or is not of the expected type.
+    fn try_downcast(self, ast: &Ast) -> Result<NodeId<T>, LookupNodeError>
+    where
+        T: 'static,
+        for<'ast> &'ast T: TryFrom<&'ast Node>,
+    {
+        let id = self.downcast();
+        id.try_get(ast)?;
+        Ok(id)
+    }
 }
 
 impl<T> Display for NodeId<T> {
@@ -227,12 +307,177 @@ impl<T> Display for NodeId<T> {
     }
 }
 
+impl NodeReference for GenericNodeId {
+    const NAME: &'static str = "GenericNodeId";
+
+    fn accepts(node: &Node) -> bool {
+        !matches!(node, Node::Empty)
+    }
+}
+
 impl Debug for GenericNodeId {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         write!(formatter, "NodeId({index})", index = self.0.get())
     }
 }
 
+/// A node struct deserialized together with its optional `location` field.
+///
+/// GHDL stores the source location inside each node object, next to the node's
+/// own fields. This wrapper strips it from the field stream so that node structs
+/// don't need a `location` field of their own.
+pub(crate) struct Located<T> {
+    node: T,
+    location: Option<Location>,
+}
+
+impl<'de, T> Deserialize<'de> for Located<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Visitor;
+        use serde::de::value::MapAccessDeserializer;
+
+        struct LocatedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for LocatedVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = Located<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a node object")
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut location = None;
+                let node = T::deserialize(MapAccessDeserializer::new(LocationFilter {
+                    map,
+                    location: &mut location,
+                }))?;
+                Ok(Located { node, location })
+            }
+        }
+
+        deserializer.deserialize_map(LocatedVisitor(PhantomData))
+    }
+}
+
+/// A node struct serialized together with its optional `location` field, the inverse of [`Located`].
+#[derive(Serialize)]
+pub(crate) struct LocatedRef<'node, T> {
+    #[serde(flatten)]
+    node: &'node T,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+}
+
+/// Map access adapter that removes the `location` entry from a node object.
+struct LocationFilter<'loc, A> {
+    map: A,
+    location: &'loc mut Option<Location>,
+}
+
+impl<'de, A> MapAccess<'de> for LocationFilter<'_, A>
+where
+    A: MapAccess<'de>,
+{
+    type Error = A::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        use serde::de::value::BorrowedStrDeserializer;
+        use serde::de::value::StrDeserializer;
+
+        loop {
+            let Some(key) = self.map.next_key::<FieldKey<'de>>()? else {
+                return Ok(None);
+            };
+            if key.as_str() == "location" {
+                *self.location = self.map.next_value()?;
+                continue;
+            }
+            return match key {
+                FieldKey::Borrowed(key) => seed.deserialize(BorrowedStrDeserializer::new(key)),
+                FieldKey::Owned(key) => seed.deserialize(StrDeserializer::new(&key)),
+            }
+            .map(Some);
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        self.map.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.map.size_hint()
+    }
+}
+
+/// Field name of a node object, borrowed from the input whenever possible.
+enum FieldKey<'de> {
+    Borrowed(&'de str),
+    Owned(String),
+}
+
+impl FieldKey<'_> {
+    fn as_str(&self) -> &str {
+        match self {
+            FieldKey::Borrowed(key) => key,
+            FieldKey::Owned(key) => key,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldKey<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+        use serde::de::Visitor;
+
+        struct FieldKeyVisitor;
+
+        impl<'de> Visitor<'de> for FieldKeyVisitor {
+            type Value = FieldKey<'de>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a field name")
+            }
+
+            fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(FieldKey::Borrowed(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(FieldKey::Owned(value.to_owned()))
+            }
+        }
+
+        deserializer.deserialize_str(FieldKeyVisitor)
+    }
+}
+
 macro_rules! node_declaration {
     (
         $(
@@ -250,6 +495,17 @@ macro_rules! node_declaration {
                 $variant($variant),
             )+
 
+            /// Node of a kind not known to this crate, kept as raw JSON.
+            ///
+            /// Only produced by lenient loading (see [`LoadOptions`]).
+            #[serde(skip_deserializing)]
+            Unknown {
+                /// Node kind tag of the JSON record, e.g. `"entity_declaration"`.
+                kind: String,
+                /// Content of the JSON record.
+                raw: serde_json::Value,
+            },
+
             Empty,
         }
 
@@ -261,10 +517,105 @@ macro_rules! node_declaration {
                     $(
                         Self::$variant(..) => stringify!($variant),
                     )+
+                    Self::Unknown { .. } => "<unknown>",
                     Self::Empty => "<empty>",
                 }
             }
 
+            /// Iterates over all node references stored in this node, in field order.
+            ///
+            /// Each item consists of the serialized field name (e.g. `"type"` rather than `"typ"`)
+            /// and the referenced node. Both owning fields and plain references such as
+            /// `named_entity` are included; fields holding several IDs yield one item per ID.
+            pub fn children(&self) -> impl Iterator<Item = (&'static str, GenericNodeId)> + use<> {
+                let mut children = SmallVec::<[_; 8]>::new();
+                self.for_each_reference(|reference| children.push((reference.field.name, reference.id)));
+                children.into_iter()
+            }
+
+            /// Iterates over the node references stored in the owning fields of this node.
+            ///
+            /// Unlike [`children`](Self::children), references such as `named_entity` are skipped.
+            pub(crate) fn owned_children(
+                &self,
+            ) -> impl Iterator<Item = (&'static str, GenericNodeId)> + use<> {
+                let mut children = SmallVec::<[_; 8]>::new();
+                self.for_each_reference(|reference| {
+                    if reference.field.owning {
+                        children.push((reference.field.name, reference.id));
+                    }
+                });
+                children.into_iter()
+            }
+
+            /// Calls `callback` for every node reference stored in this node.
+            pub(crate) fn for_each_reference(&self, mut callback: impl FnMut(references::Reference)) {
+                self.visit_fields(&mut callback);
+            }
+
+            /// Passes every node reference and identifier stored in this node to `sink`.
+            pub(crate) fn visit_fields(&self, sink: &mut impl references::FieldSink) {
+                match self {
+                    $(
+                        Self::$variant(node) => references::NodeFields::visit_fields(node, sink),
+                    )+
+                    Self::Unknown { .. } | Self::Empty => {},
+                }
+            }
+
+        }
+
+        /// Node kind tag of a JSONL node record, used to tell unknown kinds from invalid records.
+        #[derive(Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        pub(crate) enum NodeTag {
+            $(
+                $($(#[$variant_attr])*)?
+                $variant,
+            )+
+        }
+
+        /// A JSONL node record, together with the source location stored inside it.
+        #[derive(Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        pub(crate) enum LocatedNode {
+            $(
+                $($(#[$variant_attr])*)?
+                $variant(Located<$variant>),
+            )+
+        }
+
+        impl LocatedNode {
+            /// Splits this record into the node and its source location.
+            pub(crate) fn into_parts(self) -> (Node, Option<Location>) {
+                match self {
+                    $(
+                        Self::$variant(located) => (Node::$variant(located.node), located.location),
+                    )+
+                }
+            }
+        }
+
+        /// A JSONL node record borrowed from a [`Node`], the inverse of [`LocatedNode`].
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        pub(crate) enum LocatedNodeRef<'node> {
+            $(
+                $($(#[$variant_attr])*)?
+                $variant(LocatedRef<'node, $variant>),
+            )+
+        }
+
+        impl<'node> LocatedNodeRef<'node> {
+            /// Combines a node and its source location into a record, or returns `None` for unknown and empty nodes.
+            pub(crate) fn new(node: &'node Node, location: Option<Location>) -> Option<Self> {
+                match node {
+                    $(
+                        Node::$variant(node) => Some(Self::$variant(LocatedRef { node, location })),
+                    )+
+                    Node::Unknown { .. } | Node::Empty => None,
+                }
+            }
         }
 
         impl ::std::fmt::Debug for Node {
@@ -273,6 +624,7 @@ macro_rules! node_declaration {
                     $(
                         Self::$variant(inner) => ::std::fmt::Debug::fmt(inner, formatter),
                     )+
+                    Self::Unknown { kind, .. } => write!(formatter, "<unknown {kind}>"),
                     Self::Empty => formatter.write_str("<empty>"),
                 }
             }
@@ -290,6 +642,17 @@ macro_rules! node_declaration {
                 }
             }
 
+            impl<'ast> TryFrom<&'ast mut Node> for &'ast mut $variant {
+                type Error = ();
+
+                fn try_from(value: &'ast mut Node) -> Result<Self, Self::Error> {
+                    match value {
+                        Node::$variant(val) => Ok(val),
+                        _ => Err(()),
+                    }
+                }
+            }
+
             impl TryFrom<Node> for $variant {
                 type Error = ();
 
@@ -301,6 +664,18 @@ macro_rules! node_declaration {
                 }
             }
 
+            impl NodeKind for $variant {
+                type Id = NodeId<$variant>;
+            }
+
+            impl references::NodeReference for NodeId<$variant> {
+                const NAME: &'static str = concat!("NodeId<", stringify!($variant), ">");
+
+                fn accepts(node: &Node) -> bool {
+                    matches!(node, Node::$variant(_))
+                }
+            }
+
             impl From<NodeId<$variant>> for GenericNodeId {
                 fn from(value: NodeId<$variant>) -> Self {
                     Self(value.0, PhantomData)
@@ -327,293 +702,391 @@ macro_rules! node_declaration {
     };
 }
 
-node_declaration! {
-    // Associations
-    AssociationElementByExpression,
-    AssociationElementByIndividual,
-    AssociationElementByName,
-    AssociationElementOpen,
-    AssociationElementPackage,
-    AssociationElementSubprogram,
-    AssociationElementTerminal,
-    AssociationElementType,
-
-    // Attributes
-    Attribute,
-    AttributeValue,
-
-    // Choices
-    ChoiceByExpression,
-    ChoiceByName,
-    ChoiceByNone,
-    ChoiceByOthers,
-    ChoiceByRange,
-
-    // Common
-    Error,
-    OverloadList,
-
-    // Concurrent Statements
-    BlockStatement,
-    CaseGenerateStatement,
-    ComponentInstantiationStatement,
-    ConcurrentAssertionStatement,
-    ConcurrentBreakStatement,
-    ConcurrentConditionalSignalAssignment,
-    ConcurrentProcedureCallStatement,
-    ConcurrentSelectedSignalAssignment,
-    ConcurrentSimpleSignalAssignment,
-    ForGenerateStatement,
-    GenerateStatementBody,
-    IfGenerateElseClause,
-    IfGenerateStatement,
-    ProcessStatement,
-    SensitizedProcessStatement,
-    SimpleSimultaneousStatement,
-    SimultaneousCaseStatement,
-    SimultaneousElsif,
-    SimultaneousIfStatement,
-    SimultaneousNullStatement,
-    SimultaneousProceduralStatement,
-
-    // Configuration
-    BindingIndication,
-    BlockConfiguration,
-    BlockHeader,
-    ComponentConfiguration,
-    EntityAspectConfiguration,
-    EntityAspectEntity,
-    EntityAspectOpen,
-
-    // Declarations
-    AcrossQuantityDeclaration,
-    AnonymousTypeDeclaration,
-    ArrayElementResolution,
-    AttributeDeclaration,
-    AttributeImplicitDeclaration,
-    ComponentDeclaration,
-    ConstantDeclaration,
-    ElementDeclaration,
-    FileDeclaration,
-    FreeQuantityDeclaration,
-    FunctionBody,
-    FunctionDeclaration,
-    FunctionInstantiationDeclaration,
-    GroupDeclaration,
-    GroupTemplateDeclaration,
-    GuardSignalDeclaration,
-    InterfaceConstantDeclaration,
-    InterfaceFileDeclaration,
-    InterfaceFunctionDeclaration,
-    InterfaceProcedureDeclaration,
-    InterfaceQuantityDeclaration,
-    InterfaceSignalDeclaration,
-    InterfaceTerminalDeclaration,
-    InterfaceTypeDeclaration,
-    InterfaceVariableDeclaration,
-    InterfaceViewDeclaration,
-    IteratorDeclaration,
-    ModeViewDeclaration,
-    NatureDeclaration,
-    NatureElementDeclaration,
-    NoiseQuantityDeclaration,
-    NonObjectAliasDeclaration,
-    ObjectAliasDeclaration,
-    ProcedureBody,
-    ProcedureDeclaration,
-    ProcedureInstantiationDeclaration,
-    SignalDeclaration,
-    Signature,
-    SpectrumQuantityDeclaration,
-    SubnatureDeclaration,
-    SubprogramInstantiationBody,
-    SubtypeDeclaration,
-    SuspendStateDeclaration,
-    TerminalDeclaration,
-    ThroughQuantityDeclaration,
-    TypeDeclaration,
-    UnitDeclaration,
-    VariableDeclaration,
-
-    // Expressions
-    Aggregate,
-    AggregateInfo,
-    AllocatorByExpression,
-    AllocatorBySubtype,
-    BinaryOperator,
-    CharacterLiteral,
-    EnumerationLiteral,
-    FloatingPointLiteral,
-    FunctionCall,
-    IntegerLiteral,
-    NullLiteral,
-    OverflowLiteral,
-    ParenthesisExpression,
-    PhysicalFpLiteral,
-    PhysicalIntLiteral,
-    QualifiedExpression,
-    RangeExpression,
-    SimpleAggregate,
-    #[serde(rename = "string_literal8")]
-    StringLiteral,
-    TypeConversion,
-    UnaryOperator,
-
-    // Libraries
-    ArchitectureBody,
-    ConfigurationDeclaration,
-    ContextDeclaration,
-    ContextReference,
-    DesignFile,
-    DesignUnit,
-    EntityDeclaration,
-    ForeignModule,
-    InterfacePackageDeclaration,
-    #[serde(rename = "library_declaration")]
-    Library,
-    LibraryClause,
-    PackageBody,
-    PackageDeclaration,
-    PackageHeader,
-    PackageInstantiationBody,
-    PackageInstantiationDeclaration,
-    UseClause,
-    VmodeDeclaration,
-    VpropDeclaration,
-    VunitDeclaration,
-
-    // Names
-    AbsolutePathname,
-    AttributeName,
-    BoxName,
-    Dereference,
-    ExternalConstantName,
-    ExternalSignalName,
-    ExternalVariableName,
-    ImplicitDereference,
-    IndexedName,
-    OperatorSymbol,
-    PackagePathname,
-    ParenthesisName,
-    PathnameElement,
-    ReferenceName,
-    RelativePathname,
-    SelectedByAllName,
-    SelectedElement,
-    SelectedName,
-    SimpleName,
-    SliceName,
-
-    // PSL
-    PslAssertDirective,
-    PslAssumeDirective,
-    PslBooleanParameter,
-    PslCoverDirective,
-    PslDeclaration,
-    PslDefaultClock,
-    PslEndpointDeclaration,
-    PslExpression,
-    PslFell,
-    PslHierarchicalName,
-    PslInheritSpec,
-    PslOnehot,
-    PslOnehot0,
-    PslPrev,
-    PslRestrictDirective,
-    PslRose,
-    PslStable,
-
-    // Sequential Statements
-    AssertionStatement,
-    BreakElement,
-    BreakStatement,
-    CaseStatement,
-    ConditionalSignalAssignmentStatement,
-    ConditionalVariableAssignmentStatement,
-    Elsif,
-    ExitStatement,
-    ForLoopStatement,
-    IfStatement,
-    NextStatement,
-    NullStatement,
-    ProcedureCall,
-    ProcedureCallStatement,
-    ReportStatement,
-    ReturnStatement,
-    SelectedVariableAssignmentStatement,
-    SelectedWaveformAssignmentStatement,
-    SignalForceAssignmentStatement,
-    SignalReleaseAssignmentStatement,
-    SimpleSignalAssignmentStatement,
-    SuspendStateStatement,
-    VariableAssignmentStatement,
-    WaitStatement,
-    WhileLoopStatement,
-
-    // Specifications
-    AttributeSpecification,
-    ConfigurationSpecification,
-    DisconnectionSpecification,
-    EntityClass,
-    StepLimitSpecification,
-
-    // Types
-    AccessSubtypeDefinition,
-    AccessTypeDefinition,
-    ArrayModeViewElement,
-    ArrayModeViewIndication,
-    ArrayNatureDefinition,
-    ArraySubnatureDefinition,
-    ArraySubtypeDefinition,
-    ArrayTypeDefinition,
-    EnumerationSubtypeDefinition,
-    EnumerationTypeDefinition,
-    FileDefinition,
-    FileSubtypeDefinition,
-    FileTypeDefinition,
-    FloatingSubtypeDefinition,
-    FloatingTypeDefinition,
-    ForeignVectorTypeDefinition,
-    IncompleteTypeDefinition,
-    IntegerSubtypeDefinition,
-    IntegerTypeDefinition,
-    InterfaceTypeDefinition,
-    PhysicalSubtypeDefinition,
-    PhysicalTypeDefinition,
-    ProtectedTypeBody,
-    ProtectedTypeDeclaration,
-    RecordElementConstraint,
-    RecordElementResolution,
-    RecordModeViewElement,
-    RecordModeViewIndication,
-    RecordNatureDefinition,
-    RecordResolution,
-    RecordSubtypeDefinition,
-    RecordTypeDefinition,
-    ScalarNatureDefinition,
-    SimpleModeViewElement,
-    WildcardTypeDefinition,
-
-    // Waveforms
-    ConditionalExpression,
-    ConditionalWaveform,
-    UnaffectedWaveform,
-    WaveformElement,
+/// Invokes the given macro with the list of all node kinds.
+///
+/// Code generated for every node kind (e.g. the [`Node`] enum or the
+/// [visitors](crate::visit)) is derived from this list.
+macro_rules! with_node_kinds {
+    ($callback:ident) => {
+        $callback! {
+            // Associations
+            AssociationElementByExpression,
+            AssociationElementByIndividual,
+            AssociationElementByName,
+            AssociationElementOpen,
+            AssociationElementPackage,
+            AssociationElementSubprogram,
+            AssociationElementTerminal,
+            AssociationElementType,
+
+            // Attributes
+            Attribute,
+            AttributeValue,
+
+            // Choices
+            ChoiceByExpression,
+            ChoiceByName,
+            ChoiceByNone,
+            ChoiceByOthers,
+            ChoiceByRange,
+
+            // Common
+            Error,
+            OverloadList,
+
+            // Concurrent Statements
+            BlockStatement,
+            CaseGenerateStatement,
+            ComponentInstantiationStatement,
+            ConcurrentAssertionStatement,
+            ConcurrentBreakStatement,
+            ConcurrentConditionalSignalAssignment,
+            ConcurrentProcedureCallStatement,
+            ConcurrentSelectedSignalAssignment,
+            ConcurrentSimpleSignalAssignment,
+            ForGenerateStatement,
+            GenerateStatementBody,
+            IfGenerateElseClause,
+            IfGenerateStatement,
+            ProcessStatement,
+            SensitizedProcessStatement,
+            SimpleSimultaneousStatement,
+            SimultaneousCaseStatement,
+            SimultaneousElsif,
+            SimultaneousIfStatement,
+            SimultaneousNullStatement,
+            SimultaneousProceduralStatement,
+
+            // Configuration
+            BindingIndication,
+            BlockConfiguration,
+            BlockHeader,
+            ComponentConfiguration,
+            EntityAspectConfiguration,
+            EntityAspectEntity,
+            EntityAspectOpen,
+
+            // Declarations
+            AcrossQuantityDeclaration,
+            AnonymousTypeDeclaration,
+            ArrayElementResolution,
+            AttributeDeclaration,
+            AttributeImplicitDeclaration,
+            ComponentDeclaration,
+            ConstantDeclaration,
+            ElementDeclaration,
+            FileDeclaration,
+            FreeQuantityDeclaration,
+            FunctionBody,
+            FunctionDeclaration,
+            FunctionInstantiationDeclaration,
+            GroupDeclaration,
+            GroupTemplateDeclaration,
+            GuardSignalDeclaration,
+            InterfaceConstantDeclaration,
+            InterfaceFileDeclaration,
+            InterfaceFunctionDeclaration,
+            InterfaceProcedureDeclaration,
+            InterfaceQuantityDeclaration,
+            InterfaceSignalDeclaration,
+            InterfaceTerminalDeclaration,
+            InterfaceTypeDeclaration,
+            InterfaceVariableDeclaration,
+            InterfaceViewDeclaration,
+            IteratorDeclaration,
+            ModeViewDeclaration,
+            NatureDeclaration,
+            NatureElementDeclaration,
+            NoiseQuantityDeclaration,
+            NonObjectAliasDeclaration,
+            ObjectAliasDeclaration,
+            ProcedureBody,
+            ProcedureDeclaration,
+            ProcedureInstantiationDeclaration,
+            SignalDeclaration,
+            Signature,
+            SpectrumQuantityDeclaration,
+            SubnatureDeclaration,
+            SubprogramInstantiationBody,
+            SubtypeDeclaration,
+            SuspendStateDeclaration,
+            TerminalDeclaration,
+            ThroughQuantityDeclaration,
+            TypeDeclaration,
+            UnitDeclaration,
+            VariableDeclaration,
+
+            // Expressions
+            Aggregate,
+            AggregateInfo,
+            AllocatorByExpression,
+            AllocatorBySubtype,
+            BinaryOperator,
+            CharacterLiteral,
+            EnumerationLiteral,
+            FloatingPointLiteral,
+            FunctionCall,
+            IntegerLiteral,
+            NullLiteral,
+            OverflowLiteral,
+            ParenthesisExpression,
+            PhysicalFpLiteral,
+            PhysicalIntLiteral,
+            QualifiedExpression,
+            RangeExpression,
+            SimpleAggregate,
+            #[serde(rename = "string_literal8")]
+            StringLiteral,
+            TypeConversion,
+            UnaryOperator,
+
+            // Libraries
+            ArchitectureBody,
+            ConfigurationDeclaration,
+            ContextDeclaration,
+            ContextReference,
+            DesignFile,
+            DesignUnit,
+            EntityDeclaration,
+            ForeignModule,
+            InterfacePackageDeclaration,
+            #[serde(rename = "library_declaration")]
+            Library,
+            LibraryClause,
+            PackageBody,
+            PackageDeclaration,
+            PackageHeader,
+            PackageInstantiationBody,
+            PackageInstantiationDeclaration,
+            UseClause,
+            VmodeDeclaration,
+            VpropDeclaration,
+            VunitDeclaration,
+
+            // Names
+            AbsolutePathname,
+            AttributeName,
+            BoxName,
+            Dereference,
+            ExternalConstantName,
+            ExternalSignalName,
+            ExternalVariableName,
+            ImplicitDereference,
+            IndexedName,
+            OperatorSymbol,
+            PackagePathname,
+            ParenthesisName,
+            PathnameElement,
+            ReferenceName,
+            RelativePathname,
+            SelectedByAllName,
+            SelectedElement,
+            SelectedName,
+            SimpleName,
+            SliceName,
+
+            // PSL
+            PslAssertDirective,
+            PslAssumeDirective,
+            PslBooleanParameter,
+            PslCoverDirective,
+            PslDeclaration,
+            PslDefaultClock,
+            PslEndpointDeclaration,
+            PslExpression,
+            PslFell,
+            PslHierarchicalName,
+            PslInheritSpec,
+            PslOnehot,
+            PslOnehot0,
+            PslPrev,
+            PslRestrictDirective,
+            PslRose,
+            PslStable,
+
+            // Sequential Statements
+            AssertionStatement,
+            BreakElement,
+            BreakStatement,
+            CaseStatement,
+            ConditionalSignalAssignmentStatement,
+            ConditionalVariableAssignmentStatement,
+            Elsif,
+            ExitStatement,
+            ForLoopStatement,
+            IfStatement,
+            NextStatement,
+            NullStatement,
+            ProcedureCall,
+            ProcedureCallStatement,
+            ReportStatement,
+            ReturnStatement,
+            SelectedVariableAssignmentStatement,
+            SelectedWaveformAssignmentStatement,
+            SignalForceAssignmentStatement,
+            SignalReleaseAssignmentStatement,
+            SimpleSignalAssignmentStatement,
+            SuspendStateStatement,
+            VariableAssignmentStatement,
+            WaitStatement,
+            WhileLoopStatement,
+
+            // Specifications
+            AttributeSpecification,
+            ConfigurationSpecification,
+            DisconnectionSpecification,
+            EntityClass,
+            StepLimitSpecification,
+
+            // Types
+            AccessSubtypeDefinition,
+            AccessTypeDefinition,
+            ArrayModeViewElement,
+            ArrayModeViewIndication,
+            ArrayNatureDefinition,
+            ArraySubnatureDefinition,
+            ArraySubtypeDefinition,
+            ArrayTypeDefinition,
+            EnumerationSubtypeDefinition,
+            EnumerationTypeDefinition,
+            FileDefinition,
+            FileSubtypeDefinition,
+            FileTypeDefinition,
+            FloatingSubtypeDefinition,
+            FloatingTypeDefinition,
+            ForeignVectorTypeDefinition,
+            IncompleteTypeDefinition,
+            IntegerSubtypeDefinition,
+            IntegerTypeDefinition,
+            InterfaceTypeDefinition,
+            PhysicalSubtypeDefinition,
+            PhysicalTypeDefinition,
+            ProtectedTypeBody,
+            ProtectedTypeDeclaration,
+            RecordElementConstraint,
+            RecordElementResolution,
+            RecordModeViewElement,
+            RecordModeViewIndication,
+            RecordNatureDefinition,
+            RecordResolution,
+            RecordSubtypeDefinition,
+            RecordTypeDefinition,
+            ScalarNatureDefinition,
+            SimpleModeViewElement,
+            WildcardTypeDefinition,
+
+            // Waveforms
+            ConditionalExpression,
+            ConditionalWaveform,
+            UnaffectedWaveform,
+            WaveformElement,
+        }
+    };
 }
 
+pub(crate) use with_node_kinds;
+
+with_node_kinds!(node_declaration);
+
 /// Declares a typed subset of [`Node`] variants and a corresponding node ID type.
 ///
+/// The optional `subset_of` list names the subsets (and their ID types) which
+/// contain all variants of this one; `From` conversions into them are generated
+/// for the ID and reference types, along with a `TryFrom` conversion back for
+/// the reference types. The optional `overlaps` list names subsets which share
+/// only some variants with this one; `TryFrom` conversions in both directions
+/// are generated for the reference types. Each pair of subsets must only be
+/// listed once.
+///
 /// # Example
 ///
-/// ```ignore
-/// ghdl_ast::subset_declaration!(MySubset MySubsetNodeId {
+/// ```
+/// ghdl_ast::subset_declaration!(MySubset MySubsetOwned MySubsetNodeId {
 ///     SimpleName(SimpleName),
 ///     SelectedName(SelectedName),
-/// });
+/// } subset_of [Name NameNodeId] overlaps [DenotingName]);
 /// ```
 #[macro_export]
 macro_rules! subset_declaration {
-    ( $name:ident $owned_name:ident $name_id:ident {
+    (@subset_of $name:ident $name_id:ident {
+        $(
+            $(#[$variant_attr:meta])*
+            $variant:ident($type:ident)
+        ),+ $(,)?
+    } $superset:ident $superset_id:ident) => {
+        impl<'ast> ::std::convert::From<$name<'ast>> for $crate::$superset<'ast> {
+            fn from(value: $name<'ast>) -> Self {
+                match value {
+                    $(
+                        $name::$variant(inner) => Self::from(inner),
+                    )+
+                }
+            }
+        }
+
+        impl<'ast> ::std::convert::TryFrom<$crate::$superset<'ast>> for $name<'ast> {
+            type Error = $crate::TryFromNodeError;
+
+            fn try_from(value: $crate::$superset<'ast>) -> ::std::result::Result<Self, Self::Error> {
+                $crate::SubsetRef::try_convert(value)
+            }
+        }
+
+        impl ::std::convert::From<$name_id> for $crate::$superset_id {
+            fn from(value: $name_id) -> Self {
+                Self::from(value.0)
+            }
+        }
+
+        const _: () = assert!(
+            $crate::__private::contains_kinds(
+                <$crate::$superset<'static> as $crate::SubsetRef<'static>>::KINDS,
+                <$name<'static> as $crate::SubsetRef<'static>>::KINDS,
+            ),
+            concat!("`", stringify!($name), "` isn't a subset of `", stringify!($superset), "`"),
+        );
+    };
+
+    (@overlaps $name:ident $other:ident) => {
+        const _: () = assert!(
+            $crate::__private::shares_kind(
+                <$name<'static> as $crate::SubsetRef<'static>>::KINDS,
+                <$crate::$other<'static> as $crate::SubsetRef<'static>>::KINDS,
+            ),
+            concat!("`", stringify!($name), "` doesn't overlap `", stringify!($other), "`"),
+        );
+
+        impl<'ast> ::std::convert::TryFrom<$crate::$other<'ast>> for $name<'ast> {
+            type Error = $crate::TryFromNodeError;
+
+            fn try_from(value: $crate::$other<'ast>) -> ::std::result::Result<Self, Self::Error> {
+                $crate::SubsetRef::try_convert(value)
+            }
+        }
+
+        impl<'ast> ::std::convert::TryFrom<$name<'ast>> for $crate::$other<'ast> {
+            type Error = $crate::TryFromNodeError;
+
+            fn try_from(value: $name<'ast>) -> ::std::result::Result<Self, Self::Error> {
+                $crate::SubsetRef::try_convert(value)
+            }
+        }
+    };
+
+    ( $name:ident $owned_name:ident $name_id:ident $variants:tt
+        $(subset_of [$($superset:ident $superset_id:ident),+ $(,)?])?
+        $(overlaps [$($other:ident),+ $(,)?])?
+    ) => {
+        $crate::subset_declaration!(@declare $name $owned_name $name_id $variants);
+        $($(
+            $crate::subset_declaration!(@subset_of $name $name_id $variants $superset $superset_id);
+        )+)?
+        $($(
+            $crate::subset_declaration!(@overlaps $name $other);
+        )+)?
+    };
+
+    (@declare $name:ident $owned_name:ident $name_id:ident {
         $(
             $(#[$variant_attr:meta])*
             $variant:ident($type:ident)
@@ -631,6 +1104,48 @@ macro_rules! subset_declaration {
             )+
         }
 
+        $(
+            impl<'ast> ::std::convert::From<&'ast $crate::$type> for $name<'ast> {
+                fn from(value: &'ast $crate::$type) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )+
+
+        impl $crate::NodeKind for $name<'_> {
+            type Id = $name_id;
+        }
+
+        impl<'ast> $crate::SubsetRef<'ast> for $name<'ast> {
+            const NAME: &'static str = stringify!($name);
+            const KINDS: &'static [&'static str] = &[$(stringify!($type)),+];
+
+            fn from_any(node: &'ast dyn ::std::any::Any) -> ::std::option::Option<Self> {
+                $(
+                    if let Some(inner) = node.downcast_ref::<$crate::$type>() {
+                        return Some(Self::$variant(inner));
+                    }
+                )+
+                None
+            }
+
+            fn as_any(&self) -> &'ast dyn ::std::any::Any {
+                match *self {
+                    $(
+                        Self::$variant(inner) => inner,
+                    )+
+                }
+            }
+
+            fn type_str(&self) -> &'static str {
+                match self {
+                    $(
+                        Self::$variant(..) => stringify!($type),
+                    )+
+                }
+            }
+        }
+
         impl<'ast> ::std::convert::TryFrom<&'ast $crate::Node> for $name<'ast> {
             type Error = $crate::TryFromNodeError;
 
@@ -685,6 +1200,25 @@ macro_rules! subset_declaration {
             pub(crate) fn new(id: $crate::IdPrimitive) -> Self {
                 Self(id)
             }
+
+            /// Converts this ID into the ID of another node subset after checking
+            /// that the node is of a kind in that subset.
+            ///
+            /// # Errors
+            ///
+            /// Returns an `Err` if the node is not found or is not in the subset `S`.
+            pub fn downcast_subset<S>(
+                self,
+                ast: &$crate::Ast,
+            ) -> ::std::result::Result<S, $crate::LookupNodeError>
+            where
+                S: $crate::AstNodeId + ::std::convert::From<$crate::IdPrimitive>,
+                for<'ast> S::NodeType<'ast>: ::std::convert::TryFrom<&'ast $crate::Node>,
+            {
+                let id = S::from(self.0);
+                $crate::AstNodeId::try_get(&id, ast)?;
+                Ok(id)
+            }
         }
 
         impl ::std::convert::From<$crate::IdPrimitive> for $name_id {
@@ -756,6 +1290,14 @@ macro_rules! subset_declaration {
             }
         }
 
+        impl $crate::NodeReference for $name_id {
+            const NAME: &'static str = stringify!($name_id);
+
+            fn accepts(node: &$crate::Node) -> bool {
+                matches!(node, $($crate::Node::$type(_))|+)
+            }
+        }
+
         $(
             impl $crate::DowncastNodeId<$crate::$type> for $name_id {}
         )+
@@ -770,6 +1312,88 @@ macro_rules! subset_declaration {
     };
 }
 
+/// Conversion between the reference enums of overlapping node subsets.
+///
+/// Implemented by [`subset_declaration!`] for all reference enums, and used by the
+/// `TryFrom` conversions it generates between them.
+#[doc(hidden)]
+pub trait SubsetRef<'ast>: Sized {
+    /// Name of the subset.
+    const NAME: &'static str;
+
+    /// Type names of the nodes in this subset.
+    const KINDS: &'static [&'static str];
+
+    /// Wraps the given node in the matching variant, or returns `None` if its type isn't in this subset.
+    fn from_any(node: &'ast dyn Any) -> Option<Self>;
+
+    /// Returns the node wrapped by this variant.
+    fn as_any(&self) -> &'ast dyn Any;
+
+    /// Returns the type name of the node wrapped by this variant.
+    fn type_str(&self) -> &'static str;
+
+    /// Converts a reference enum of another subset into this one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the wrapped node's type isn't in this subset.
+    fn try_convert<S>(value: S) -> Result<Self, TryFromNodeError>
+    where
+        S: SubsetRef<'ast>,
+    {
+        Self::from_any(value.as_any()).ok_or_else(|| TryFromNodeError {
+            actual: value.type_str(),
+            expected: Self::NAME,
+        })
+    }
+}
+
+/// Returns whether `kinds` contains the node type name `kind`, in constant evaluation.
+const fn contains_kind(kinds: &[&str], kind: &str) -> bool {
+    let mut index = 0;
+    while index < kinds.len() {
+        let (left, right) = (kinds[index].as_bytes(), kind.as_bytes());
+        let mut position = 0;
+        while position < left.len() && position < right.len() && left[position] == right[position] {
+            position += 1;
+        }
+        if position == left.len() && position == right.len() {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+/// Returns whether every node type name of `subset` is in `kinds`, checking the `subset_of` lists of [`subset_declaration!`].
+#[doc(hidden)]
+#[must_use]
+pub const fn contains_kinds(kinds: &[&str], subset: &[&str]) -> bool {
+    let mut index = 0;
+    while index < subset.len() {
+        if !contains_kind(kinds, subset[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Returns whether `kinds` and `others` share a node type name, checking the `overlaps` lists of [`subset_declaration!`].
+#[doc(hidden)]
+#[must_use]
+pub const fn shares_kind(kinds: &[&str], others: &[&str]) -> bool {
+    let mut index = 0;
+    while index < others.len() {
+        if contains_kind(kinds, others[index]) {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
 /// Error returned when converting a [`Node`] into a typed subset fails.
 #[derive(Clone, Copy, Debug, thiserror::Error)]
 #[error("node is of type {actual}; expected {expected}")]
@@ -779,3 +1403,142 @@ pub struct TryFromNodeError {
     /// Expected subset type name.
     pub expected: &'static str,
 }
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::load_fixture;
+
+    #[test]
+    fn test_downcast() {
+        let ast = load_fixture();
+        let id = |raw| IdPrimitive::new(raw).unwrap();
+
+        let signal = NamedEntityNodeId::from(id(9));
+        assert_eq!(
+            signal.try_downcast(&ast).ok(),
+            Some(NodeId::<SignalDeclaration>::from_raw(id(9)))
+        );
+        assert!(matches!(
+            DowncastNodeId::<EntityDeclaration>::try_downcast(signal, &ast),
+            Err(LookupNodeError::WrongType {
+                actual: "SignalDeclaration",
+                ..
+            })
+        ));
+
+        let object = signal
+            .downcast_subset::<ObjectDeclarationNodeId>(&ast)
+            .unwrap();
+        assert_eq!(object, ObjectDeclarationNodeId::from(id(9)));
+        assert!(matches!(
+            object.get(&ast),
+            ObjectDeclaration::Signal(declaration) if *declaration.identifier.normalized == *"clk"
+        ));
+        assert!(matches!(
+            NamedEntityNodeId::from(id(5)).downcast_subset::<ObjectDeclarationNodeId>(&ast),
+            Err(LookupNodeError::WrongType {
+                actual: "EntityDeclaration",
+                ..
+            })
+        ));
+        assert!(matches!(
+            NamedEntityNodeId::from(id(42)).downcast_subset::<ObjectDeclarationNodeId>(&ast),
+            Err(LookupNodeError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_subset_conversions() {
+        let ast = load_fixture();
+        let id = |raw| IdPrimitive::new(raw).unwrap();
+
+        let literal_id = LiteralNodeId::from(id(12));
+        assert_eq!(
+            ExpressionNodeId::from(literal_id),
+            ExpressionNodeId::from(id(12))
+        );
+        let expression = Expression::from(literal_id.get(&ast));
+        assert!(matches!(expression, Expression::IntegerLiteral(_)));
+        assert!(matches!(
+            Literal::try_from(expression),
+            Ok(Literal::Integer(literal)) if literal.value == 0
+        ));
+
+        let name = NameNodeId::from(id(16)).get(&ast);
+        assert!(matches!(Prefix::try_from(name), Ok(Prefix::SimpleName(_))));
+        let name_expression = Expression::try_from(name).unwrap();
+        assert!(matches!(name_expression, Expression::SimpleName(_)));
+        let error = Literal::try_from(name_expression).unwrap_err();
+        assert_eq!(error.actual, "SimpleName");
+        assert_eq!(error.expected, "Literal");
+
+        let signal = NamedEntityNodeId::from(id(9)).get(&ast);
+        assert!(matches!(
+            ObjectDeclaration::try_from(signal),
+            Ok(ObjectDeclaration::Signal(_))
+        ));
+        let entity = NamedEntityNodeId::from(id(5)).get(&ast);
+        assert_eq!(
+            ObjectDeclaration::try_from(entity).unwrap_err().actual,
+            "EntityDeclaration"
+        );
+    }
+
+    #[test]
+    fn test_nodes_of() {
+        let ast = load_fixture();
+        let id = |raw| IdPrimitive::new(raw).unwrap();
+
+        assert_eq!(ast.iter_nodes().count(), 19);
+        assert!(matches!(
+            ast.iter_nodes().nth(4),
+            Some((id5, Node::EntityDeclaration(_))) if id5.to_raw() == id(5)
+        ));
+
+        let signals = ast.nodes_of::<SignalDeclaration>().collect::<Vec<_>>();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].0, NodeId::from_raw(id(9)));
+        assert_eq!(signals[0].1.identifier.normalized.as_str(), "clk");
+
+        let statements = ast
+            .nodes_of::<ConcurrentStatement<'_>>()
+            .map(|(statement_id, _)| statement_id)
+            .collect::<Vec<_>>();
+        assert_eq!(statements, [ConcurrentStatementNodeId::from(id(14))]);
+
+        let names = ast
+            .nodes_of::<Name<'_>>()
+            .map(|(name_id, _)| name_id)
+            .collect::<Vec<_>>();
+        assert_eq!(names, [8, 16, 17].map(|raw| NameNodeId::from(id(raw))));
+    }
+
+    #[test]
+    fn test_node_children() {
+        let ast = load_fixture();
+        let id = |raw| GenericNodeId::from_raw(IdPrimitive::new(raw).unwrap());
+
+        let architecture_children = id(7).get(&ast).children().collect::<Vec<_>>();
+        assert_eq!(
+            architecture_children,
+            [
+                ("entity_name", id(8)),
+                ("parent", id(6)),
+                ("declarations", id(9)),
+                ("concurrent_statements", id(14)),
+            ]
+        );
+
+        let process_children = id(14).get(&ast).children().collect::<Vec<_>>();
+        assert_eq!(
+            process_children,
+            [
+                ("sequential_statements", id(15)),
+                ("sensitivity_list", id(17))
+            ]
+        );
+        assert_eq!(id(19).get(&ast).children().count(), 0);
+    }
+}
@@ -0,0 +1,2534 @@
+//! Semantics of the predefined operations, see [`ImplicitDefinition::apply`].
+//!
+//! Implements the operations of `std.standard` (LRM §9.2, §16.2) and of the
+//! `ieee` packages `std_logic_1164`, `numeric_std`, `numeric_std_unsigned`,
+//! `numeric_bit`, `std_logic_misc` and `math_real` over a small value model,
+//! [`Value`], so that static evaluators and simulators can share them.
+//!
+//! Enumeration values are represented by their position: `false` and `'0'` of
+//! `bit` are 0, `true` and `'1'` are 1, the nine values of `std_ulogic` are 0
+//! (`'U'`) to 8 (`'-'`), and characters are their Latin-1 code, so strings are
+//! arrays of codes. The leftmost element of `signed` and `unsigned` vectors is
+//! their most significant bit.
+//!
+//! Operations which need more than values, such as the images of enumeration
+//! literals, the index range of arrays, signals, files or the simulation time,
+//! aren't supported. Neither are the Synopsys packages `std_logic_arith`,
+//! `std_logic_signed` and `std_logic_unsigned`.
+
+use std::cmp::Ordering;
+
+use super::*;
+
+/// Position of `'U'` in `std_ulogic`.
+const STD_U: u32 = 0;
+/// Position of `'X'` in `std_ulogic`.
+const STD_X: u32 = 1;
+/// Position of `'0'` in `std_ulogic`.
+const STD_0: u32 = 2;
+/// Position of `'1'` in `std_ulogic`.
+const STD_1: u32 = 3;
+/// Position of `'Z'` in `std_ulogic`.
+const STD_Z: u32 = 4;
+/// Position of `'W'` in `std_ulogic`.
+const STD_W: u32 = 5;
+/// Position of `'L'` in `std_ulogic`.
+const STD_L: u32 = 6;
+/// Position of `'H'` in `std_ulogic`.
+const STD_H: u32 = 7;
+/// Position of `'-'` in `std_ulogic`.
+const STD_DONT_CARE: u32 = 8;
+
+/// Width at which integer operands are converted to vectors without truncation.
+const INTEGER_WIDTH: usize = 65;
+
+/// Value of a static expression or of an operand of a predefined operation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// Value of an integer type.
+    Integer(i64),
+    /// Value of a floating-point type.
+    Real(f64),
+    /// Value of a physical type, in multiples of its primary unit.
+    Physical(i64),
+    /// Value of an enumeration type, as the position of its literal.
+    Enum(u32),
+    /// Value of an array type, as its elements from left to right.
+    Array(Vec<Value>),
+    /// Value of a record type, as its elements in declaration order.
+    Record(Vec<Value>),
+}
+
+impl Value {
+    /// Returns the value of `false` or `true` of the predefined type `boolean`.
+    #[must_use]
+    pub fn boolean(value: bool) -> Self {
+        Self::Enum(u32::from(value))
+    }
+
+    /// Returns the value of a `string` made of the given Latin-1 characters.
+    #[must_use]
+    pub fn string(text: &str) -> Self {
+        Self::Array(
+            text.chars()
+                .map(|character| Self::Enum(character.into()))
+                .collect(),
+        )
+    }
+}
+
+/// Error returned when applying a predefined operation fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PredefinedError {
+    /// The operation isn't supported over the value model, or not for these operands.
+    #[error("operation not supported")]
+    Unsupported,
+
+    /// The result is out of the range of the value model.
+    #[error("result out of range")]
+    Overflow,
+
+    /// The operation divides by zero.
+    #[error("division by zero")]
+    DivisionByZero,
+
+    /// The operands are outside the domain of the operation, e.g. arrays of different lengths.
+    #[error("invalid operands")]
+    InvalidOperands,
+}
+
+type Outcome = Result<Value, PredefinedError>;
+
+/// Groups of predefined operations, each of which is unsupported by the others.
+const FAMILIES: [fn(ImplicitDefinition, &[Value]) -> Outcome; 9] = [
+    arithmetic, relational, array, logical, std_ulogic, conversion, numeric, math_real, image,
+];
+
+impl ImplicitDefinition {
+    /// Applies the predefined operation to the values of its operands.
+    ///
+    /// Operands are given in the order of the parameters; trailing parameters with a
+    /// default value, such as the `xmap` of `to_bit`, may be omitted. Integer and physical
+    /// values are 64 bits wide, and checking results against the range of their subtype is
+    /// left to the caller.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operation isn't supported for these operands, if it
+    /// overflows or divides by zero, or if its operands are outside its domain.
+    pub fn apply(self, operands: &[Value]) -> Result<Value, PredefinedError> {
+        for family in FAMILIES {
+            match family(self, operands) {
+                Err(PredefinedError::Unsupported) => {},
+                outcome => return outcome,
+            }
+        }
+        Err(PredefinedError::Unsupported)
+    }
+}
+
+/// Arithmetic operators on scalars (LRM §9.2.6 to §9.2.8).
+fn arithmetic(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use ImplicitDefinition::*;
+    use Value::*;
+
+    match (definition, operands) {
+        (IntegerIdentity | FloatingIdentity | PhysicalIdentity, [value]) => Ok(value.clone()),
+        (IntegerNegation, [Integer(value)]) => integer(value.checked_neg()),
+        (PhysicalNegation, [Physical(value)]) => physical(value.checked_neg()),
+        (FloatingNegation, [Real(value)]) => real(-value),
+        (IntegerAbsolute, [Integer(value)]) => integer(value.checked_abs()),
+        (PhysicalAbsolute, [Physical(value)]) => physical(value.checked_abs()),
+        (FloatingAbsolute, [Real(value)]) => real(value.abs()),
+
+        (IntegerPlus, [Integer(left), Integer(right)]) => integer(left.checked_add(*right)),
+        (PhysicalPlus, [Physical(left), Physical(right)]) => physical(left.checked_add(*right)),
+        (FloatingPlus, [Real(left), Real(right)]) => real(left + right),
+        (IntegerMinus, [Integer(left), Integer(right)]) => integer(left.checked_sub(*right)),
+        (PhysicalMinus, [Physical(left), Physical(right)]) => physical(left.checked_sub(*right)),
+        (FloatingMinus, [Real(left), Real(right)]) => real(left - right),
+
+        (IntegerMul, [Integer(left), Integer(right)]) => integer(left.checked_mul(*right)),
+        (FloatingMul, [Real(left), Real(right)]) => real(left * right),
+        (PhysicalIntegerMul, [Physical(left), Integer(right)])
+        | (IntegerPhysicalMul, [Integer(right), Physical(left)]) => {
+            physical(left.checked_mul(*right))
+        },
+        (PhysicalRealMul, [Physical(left), Real(right)])
+        | (RealPhysicalMul, [Real(right), Physical(left)]) => {
+            physical(real_to_integer(integer_to_real(*left) * right))
+        },
+        (UniversalIRMul, [Integer(left), Real(right)])
+        | (UniversalRIMul, [Real(right), Integer(left)]) => real(integer_to_real(*left) * right),
+
+        (IntegerDiv, [Integer(left), Integer(right)]) => divide(*left, *right).map(Integer),
+        (PhysicalIntegerDiv, [Physical(left), Integer(right)]) => {
+            divide(*left, *right).map(Physical)
+        },
+        (PhysicalPhysicalDiv, [Physical(left), Physical(right)]) => {
+            divide(*left, *right).map(Integer)
+        },
+        (FloatingDiv, [Real(left), Real(right)]) => real(divide_real(*left, *right)?),
+        (PhysicalRealDiv, [Physical(left), Real(right)]) => physical(real_to_integer(divide_real(
+            integer_to_real(*left),
+            *right,
+        )?)),
+        (UniversalRIDiv, [Real(left), Integer(right)]) => {
+            real(divide_real(*left, integer_to_real(*right))?)
+        },
+        (IntegerRem, [Integer(left), Integer(right)]) => remainder(*left, *right).map(Integer),
+        (PhysicalRem, [Physical(left), Physical(right)]) => remainder(*left, *right).map(Physical),
+        (IntegerMod, [Integer(left), Integer(right)]) => modulus(*left, *right).map(Integer),
+        (PhysicalMod, [Physical(left), Physical(right)]) => modulus(*left, *right).map(Physical),
+
+        // Negative exponents are only allowed for floating-point left operands.
+        (IntegerExp, [Integer(left), Integer(right)]) => {
+            let exponent =
+                u32::try_from(*right).map_err(|_ignore| PredefinedError::InvalidOperands)?;
+            integer(left.checked_pow(exponent))
+        },
+        (FloatingExp, [Real(left), Integer(right)]) => {
+            if *left == 0.0 && *right < 0 {
+                return Err(PredefinedError::DivisionByZero);
+            }
+            real(left.powf(integer_to_real(*right)))
+        },
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Relational operators, `minimum` and `maximum`, and the matching operators and condition
+/// operator on `bit` (LRM §9.2.3, §5.3.2.4).
+fn relational(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use ImplicitDefinition::*;
+    use Value::*;
+
+    // The matching operators on `bit` return a `bit`, which is represented like a `boolean`.
+    match (definition, operands) {
+        (
+            IntegerEquality | FloatingEquality | PhysicalEquality | EnumEquality | ArrayEquality
+            | RecordEquality | BitMatchEquality,
+            [left, right],
+        ) => Ok(Value::boolean(left == right)),
+        (
+            IntegerInequality | FloatingInequality | PhysicalInequality | EnumInequality
+            | ArrayInequality | RecordInequality | BitMatchInequality,
+            [left, right],
+        ) => Ok(Value::boolean(left != right)),
+        (
+            IntegerLess | FloatingLess | PhysicalLess | EnumLess | ArrayLess | BitMatchLess,
+            [left, right],
+        ) => compare(left, right).map(|ordering| Value::boolean(ordering.is_lt())),
+        (
+            IntegerLessEqual | FloatingLessEqual | PhysicalLessEqual | EnumLessEqual
+            | ArrayLessEqual | BitMatchLessEqual,
+            [left, right],
+        ) => compare(left, right).map(|ordering| Value::boolean(ordering.is_le())),
+        (
+            IntegerGreater | FloatingGreater | PhysicalGreater | EnumGreater | ArrayGreater
+            | BitMatchGreater,
+            [left, right],
+        ) => compare(left, right).map(|ordering| Value::boolean(ordering.is_gt())),
+        (
+            IntegerGreaterEqual | FloatingGreaterEqual | PhysicalGreaterEqual | EnumGreaterEqual
+            | ArrayGreaterEqual | BitMatchGreaterEqual,
+            [left, right],
+        ) => compare(left, right).map(|ordering| Value::boolean(ordering.is_ge())),
+        (BitArrayMatchEquality | BitArrayMatchInequality, [Array(left), Array(right)]) => {
+            if left.len() != right.len() {
+                return Err(PredefinedError::InvalidOperands);
+            }
+            let equal = left == right;
+            Ok(Value::boolean(
+                equal == matches!(definition, BitArrayMatchEquality),
+            ))
+        },
+        (BitCondition, [Enum(value)]) => Ok(Value::boolean(Logic::Bit.bit(*value)?)),
+
+        (
+            IntegerMinimum | FloatingMinimum | PhysicalMinimum | EnumMinimum | ArrayMinimum,
+            [left, right],
+        ) => Ok(if compare(left, right)?.is_gt() {
+            right
+        } else {
+            left
+        }
+        .clone()),
+        (
+            IntegerMaximum | FloatingMaximum | PhysicalMaximum | EnumMaximum | ArrayMaximum,
+            [left, right],
+        ) => Ok(if compare(left, right)?.is_lt() {
+            right
+        } else {
+            left
+        }
+        .clone()),
+        // The result for a null array is the low bound of the element subtype.
+        (VectorMinimum | VectorMaximum, [Array(elements)]) => {
+            let mut elements = elements.iter();
+            let mut result = elements.next().ok_or(PredefinedError::Unsupported)?;
+            for element in elements {
+                let ordering = compare(element, result)?;
+                if matches!(definition, VectorMinimum) && ordering.is_lt()
+                    || matches!(definition, VectorMaximum) && ordering.is_gt()
+                {
+                    result = element;
+                }
+            }
+            Ok(result.clone())
+        },
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Concatenation and shift operators on one-dimensional arrays (LRM §9.2.4, §9.2.5).
+fn array(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use ImplicitDefinition::*;
+    use Value::*;
+
+    match (definition, operands) {
+        (ArrayArrayConcat, [Array(left), Array(right)]) => {
+            Ok(Array(left.iter().chain(right).cloned().collect()))
+        },
+        (ArrayElementConcat, [Array(left), right]) => {
+            Ok(Array(left.iter().chain([right]).cloned().collect()))
+        },
+        (ElementArrayConcat, [left, Array(right)]) => {
+            Ok(Array([left].into_iter().chain(right).cloned().collect()))
+        },
+        (ElementElementConcat, [left, right]) => Ok(Array(vec![left.clone(), right.clone()])),
+
+        // The predefined shifts fill with the leftmost value of `bit` or `boolean`.
+        (ArraySll, [Array(elements), Integer(count)]) => {
+            Ok(Array(ShiftOperator::Sll.apply(elements, *count, &Enum(0))))
+        },
+        (ArraySrl, [Array(elements), Integer(count)]) => {
+            Ok(Array(ShiftOperator::Srl.apply(elements, *count, &Enum(0))))
+        },
+        (ArraySla, [Array(elements), Integer(count)]) => {
+            Ok(Array(ShiftOperator::Sla.apply(elements, *count, &Enum(0))))
+        },
+        (ArraySra, [Array(elements), Integer(count)]) => {
+            Ok(Array(ShiftOperator::Sra.apply(elements, *count, &Enum(0))))
+        },
+        (ArrayRol, [Array(elements), Integer(count)]) => {
+            Ok(Array(ShiftOperator::Rol.apply(elements, *count, &Enum(0))))
+        },
+        (ArrayRor, [Array(elements), Integer(count)]) => {
+            Ok(Array(ShiftOperator::Ror.apply(elements, *count, &Enum(0))))
+        },
+        (Ieee1164VectorSll, [Array(elements), Integer(count)]) => Ok(Array(
+            ShiftOperator::Sll.apply(elements, *count, &Enum(STD_0)),
+        )),
+        (Ieee1164VectorSrl, [Array(elements), Integer(count)]) => Ok(Array(
+            ShiftOperator::Srl.apply(elements, *count, &Enum(STD_0)),
+        )),
+        (Ieee1164VectorRol, [Array(elements), Integer(count)]) => Ok(Array(
+            ShiftOperator::Rol.apply(elements, *count, &Enum(STD_0)),
+        )),
+        (Ieee1164VectorRor, [Array(elements), Integer(count)]) => Ok(Array(
+            ShiftOperator::Ror.apply(elements, *count, &Enum(STD_0)),
+        )),
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Logical operators on scalars, element by element on arrays, between an array and a
+/// scalar, and as reductions of an array (LRM §9.2.2).
+fn logical(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use Value::*;
+
+    let Some((logic, operator)) = logic_operation(definition) else {
+        return Err(PredefinedError::Unsupported);
+    };
+    let binary = |left: u32, right: u32| logic.binary(operator, left, right).map(Enum);
+    match operands {
+        [Enum(value)] if operator == LogicOperator::Not => logic.not(*value).map(Enum),
+        [Array(elements)] if operator == LogicOperator::Not => positions(elements)?
+            .into_iter()
+            .map(|element| logic.not(element).map(Enum))
+            .collect::<Result<_, _>>()
+            .map(Array),
+        [Array(elements)] => logic.reduce(operator, &positions(elements)?).map(Enum),
+        [Enum(left), Enum(right)] => binary(*left, *right),
+        [Array(left), Array(right)] => {
+            if left.len() != right.len() {
+                return Err(PredefinedError::InvalidOperands);
+            }
+            positions(left)?
+                .into_iter()
+                .zip(positions(right)?)
+                .map(|(left_element, right_element)| binary(left_element, right_element))
+                .collect::<Result<_, _>>()
+                .map(Array)
+        },
+        [Array(left), Enum(right)] => positions(left)?
+            .into_iter()
+            .map(|element| binary(element, *right))
+            .collect::<Result<_, _>>()
+            .map(Array),
+        [Enum(left), Array(right)] => positions(right)?
+            .into_iter()
+            .map(|element| binary(*left, element))
+            .collect::<Result<_, _>>()
+            .map(Array),
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Returns the logic system and operator of a logical operation.
+#[expect(
+    clippy::too_many_lines,
+    reason = "table of the logical operations of all packages"
+)]
+fn logic_operation(definition: ImplicitDefinition) -> Option<(Logic, LogicOperator)> {
+    use ImplicitDefinition::*;
+
+    Some(match definition {
+        BooleanAnd
+        | BitAnd
+        | TfArrayAnd
+        | TfArrayElementAnd
+        | TfElementArrayAnd
+        | TfReductionAnd
+        | IeeeNumericBitAndUnsUns
+        | IeeeNumericBitAndUnsBit
+        | IeeeNumericBitAndBitUns
+        | IeeeNumericBitAndSgnSgn
+        | IeeeNumericBitAndSgnBit
+        | IeeeNumericBitAndBitSgn => (Logic::Bit, LogicOperator::And),
+        BooleanOr
+        | BitOr
+        | TfArrayOr
+        | TfArrayElementOr
+        | TfElementArrayOr
+        | TfReductionOr
+        | IeeeNumericBitOrUnsUns
+        | IeeeNumericBitOrUnsBit
+        | IeeeNumericBitOrBitUns
+        | IeeeNumericBitOrSgnSgn
+        | IeeeNumericBitOrSgnBit
+        | IeeeNumericBitOrBitSgn => (Logic::Bit, LogicOperator::Or),
+        BooleanNand
+        | BitNand
+        | TfArrayNand
+        | TfArrayElementNand
+        | TfElementArrayNand
+        | TfReductionNand
+        | IeeeNumericBitNandUnsUns
+        | IeeeNumericBitNandUnsBit
+        | IeeeNumericBitNandBitUns
+        | IeeeNumericBitNandSgnSgn
+        | IeeeNumericBitNandSgnBit
+        | IeeeNumericBitNandBitSgn => (Logic::Bit, LogicOperator::Nand),
+        BooleanNor
+        | BitNor
+        | TfArrayNor
+        | TfArrayElementNor
+        | TfElementArrayNor
+        | TfReductionNor
+        | IeeeNumericBitNorUnsUns
+        | IeeeNumericBitNorUnsBit
+        | IeeeNumericBitNorBitUns
+        | IeeeNumericBitNorSgnSgn
+        | IeeeNumericBitNorSgnBit
+        | IeeeNumericBitNorBitSgn => (Logic::Bit, LogicOperator::Nor),
+        BooleanXor
+        | BitXor
+        | TfArrayXor
+        | TfArrayElementXor
+        | TfElementArrayXor
+        | TfReductionXor
+        | IeeeNumericBitXorUnsUns
+        | IeeeNumericBitXorUnsBit
+        | IeeeNumericBitXorBitUns
+        | IeeeNumericBitXorSgnSgn
+        | IeeeNumericBitXorSgnBit
+        | IeeeNumericBitXorBitSgn => (Logic::Bit, LogicOperator::Xor),
+        BooleanXnor
+        | BitXnor
+        | TfArrayXnor
+        | TfArrayElementXnor
+        | TfElementArrayXnor
+        | TfReductionXnor
+        | IeeeNumericBitXnorUnsUns
+        | IeeeNumericBitXnorUnsBit
+        | IeeeNumericBitXnorBitUns
+        | IeeeNumericBitXnorSgnSgn
+        | IeeeNumericBitXnorSgnBit
+        | IeeeNumericBitXnorBitSgn => (Logic::Bit, LogicOperator::Xnor),
+        BooleanNot | BitNot | TfArrayNot | IeeeNumericBitNotUns | IeeeNumericBitNotSgn => {
+            (Logic::Bit, LogicOperator::Not)
+        },
+        Ieee1164ScalarAnd
+        | Ieee1164VectorAnd
+        | Ieee1164AndSuv
+        | Ieee1164AndLogSuv
+        | Ieee1164AndSuvLog
+        | IeeeNumericStdAndUns
+        | IeeeNumericStdAndSgn
+        | IeeeNumericStdAndUnsUns
+        | IeeeNumericStdAndSgnSgn
+        | IeeeNumericStdAndUnsLog
+        | IeeeNumericStdAndLogUns
+        | IeeeNumericStdAndSgnLog
+        | IeeeNumericStdAndLogSgn
+        | IeeeStdLogicMiscAndReduceSlv
+        | IeeeStdLogicMiscAndReduceSuv => (Logic::StdUlogic, LogicOperator::And),
+        Ieee1164ScalarOr
+        | Ieee1164VectorOr
+        | Ieee1164OrSuv
+        | Ieee1164OrLogSuv
+        | Ieee1164OrSuvLog
+        | IeeeNumericStdOrUns
+        | IeeeNumericStdOrSgn
+        | IeeeNumericStdOrUnsUns
+        | IeeeNumericStdOrSgnSgn
+        | IeeeNumericStdOrUnsLog
+        | IeeeNumericStdOrLogUns
+        | IeeeNumericStdOrSgnLog
+        | IeeeNumericStdOrLogSgn
+        | IeeeStdLogicMiscOrReduceSlv
+        | IeeeStdLogicMiscOrReduceSuv => (Logic::StdUlogic, LogicOperator::Or),
+        Ieee1164ScalarNand
+        | Ieee1164VectorNand
+        | Ieee1164NandSuv
+        | Ieee1164NandLogSuv
+        | Ieee1164NandSuvLog
+        | IeeeNumericStdNandUns
+        | IeeeNumericStdNandSgn
+        | IeeeNumericStdNandUnsUns
+        | IeeeNumericStdNandSgnSgn
+        | IeeeNumericStdNandUnsLog
+        | IeeeNumericStdNandLogUns
+        | IeeeNumericStdNandSgnLog
+        | IeeeNumericStdNandLogSgn
+        | IeeeStdLogicMiscNandReduceSlv
+        | IeeeStdLogicMiscNandReduceSuv => (Logic::StdUlogic, LogicOperator::Nand),
+        Ieee1164ScalarNor
+        | Ieee1164VectorNor
+        | Ieee1164NorSuv
+        | Ieee1164NorLogSuv
+        | Ieee1164NorSuvLog
+        | IeeeNumericStdNorUns
+        | IeeeNumericStdNorSgn
+        | IeeeNumericStdNorUnsUns
+        | IeeeNumericStdNorSgnSgn
+        | IeeeNumericStdNorUnsLog
+        | IeeeNumericStdNorLogUns
+        | IeeeNumericStdNorSgnLog
+        | IeeeNumericStdNorLogSgn
+        | IeeeStdLogicMiscNorReduceSlv
+        | IeeeStdLogicMiscNorReduceSuv => (Logic::StdUlogic, LogicOperator::Nor),
+        Ieee1164ScalarXor
+        | Ieee1164VectorXor
+        | Ieee1164XorSuv
+        | Ieee1164XorLogSuv
+        | Ieee1164XorSuvLog
+        | IeeeNumericStdXorUns
+        | IeeeNumericStdXorSgn
+        | IeeeNumericStdXorUnsUns
+        | IeeeNumericStdXorSgnSgn
+        | IeeeNumericStdXorUnsLog
+        | IeeeNumericStdXorLogUns
+        | IeeeNumericStdXorSgnLog
+        | IeeeNumericStdXorLogSgn
+        | IeeeStdLogicMiscXorReduceSlv
+        | IeeeStdLogicMiscXorReduceSuv => (Logic::StdUlogic, LogicOperator::Xor),
+        Ieee1164ScalarXnor
+        | Ieee1164VectorXnor
+        | Ieee1164XnorSuv
+        | Ieee1164XnorLogSuv
+        | Ieee1164XnorSuvLog
+        | IeeeNumericStdXnorUns
+        | IeeeNumericStdXnorSgn
+        | IeeeNumericStdXnorUnsUns
+        | IeeeNumericStdXnorSgnSgn
+        | IeeeNumericStdXnorUnsLog
+        | IeeeNumericStdXnorLogUns
+        | IeeeNumericStdXnorSgnLog
+        | IeeeNumericStdXnorLogSgn
+        | IeeeStdLogicMiscXnorReduceSlv
+        | IeeeStdLogicMiscXnorReduceSuv => (Logic::StdUlogic, LogicOperator::Xnor),
+        Ieee1164ScalarNot | Ieee1164VectorNot | IeeeNumericStdNotUns | IeeeNumericStdNotSgn => {
+            (Logic::StdUlogic, LogicOperator::Not)
+        },
+        _ => return None,
+    })
+}
+
+/// Matching operators and condition operator of `std_logic_1164`, and `std_match` of
+/// `numeric_std` (LRM §9.2.3).
+fn std_ulogic(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use ImplicitDefinition::*;
+    use Value::*;
+
+    let std = Logic::StdUlogic;
+    match (definition, operands) {
+        (StdUlogicMatchEquality, [Enum(left), Enum(right)]) => {
+            match_equality(*left, *right).map(Enum)
+        },
+        (StdUlogicMatchInequality, [Enum(left), Enum(right)]) => {
+            std.not(match_equality(*left, *right)?).map(Enum)
+        },
+        (StdUlogicMatchLess, [Enum(left), Enum(right)]) => match_less(*left, *right).map(Enum),
+        (StdUlogicMatchLessEqual, [Enum(left), Enum(right)]) => {
+            let less = match_less(*left, *right)?;
+            std.or(less, match_equality(*left, *right)?).map(Enum)
+        },
+        (StdUlogicMatchGreater, [Enum(left), Enum(right)]) => {
+            let less = match_less(*left, *right)?;
+            std.not(std.or(less, match_equality(*left, *right)?)?)
+                .map(Enum)
+        },
+        (StdUlogicMatchGreaterEqual, [Enum(left), Enum(right)]) => {
+            std.not(match_less(*left, *right)?).map(Enum)
+        },
+        (
+            StdUlogicArrayMatchEquality | StdUlogicArrayMatchInequality,
+            [Array(left), Array(right)],
+        ) => {
+            if left.len() != right.len() {
+                return Err(PredefinedError::InvalidOperands);
+            }
+            let mut result = STD_1;
+            for (left_element, right_element) in positions(left)?.into_iter().zip(positions(right)?)
+            {
+                result = std.and(result, match_equality(left_element, right_element)?)?;
+            }
+            if matches!(definition, StdUlogicArrayMatchInequality) {
+                result = std.not(result)?;
+            }
+            Ok(Enum(result))
+        },
+        (Ieee1164ConditionOperator, [Enum(value)]) => {
+            Ok(Value::boolean(std.to_bool(*value)? == Some(true)))
+        },
+
+        (IeeeNumericStdMatchLog, [Enum(left), Enum(right)]) => {
+            std_match(*left, *right).map(Value::boolean)
+        },
+        (
+            IeeeNumericStdMatchUns
+            | IeeeNumericStdMatchSgn
+            | IeeeNumericStdMatchSlv
+            | IeeeNumericStdMatchSuv,
+            [Array(left), Array(right)],
+        ) => {
+            if left.is_empty() || left.len() != right.len() {
+                return Ok(Value::boolean(false));
+            }
+            let mut matches = true;
+            for (left_element, right_element) in positions(left)?.into_iter().zip(positions(right)?)
+            {
+                matches &= std_match(left_element, right_element)?;
+            }
+            Ok(Value::boolean(matches))
+        },
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Conversions and predicates of `std_logic_1164` and `numeric_std`.
+fn conversion(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use ImplicitDefinition::*;
+    use Value::*;
+
+    let std = Logic::StdUlogic;
+    match (definition, operands) {
+        (Ieee1164ToBit | Ieee1164ToBitvector, [value, xmap @ ..]) => {
+            let xmap = optional(xmap, 0)?;
+            map_elements(value, |element| {
+                Ok(std.to_bool(element)?.map_or(xmap, u32::from))
+            })
+        },
+        (
+            Ieee1164ToStdulogic
+            | Ieee1164ToStdlogicvectorBv
+            | Ieee1164ToStdulogicvectorBv
+            | Ieee1164ToX01BitLog
+            | Ieee1164ToX01BvSlv
+            | Ieee1164ToX01BvSuv
+            | Ieee1164ToX01zBitLog
+            | Ieee1164ToX01zBvSlv
+            | Ieee1164ToX01zBvSuv
+            | Ieee1164ToUx01BitLog
+            | Ieee1164ToUx01BvSlv
+            | Ieee1164ToUx01BvSuv,
+            [value],
+        ) => map_elements(value, |element| Ok(std.position(Logic::Bit.bit(element)?))),
+        (Ieee1164ToStdlogicvectorSuv | Ieee1164ToStdulogicvectorSlv, [value @ Array(_)]) => {
+            Ok(value.clone())
+        },
+        (
+            Ieee1164ToX01Log
+            | Ieee1164ToX01Slv
+            | Ieee1164ToX01Suv
+            | IeeeNumericStdToX01Uns
+            | IeeeNumericStdToX01Sgn,
+            [value],
+        ) => map_elements(value, to_x01),
+        (
+            Ieee1164ToX01zLog
+            | Ieee1164ToX01zSlv
+            | Ieee1164ToX01zSuv
+            | IeeeNumericStdToX01zUns
+            | IeeeNumericStdToX01zSgn,
+            [value],
+        ) => map_elements(value, |element| {
+            if element == STD_Z {
+                Ok(STD_Z)
+            } else {
+                to_x01(element)
+            }
+        }),
+        (
+            Ieee1164ToUx01Log
+            | Ieee1164ToUx01Slv
+            | Ieee1164ToUx01Suv
+            | IeeeNumericStdToUx01Uns
+            | IeeeNumericStdToUx01Sgn,
+            [value],
+        ) => map_elements(value, to_ux01),
+        (Ieee1164IsXLog, [Enum(value)]) => Ok(Value::boolean(std.to_bool(*value)?.is_none())),
+        (Ieee1164IsXSlv | IeeeNumericStdIsXUns | IeeeNumericStdIsXSgn, [Array(elements)]) => {
+            let mut is_x = false;
+            for element in positions(elements)? {
+                is_x |= std.to_bool(element)?.is_none();
+            }
+            Ok(Value::boolean(is_x))
+        },
+        // A metavalue maps a whole vector to `xmap`.
+        (Ieee1164To01LogLog, [Enum(value), xmap @ ..]) => {
+            let xmap = optional(xmap, STD_0)?;
+            Ok(Enum(
+                std.to_bool(*value)?.map_or(xmap, |bit| std.position(bit)),
+            ))
+        },
+        (
+            Ieee1164To01SlvLog | IeeeNumericStdTo01Uns | IeeeNumericStdTo01Sgn,
+            [Array(elements), xmap @ ..],
+        ) => {
+            let xmap = optional(xmap, STD_0)?;
+            match std.to_bits(&positions(elements)?)? {
+                Some(bits) => Ok(std.vector(&bits)),
+                None => Ok(Array(vec![Enum(xmap); elements.len()])),
+            }
+        },
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Arithmetic, relational, matching, shift and conversion functions of `numeric_std`,
+/// `numeric_std_unsigned` and `numeric_bit`.
+fn numeric(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    let Some((operator, numeric)) = numeric_operation(definition) else {
+        return Err(PredefinedError::Unsupported);
+    };
+    match operator {
+        NumericOperator::Arithmetic(operator) => numeric.arithmetic(operator, operands),
+        NumericOperator::Relational(relation) => numeric.relational(relation, operands),
+        NumericOperator::Matching(relation) => numeric.matching(relation, operands),
+        NumericOperator::Negation | NumericOperator::Absolute => numeric.unary(operator, operands),
+        NumericOperator::ShiftLeft
+        | NumericOperator::ShiftRight
+        | NumericOperator::RotateLeft
+        | NumericOperator::RotateRight
+        | NumericOperator::Shift(_) => numeric.shift(operator, operands),
+        NumericOperator::Resize => numeric.resize(operands),
+        NumericOperator::ToInteger => numeric.to_integer(operands),
+        NumericOperator::FromInteger => numeric.to_vector(operands),
+    }
+}
+
+/// Returns the operator and operand types of a numeric operation.
+#[expect(
+    clippy::too_many_lines,
+    reason = "table of the operations of the numeric packages"
+)]
+fn numeric_operation(definition: ImplicitDefinition) -> Option<(NumericOperator, Numeric)> {
+    use ImplicitDefinition::*;
+    use NumericOperator::*;
+
+    Some(match definition {
+        IeeeNumericBitToIntSgnInt => (ToInteger, BIT_SIGNED),
+        IeeeNumericBitToIntUnsNat => (ToInteger, BIT_UNSIGNED),
+        IeeeNumericBitToSgnIntNatSgn | IeeeNumericBitToSgnIntSgnSgn => (FromInteger, BIT_SIGNED),
+        IeeeNumericBitToUnsNatNatUns | IeeeNumericBitToUnsNatUnsUns => (FromInteger, BIT_UNSIGNED),
+        IeeeNumericStdAbsSgn => (Absolute, SIGNED),
+        IeeeNumericStdAddIntSgn
+        | IeeeNumericStdAddLogSgn
+        | IeeeNumericStdAddSgnInt
+        | IeeeNumericStdAddSgnLog
+        | IeeeNumericStdAddSgnSgn => (Arithmetic(ArithmeticOperator::Add), SIGNED),
+        IeeeNumericStdAddLogUns
+        | IeeeNumericStdAddNatUns
+        | IeeeNumericStdAddUnsLog
+        | IeeeNumericStdAddUnsNat
+        | IeeeNumericStdAddUnsUns
+        | IeeeNumericStdUnsignedAddNatSlv
+        | IeeeNumericStdUnsignedAddSlvNat
+        | IeeeNumericStdUnsignedAddSlvSlv => (Arithmetic(ArithmeticOperator::Add), UNSIGNED),
+        IeeeNumericStdDivIntSgn | IeeeNumericStdDivSgnInt | IeeeNumericStdDivSgnSgn => {
+            (Arithmetic(ArithmeticOperator::Div), SIGNED)
+        },
+        IeeeNumericStdDivNatUns | IeeeNumericStdDivUnsNat | IeeeNumericStdDivUnsUns => {
+            (Arithmetic(ArithmeticOperator::Div), UNSIGNED)
+        },
+        IeeeNumericStdEqIntSgn | IeeeNumericStdEqSgnInt | IeeeNumericStdEqSgnSgn => {
+            (Relational(Relation::Equality), SIGNED)
+        },
+        IeeeNumericStdEqNatUns | IeeeNumericStdEqUnsNat | IeeeNumericStdEqUnsUns => {
+            (Relational(Relation::Equality), UNSIGNED)
+        },
+        IeeeNumericStdGeIntSgn | IeeeNumericStdGeSgnInt | IeeeNumericStdGeSgnSgn => {
+            (Relational(Relation::GreaterEqual), SIGNED)
+        },
+        IeeeNumericStdGeNatUns | IeeeNumericStdGeUnsNat | IeeeNumericStdGeUnsUns => {
+            (Relational(Relation::GreaterEqual), UNSIGNED)
+        },
+        IeeeNumericStdGtIntSgn | IeeeNumericStdGtSgnInt | IeeeNumericStdGtSgnSgn => {
+            (Relational(Relation::Greater), SIGNED)
+        },
+        IeeeNumericStdGtNatUns | IeeeNumericStdGtUnsNat | IeeeNumericStdGtUnsUns => {
+            (Relational(Relation::Greater), UNSIGNED)
+        },
+        IeeeNumericStdLeIntSgn | IeeeNumericStdLeSgnInt | IeeeNumericStdLeSgnSgn => {
+            (Relational(Relation::LessEqual), SIGNED)
+        },
+        IeeeNumericStdLeNatUns | IeeeNumericStdLeUnsNat | IeeeNumericStdLeUnsUns => {
+            (Relational(Relation::LessEqual), UNSIGNED)
+        },
+        IeeeNumericStdLtIntSgn | IeeeNumericStdLtSgnInt | IeeeNumericStdLtSgnSgn => {
+            (Relational(Relation::Less), SIGNED)
+        },
+        IeeeNumericStdLtNatUns | IeeeNumericStdLtUnsNat | IeeeNumericStdLtUnsUns => {
+            (Relational(Relation::Less), UNSIGNED)
+        },
+        IeeeNumericStdMatchEqIntSgn | IeeeNumericStdMatchEqSgnInt | IeeeNumericStdMatchEqSgnSgn => {
+            (Matching(Relation::Equality), SIGNED)
+        },
+        IeeeNumericStdMatchEqNatUns | IeeeNumericStdMatchEqUnsNat | IeeeNumericStdMatchEqUnsUns => {
+            (Matching(Relation::Equality), UNSIGNED)
+        },
+        IeeeNumericStdMatchGeIntSgn | IeeeNumericStdMatchGeSgnInt | IeeeNumericStdMatchGeSgnSgn => {
+            (Matching(Relation::GreaterEqual), SIGNED)
+        },
+        IeeeNumericStdMatchGeNatUns | IeeeNumericStdMatchGeUnsNat | IeeeNumericStdMatchGeUnsUns => {
+            (Matching(Relation::GreaterEqual), UNSIGNED)
+        },
+        IeeeNumericStdMatchGtIntSgn | IeeeNumericStdMatchGtSgnInt | IeeeNumericStdMatchGtSgnSgn => {
+            (Matching(Relation::Greater), SIGNED)
+        },
+        IeeeNumericStdMatchGtNatUns | IeeeNumericStdMatchGtUnsNat | IeeeNumericStdMatchGtUnsUns => {
+            (Matching(Relation::Greater), UNSIGNED)
+        },
+        IeeeNumericStdMatchLeIntSgn | IeeeNumericStdMatchLeSgnInt | IeeeNumericStdMatchLeSgnSgn => {
+            (Matching(Relation::LessEqual), SIGNED)
+        },
+        IeeeNumericStdMatchLeNatUns | IeeeNumericStdMatchLeUnsNat | IeeeNumericStdMatchLeUnsUns => {
+            (Matching(Relation::LessEqual), UNSIGNED)
+        },
+        IeeeNumericStdMatchLtIntSgn | IeeeNumericStdMatchLtSgnInt | IeeeNumericStdMatchLtSgnSgn => {
+            (Matching(Relation::Less), SIGNED)
+        },
+        IeeeNumericStdMatchLtNatUns | IeeeNumericStdMatchLtUnsNat | IeeeNumericStdMatchLtUnsUns => {
+            (Matching(Relation::Less), UNSIGNED)
+        },
+        IeeeNumericStdMatchNeIntSgn | IeeeNumericStdMatchNeSgnInt | IeeeNumericStdMatchNeSgnSgn => {
+            (Matching(Relation::Inequality), SIGNED)
+        },
+        IeeeNumericStdMatchNeNatUns | IeeeNumericStdMatchNeUnsNat | IeeeNumericStdMatchNeUnsUns => {
+            (Matching(Relation::Inequality), UNSIGNED)
+        },
+        IeeeNumericStdMaxIntSgn | IeeeNumericStdMaxSgnInt | IeeeNumericStdMaxSgnSgn => {
+            (Arithmetic(ArithmeticOperator::Maximum), SIGNED)
+        },
+        IeeeNumericStdMaxNatUns
+        | IeeeNumericStdMaxUnsNat
+        | IeeeNumericStdMaxUnsUns
+        | IeeeNumericStdUnsignedMaximumSlvSlv => {
+            (Arithmetic(ArithmeticOperator::Maximum), UNSIGNED)
+        },
+        IeeeNumericStdMinIntSgn | IeeeNumericStdMinSgnInt | IeeeNumericStdMinSgnSgn => {
+            (Arithmetic(ArithmeticOperator::Minimum), SIGNED)
+        },
+        IeeeNumericStdMinNatUns
+        | IeeeNumericStdMinUnsNat
+        | IeeeNumericStdMinUnsUns
+        | IeeeNumericStdUnsignedMinimumSlvSlv => {
+            (Arithmetic(ArithmeticOperator::Minimum), UNSIGNED)
+        },
+        IeeeNumericStdModIntSgn | IeeeNumericStdModSgnInt | IeeeNumericStdModSgnSgn => {
+            (Arithmetic(ArithmeticOperator::Mod), SIGNED)
+        },
+        IeeeNumericStdModNatUns | IeeeNumericStdModUnsNat | IeeeNumericStdModUnsUns => {
+            (Arithmetic(ArithmeticOperator::Mod), UNSIGNED)
+        },
+        IeeeNumericStdMulIntSgn | IeeeNumericStdMulSgnInt | IeeeNumericStdMulSgnSgn => {
+            (Arithmetic(ArithmeticOperator::Mul), SIGNED)
+        },
+        IeeeNumericStdMulNatUns | IeeeNumericStdMulUnsNat | IeeeNumericStdMulUnsUns => {
+            (Arithmetic(ArithmeticOperator::Mul), UNSIGNED)
+        },
+        IeeeNumericStdNeIntSgn | IeeeNumericStdNeSgnInt | IeeeNumericStdNeSgnSgn => {
+            (Relational(Relation::Inequality), SIGNED)
+        },
+        IeeeNumericStdNeNatUns | IeeeNumericStdNeUnsNat | IeeeNumericStdNeUnsUns => {
+            (Relational(Relation::Inequality), UNSIGNED)
+        },
+        IeeeNumericStdNegSgn => (Negation, SIGNED),
+        IeeeNumericStdNegUns => (Negation, UNSIGNED),
+        IeeeNumericStdRemIntSgn | IeeeNumericStdRemSgnInt | IeeeNumericStdRemSgnSgn => {
+            (Arithmetic(ArithmeticOperator::Rem), SIGNED)
+        },
+        IeeeNumericStdRemNatUns | IeeeNumericStdRemUnsNat | IeeeNumericStdRemUnsUns => {
+            (Arithmetic(ArithmeticOperator::Rem), UNSIGNED)
+        },
+        IeeeNumericStdResizeSgnNat | IeeeNumericStdResizeSgnSgn => (Resize, SIGNED),
+        IeeeNumericStdResizeUnsNat
+        | IeeeNumericStdResizeUnsUns
+        | IeeeNumericStdUnsignedResizeSlvNat
+        | IeeeNumericStdUnsignedResizeSlvSlv => (Resize, UNSIGNED),
+        IeeeNumericStdRolSgnInt => (Shift(ShiftOperator::Rol), SIGNED),
+        IeeeNumericStdRolUnsInt => (Shift(ShiftOperator::Rol), UNSIGNED),
+        IeeeNumericStdRorSgnInt => (Shift(ShiftOperator::Ror), SIGNED),
+        IeeeNumericStdRorUnsInt => (Shift(ShiftOperator::Ror), UNSIGNED),
+        IeeeNumericStdRotLeftSgnNat => (RotateLeft, SIGNED),
+        IeeeNumericStdRotLeftUnsNat | IeeeNumericStdUnsignedRotateLeft => (RotateLeft, UNSIGNED),
+        IeeeNumericStdRotRightSgnNat => (RotateRight, SIGNED),
+        IeeeNumericStdRotRightUnsNat | IeeeNumericStdUnsignedRotateRight => (RotateRight, UNSIGNED),
+        IeeeNumericStdShfLeftSgnNat => (ShiftLeft, SIGNED),
+        IeeeNumericStdShfLeftUnsNat | IeeeNumericStdUnsignedShiftLeft => (ShiftLeft, UNSIGNED),
+        IeeeNumericStdShfRightSgnNat => (ShiftRight, SIGNED),
+        IeeeNumericStdShfRightUnsNat | IeeeNumericStdUnsignedShiftRight => (ShiftRight, UNSIGNED),
+        IeeeNumericStdSlaSgnInt => (Shift(ShiftOperator::Sla), SIGNED),
+        IeeeNumericStdSlaUnsInt => (Shift(ShiftOperator::Sla), UNSIGNED),
+        IeeeNumericStdSllSgnInt => (Shift(ShiftOperator::Sll), SIGNED),
+        IeeeNumericStdSllUnsInt => (Shift(ShiftOperator::Sll), UNSIGNED),
+        IeeeNumericStdSraSgnInt => (Shift(ShiftOperator::Sra), SIGNED),
+        IeeeNumericStdSraUnsInt => (Shift(ShiftOperator::Sra), UNSIGNED),
+        IeeeNumericStdSrlSgnInt => (Shift(ShiftOperator::Srl), SIGNED),
+        IeeeNumericStdSrlUnsInt => (Shift(ShiftOperator::Srl), UNSIGNED),
+        IeeeNumericStdSubIntSgn
+        | IeeeNumericStdSubLogSgn
+        | IeeeNumericStdSubSgnInt
+        | IeeeNumericStdSubSgnLog
+        | IeeeNumericStdSubSgnSgn => (Arithmetic(ArithmeticOperator::Sub), SIGNED),
+        IeeeNumericStdSubLogUns
+        | IeeeNumericStdSubNatUns
+        | IeeeNumericStdSubUnsLog
+        | IeeeNumericStdSubUnsNat
+        | IeeeNumericStdSubUnsUns
+        | IeeeNumericStdUnsignedSubNatSlv
+        | IeeeNumericStdUnsignedSubSlvNat
+        | IeeeNumericStdUnsignedSubSlvSlv => (Arithmetic(ArithmeticOperator::Sub), UNSIGNED),
+        IeeeNumericStdToIntSgnInt => (ToInteger, SIGNED),
+        IeeeNumericStdToIntUnsNat | IeeeNumericStdUnsignedToIntegerSlvNat => (ToInteger, UNSIGNED),
+        IeeeNumericStdToSgnIntNatSgn | IeeeNumericStdToSgnIntSgnSgn => (FromInteger, SIGNED),
+        IeeeNumericStdToUnsNatNatUns
+        | IeeeNumericStdToUnsNatUnsUns
+        | IeeeNumericStdUnsignedToSlvNatNat
+        | IeeeNumericStdUnsignedToSlvNatSlv
+        | IeeeNumericStdUnsignedToSuvNatNat
+        | IeeeNumericStdUnsignedToSuvNatSuv => (FromInteger, UNSIGNED),
+        IeeeNumericBitAbsSgn => (Absolute, BIT_SIGNED),
+        IeeeNumericBitNegSgn => (Negation, BIT_SIGNED),
+        IeeeNumericBitAddUnsUns
+        | IeeeNumericBitAddUnsNat
+        | IeeeNumericBitAddNatUns
+        | IeeeNumericBitAddUnsBit
+        | IeeeNumericBitAddBitUns => (Arithmetic(ArithmeticOperator::Add), BIT_UNSIGNED),
+        IeeeNumericBitAddSgnSgn
+        | IeeeNumericBitAddSgnInt
+        | IeeeNumericBitAddIntSgn
+        | IeeeNumericBitAddSgnBit
+        | IeeeNumericBitAddBitSgn => (Arithmetic(ArithmeticOperator::Add), BIT_SIGNED),
+        IeeeNumericBitSubUnsUns
+        | IeeeNumericBitSubUnsNat
+        | IeeeNumericBitSubNatUns
+        | IeeeNumericBitSubUnsBit
+        | IeeeNumericBitSubBitUns => (Arithmetic(ArithmeticOperator::Sub), BIT_UNSIGNED),
+        IeeeNumericBitSubSgnSgn
+        | IeeeNumericBitSubSgnInt
+        | IeeeNumericBitSubIntSgn
+        | IeeeNumericBitSubSgnBit
+        | IeeeNumericBitSubBitSgn => (Arithmetic(ArithmeticOperator::Sub), BIT_SIGNED),
+        IeeeNumericBitMulUnsUns | IeeeNumericBitMulUnsNat | IeeeNumericBitMulNatUns => {
+            (Arithmetic(ArithmeticOperator::Mul), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMulSgnSgn | IeeeNumericBitMulSgnInt | IeeeNumericBitMulIntSgn => {
+            (Arithmetic(ArithmeticOperator::Mul), BIT_SIGNED)
+        },
+        IeeeNumericBitDivUnsUns | IeeeNumericBitDivUnsNat | IeeeNumericBitDivNatUns => {
+            (Arithmetic(ArithmeticOperator::Div), BIT_UNSIGNED)
+        },
+        IeeeNumericBitDivSgnSgn | IeeeNumericBitDivSgnInt | IeeeNumericBitDivIntSgn => {
+            (Arithmetic(ArithmeticOperator::Div), BIT_SIGNED)
+        },
+        IeeeNumericBitRemUnsUns | IeeeNumericBitRemUnsNat | IeeeNumericBitRemNatUns => {
+            (Arithmetic(ArithmeticOperator::Rem), BIT_UNSIGNED)
+        },
+        IeeeNumericBitRemSgnSgn | IeeeNumericBitRemSgnInt | IeeeNumericBitRemIntSgn => {
+            (Arithmetic(ArithmeticOperator::Rem), BIT_SIGNED)
+        },
+        IeeeNumericBitModUnsUns | IeeeNumericBitModUnsNat | IeeeNumericBitModNatUns => {
+            (Arithmetic(ArithmeticOperator::Mod), BIT_UNSIGNED)
+        },
+        IeeeNumericBitModSgnSgn | IeeeNumericBitModSgnInt | IeeeNumericBitModIntSgn => {
+            (Arithmetic(ArithmeticOperator::Mod), BIT_SIGNED)
+        },
+        IeeeNumericBitGtUnsUns | IeeeNumericBitGtUnsNat | IeeeNumericBitGtNatUns => {
+            (Relational(Relation::Greater), BIT_UNSIGNED)
+        },
+        IeeeNumericBitGtSgnSgn | IeeeNumericBitGtSgnInt | IeeeNumericBitGtIntSgn => {
+            (Relational(Relation::Greater), BIT_SIGNED)
+        },
+        IeeeNumericBitLtUnsUns | IeeeNumericBitLtUnsNat | IeeeNumericBitLtNatUns => {
+            (Relational(Relation::Less), BIT_UNSIGNED)
+        },
+        IeeeNumericBitLtSgnSgn | IeeeNumericBitLtSgnInt | IeeeNumericBitLtIntSgn => {
+            (Relational(Relation::Less), BIT_SIGNED)
+        },
+        IeeeNumericBitLeUnsUns | IeeeNumericBitLeUnsNat | IeeeNumericBitLeNatUns => {
+            (Relational(Relation::LessEqual), BIT_UNSIGNED)
+        },
+        IeeeNumericBitLeSgnSgn | IeeeNumericBitLeSgnInt | IeeeNumericBitLeIntSgn => {
+            (Relational(Relation::LessEqual), BIT_SIGNED)
+        },
+        IeeeNumericBitGeUnsUns | IeeeNumericBitGeUnsNat | IeeeNumericBitGeNatUns => {
+            (Relational(Relation::GreaterEqual), BIT_UNSIGNED)
+        },
+        IeeeNumericBitGeSgnSgn | IeeeNumericBitGeSgnInt | IeeeNumericBitGeIntSgn => {
+            (Relational(Relation::GreaterEqual), BIT_SIGNED)
+        },
+        IeeeNumericBitEqUnsUns | IeeeNumericBitEqUnsNat | IeeeNumericBitEqNatUns => {
+            (Relational(Relation::Equality), BIT_UNSIGNED)
+        },
+        IeeeNumericBitEqSgnSgn | IeeeNumericBitEqSgnInt | IeeeNumericBitEqIntSgn => {
+            (Relational(Relation::Equality), BIT_SIGNED)
+        },
+        IeeeNumericBitNeUnsUns | IeeeNumericBitNeUnsNat | IeeeNumericBitNeNatUns => {
+            (Relational(Relation::Inequality), BIT_UNSIGNED)
+        },
+        IeeeNumericBitNeSgnSgn | IeeeNumericBitNeSgnInt | IeeeNumericBitNeIntSgn => {
+            (Relational(Relation::Inequality), BIT_SIGNED)
+        },
+        IeeeNumericBitMatchGtUnsUns | IeeeNumericBitMatchGtUnsNat | IeeeNumericBitMatchGtNatUns => {
+            (Matching(Relation::Greater), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMatchGtSgnSgn | IeeeNumericBitMatchGtSgnInt | IeeeNumericBitMatchGtIntSgn => {
+            (Matching(Relation::Greater), BIT_SIGNED)
+        },
+        IeeeNumericBitMatchLtUnsUns | IeeeNumericBitMatchLtUnsNat | IeeeNumericBitMatchLtNatUns => {
+            (Matching(Relation::Less), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMatchLtSgnSgn | IeeeNumericBitMatchLtSgnInt | IeeeNumericBitMatchLtIntSgn => {
+            (Matching(Relation::Less), BIT_SIGNED)
+        },
+        IeeeNumericBitMatchLeUnsUns | IeeeNumericBitMatchLeUnsNat | IeeeNumericBitMatchLeNatUns => {
+            (Matching(Relation::LessEqual), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMatchLeSgnSgn | IeeeNumericBitMatchLeSgnInt | IeeeNumericBitMatchLeIntSgn => {
+            (Matching(Relation::LessEqual), BIT_SIGNED)
+        },
+        IeeeNumericBitMatchGeUnsUns | IeeeNumericBitMatchGeUnsNat | IeeeNumericBitMatchGeNatUns => {
+            (Matching(Relation::GreaterEqual), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMatchGeSgnSgn | IeeeNumericBitMatchGeSgnInt | IeeeNumericBitMatchGeIntSgn => {
+            (Matching(Relation::GreaterEqual), BIT_SIGNED)
+        },
+        IeeeNumericBitMatchEqUnsUns | IeeeNumericBitMatchEqUnsNat | IeeeNumericBitMatchEqNatUns => {
+            (Matching(Relation::Equality), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMatchEqSgnSgn | IeeeNumericBitMatchEqSgnInt | IeeeNumericBitMatchEqIntSgn => {
+            (Matching(Relation::Equality), BIT_SIGNED)
+        },
+        IeeeNumericBitMatchNeUnsUns | IeeeNumericBitMatchNeUnsNat | IeeeNumericBitMatchNeNatUns => {
+            (Matching(Relation::Inequality), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMatchNeSgnSgn | IeeeNumericBitMatchNeSgnInt | IeeeNumericBitMatchNeIntSgn => {
+            (Matching(Relation::Inequality), BIT_SIGNED)
+        },
+        IeeeNumericBitShfLeftUnsNat => (ShiftLeft, BIT_UNSIGNED),
+        IeeeNumericBitShfRightUnsNat => (ShiftRight, BIT_UNSIGNED),
+        IeeeNumericBitShfLeftSgnNat => (ShiftLeft, BIT_SIGNED),
+        IeeeNumericBitShfRightSgnNat => (ShiftRight, BIT_SIGNED),
+        IeeeNumericBitRotLeftUnsNat => (RotateLeft, BIT_UNSIGNED),
+        IeeeNumericBitRotRightUnsNat => (RotateRight, BIT_UNSIGNED),
+        IeeeNumericBitRotLeftSgnNat => (RotateLeft, BIT_SIGNED),
+        IeeeNumericBitRotRightSgnNat => (RotateRight, BIT_SIGNED),
+        IeeeNumericBitResizeUnsNat | IeeeNumericBitResizeUnsUns => (Resize, BIT_UNSIGNED),
+        IeeeNumericBitResizeSgnNat | IeeeNumericBitResizeSgnSgn => (Resize, BIT_SIGNED),
+        IeeeNumericBitSllUnsInt => (Shift(ShiftOperator::Sll), BIT_UNSIGNED),
+        IeeeNumericBitSllSgnInt => (Shift(ShiftOperator::Sll), BIT_SIGNED),
+        IeeeNumericBitSrlUnsInt => (Shift(ShiftOperator::Srl), BIT_UNSIGNED),
+        IeeeNumericBitSrlSgnInt => (Shift(ShiftOperator::Srl), BIT_SIGNED),
+        IeeeNumericBitSlaUnsInt => (Shift(ShiftOperator::Sla), BIT_UNSIGNED),
+        IeeeNumericBitSlaSgnInt => (Shift(ShiftOperator::Sla), BIT_SIGNED),
+        IeeeNumericBitSraUnsInt => (Shift(ShiftOperator::Sra), BIT_UNSIGNED),
+        IeeeNumericBitSraSgnInt => (Shift(ShiftOperator::Sra), BIT_SIGNED),
+        IeeeNumericBitRolUnsInt => (Shift(ShiftOperator::Rol), BIT_UNSIGNED),
+        IeeeNumericBitRolSgnInt => (Shift(ShiftOperator::Rol), BIT_SIGNED),
+        IeeeNumericBitRorUnsInt => (Shift(ShiftOperator::Ror), BIT_UNSIGNED),
+        IeeeNumericBitRorSgnInt => (Shift(ShiftOperator::Ror), BIT_SIGNED),
+        IeeeNumericBitMinUnsUns | IeeeNumericBitMinUnsNat | IeeeNumericBitMinNatUns => {
+            (Arithmetic(ArithmeticOperator::Minimum), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMinSgnSgn | IeeeNumericBitMinSgnInt | IeeeNumericBitMinIntSgn => {
+            (Arithmetic(ArithmeticOperator::Minimum), BIT_SIGNED)
+        },
+        IeeeNumericBitMaxUnsUns | IeeeNumericBitMaxUnsNat | IeeeNumericBitMaxNatUns => {
+            (Arithmetic(ArithmeticOperator::Maximum), BIT_UNSIGNED)
+        },
+        IeeeNumericBitMaxSgnSgn | IeeeNumericBitMaxSgnInt | IeeeNumericBitMaxIntSgn => {
+            (Arithmetic(ArithmeticOperator::Maximum), BIT_SIGNED)
+        },
+        _ => return None,
+    })
+}
+
+/// Functions of `math_real`.
+fn math_real(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use ImplicitDefinition::*;
+    use Value::*;
+
+    match (definition, operands) {
+        (IeeeMathRealCeil, [Real(value)]) => real(value.ceil()),
+        (IeeeMathRealFloor, [Real(value)]) => real(value.floor()),
+        // Halfway values are rounded away from zero.
+        (IeeeMathRealRound, [Real(value)]) => real(value.round()),
+        (IeeeMathRealTrunc, [Real(value)]) => real(value.trunc()),
+        (IeeeMathRealSign, [Real(value)]) => real(match value.partial_cmp(&0.0) {
+            Some(Ordering::Greater) => 1.0,
+            Some(Ordering::Less) => -1.0,
+            _ => 0.0,
+        }),
+        (IeeeMathRealRealmax, [Real(left), Real(right)]) => real(left.max(*right)),
+        (IeeeMathRealRealmin, [Real(left), Real(right)]) => real(left.min(*right)),
+        (IeeeMathRealMod, [Real(left), Real(right)]) => {
+            domain(*right != 0.0, left - right * (left / right).floor())
+        },
+        (IeeeMathRealSqrt, [Real(value)]) => domain(*value >= 0.0, value.sqrt()),
+        (IeeeMathRealCbrt, [Real(value)]) => real(value.cbrt()),
+        (IeeeMathRealPowIntReal, [Integer(base), Real(exponent)]) => {
+            power(integer_to_real(*base), *exponent)
+        },
+        (IeeeMathRealPowRealReal, [Real(base), Real(exponent)]) => power(*base, *exponent),
+        (IeeeMathRealExp, [Real(value)]) => real(value.exp()),
+        (IeeeMathRealLog, [Real(value)]) => domain(*value > 0.0, value.ln()),
+        (IeeeMathRealLog2, [Real(value)]) => domain(*value > 0.0, value.log2()),
+        (IeeeMathRealLog10, [Real(value)]) => domain(*value > 0.0, value.log10()),
+        (IeeeMathRealLogRealReal, [Real(value), Real(base)]) => {
+            let base_log = base.ln();
+            domain(
+                *value > 0.0 && *base > 0.0 && base_log != 0.0,
+                value.ln() / base_log,
+            )
+        },
+        (IeeeMathRealSin, [Real(value)]) => real(value.sin()),
+        (IeeeMathRealCos, [Real(value)]) => real(value.cos()),
+        (IeeeMathRealTan, [Real(value)]) => real(value.tan()),
+        (IeeeMathRealArcsin, [Real(value)]) => domain(value.abs() <= 1.0, value.asin()),
+        (IeeeMathRealArccos, [Real(value)]) => domain(value.abs() <= 1.0, value.acos()),
+        (IeeeMathRealArctan, [Real(value)]) => real(value.atan()),
+        (IeeeMathRealArctanRealReal, [Real(y), Real(x)]) => {
+            domain(*y != 0.0 || *x != 0.0, y.atan2(*x))
+        },
+        (IeeeMathRealSinh, [Real(value)]) => real(value.sinh()),
+        (IeeeMathRealCosh, [Real(value)]) => real(value.cosh()),
+        (IeeeMathRealTanh, [Real(value)]) => real(value.tanh()),
+        (IeeeMathRealArcsinh, [Real(value)]) => real(value.asinh()),
+        (IeeeMathRealArccosh, [Real(value)]) => domain(*value >= 1.0, value.acosh()),
+        (IeeeMathRealArctanh, [Real(value)]) => domain(value.abs() < 1.0, value.atanh()),
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// `to_string`, `to_hstring` and `to_ostring` of numbers and vectors (LRM §5.7).
+fn image(definition: ImplicitDefinition, operands: &[Value]) -> Outcome {
+    use ImplicitDefinition::*;
+    use Value::*;
+
+    match (definition, operands) {
+        (IntegerToString, [Integer(value)]) => Ok(Value::string(&value.to_string())),
+        (FloatingToString, [Real(value)]) => Ok(Value::string(&real_image(*value))),
+        (RealToStringDigits, [Real(value), Integer(digits)]) => {
+            let digits =
+                usize::try_from(*digits).map_err(|_ignore| PredefinedError::InvalidOperands)?;
+            Ok(Value::string(&if digits == 0 {
+                real_image(*value)
+            } else {
+                format!("{value:.digits$}")
+            }))
+        },
+        (BitVectorToHstring, [Array(elements)]) => radix_image(Logic::Bit, elements, 4, false),
+        (BitVectorToOstring, [Array(elements)]) => radix_image(Logic::Bit, elements, 3, false),
+        (Ieee1164ToHstring | IeeeNumericStdToHstringUns, [Array(elements)]) => {
+            radix_image(Logic::StdUlogic, elements, 4, false)
+        },
+        (Ieee1164ToOstring | IeeeNumericStdToOstringUns, [Array(elements)]) => {
+            radix_image(Logic::StdUlogic, elements, 3, false)
+        },
+        (IeeeNumericStdToHstringSgn, [Array(elements)]) => {
+            radix_image(Logic::StdUlogic, elements, 4, true)
+        },
+        (IeeeNumericStdToOstringSgn, [Array(elements)]) => {
+            radix_image(Logic::StdUlogic, elements, 3, true)
+        },
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Logic system of the elements of logical operations and numeric vectors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Logic {
+    /// `bit` or `boolean`, whose positions are 0 and 1.
+    Bit,
+    /// The nine values of `std_ulogic`.
+    StdUlogic,
+}
+
+/// Logical operator, see [`logical`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LogicOperator {
+    And,
+    Or,
+    Nand,
+    Nor,
+    Xor,
+    Xnor,
+    Not,
+}
+
+impl Logic {
+    /// Returns the value of a `bit` or `boolean`.
+    fn bit(self, value: u32) -> Result<bool, PredefinedError> {
+        self.to_bool(value)?.ok_or(PredefinedError::InvalidOperands)
+    }
+
+    /// Returns the value of an element as a bit, or `None` for a metavalue.
+    fn to_bool(self, value: u32) -> Result<Option<bool>, PredefinedError> {
+        match (self, value) {
+            (Self::Bit, 0) | (Self::StdUlogic, STD_0 | STD_L) => Ok(Some(false)),
+            (Self::Bit, 1) | (Self::StdUlogic, STD_1 | STD_H) => Ok(Some(true)),
+            (Self::StdUlogic, STD_U | STD_X | STD_Z | STD_W | STD_DONT_CARE) => Ok(None),
+            _ => Err(PredefinedError::InvalidOperands),
+        }
+    }
+
+    /// Returns the position of `'0'` or `'1'`.
+    fn position(self, value: bool) -> u32 {
+        match self {
+            Self::Bit => u32::from(value),
+            Self::StdUlogic => STD_0 + u32::from(value),
+        }
+    }
+
+    /// Returns the bits of a vector, or `None` if it contains a metavalue.
+    fn to_bits(self, elements: &[u32]) -> Result<Option<Vec<bool>>, PredefinedError> {
+        let mut bits = Vec::with_capacity(elements.len());
+        let mut known = true;
+        for &element in elements {
+            match self.to_bool(element)? {
+                Some(bit) => bits.push(bit),
+                None => known = false,
+            }
+        }
+        Ok(known.then_some(bits))
+    }
+
+    /// Returns the vector of the given bits.
+    fn vector(self, bits: &[bool]) -> Value {
+        Value::Array(
+            bits.iter()
+                .map(|&bit| Value::Enum(self.position(bit)))
+                .collect(),
+        )
+    }
+
+    fn not(self, value: u32) -> Result<u32, PredefinedError> {
+        match self {
+            Self::Bit => Ok(self.position(!self.bit(value)?)),
+            Self::StdUlogic => match to_ux01(value)? {
+                STD_0 => Ok(STD_1),
+                STD_1 => Ok(STD_0),
+                unknown => Ok(unknown),
+            },
+        }
+    }
+
+    fn and(self, left: u32, right: u32) -> Result<u32, PredefinedError> {
+        match self {
+            Self::Bit => Ok(self.position(self.bit(left)? && self.bit(right)?)),
+            Self::StdUlogic => Ok(match (to_ux01(left)?, to_ux01(right)?) {
+                (STD_0, _) | (_, STD_0) => STD_0,
+                (STD_U, _) | (_, STD_U) => STD_U,
+                (STD_X, _) | (_, STD_X) => STD_X,
+                _ => STD_1,
+            }),
+        }
+    }
+
+    fn or(self, left: u32, right: u32) -> Result<u32, PredefinedError> {
+        match self {
+            Self::Bit => Ok(self.position(self.bit(left)? || self.bit(right)?)),
+            Self::StdUlogic => Ok(match (to_ux01(left)?, to_ux01(right)?) {
+                (STD_1, _) | (_, STD_1) => STD_1,
+                (STD_U, _) | (_, STD_U) => STD_U,
+                (STD_X, _) | (_, STD_X) => STD_X,
+                _ => STD_0,
+            }),
+        }
+    }
+
+    fn xor(self, left: u32, right: u32) -> Result<u32, PredefinedError> {
+        match self {
+            Self::Bit => Ok(self.position(self.bit(left)? != self.bit(right)?)),
+            Self::StdUlogic => Ok(match (to_ux01(left)?, to_ux01(right)?) {
+                (STD_U, _) | (_, STD_U) => STD_U,
+                (STD_X, _) | (_, STD_X) => STD_X,
+                (left_bit, right_bit) => self.position(left_bit != right_bit),
+            }),
+        }
+    }
+
+    fn binary(
+        self,
+        operator: LogicOperator,
+        left: u32,
+        right: u32,
+    ) -> Result<u32, PredefinedError> {
+        match operator {
+            LogicOperator::And => self.and(left, right),
+            LogicOperator::Or => self.or(left, right),
+            LogicOperator::Xor => self.xor(left, right),
+            LogicOperator::Nand => self.not(self.and(left, right)?),
+            LogicOperator::Nor => self.not(self.or(left, right)?),
+            LogicOperator::Xnor => self.not(self.xor(left, right)?),
+            LogicOperator::Not => Err(PredefinedError::Unsupported),
+        }
+    }
+
+    /// Reduces a vector with a logical operator; `nand`, `nor` and `xnor` negate the
+    /// reduction with `and`, `or` and `xor`.
+    fn reduce(self, operator: LogicOperator, elements: &[u32]) -> Result<u32, PredefinedError> {
+        let (reduction, negated) = match operator {
+            LogicOperator::And => (LogicOperator::And, false),
+            LogicOperator::Or => (LogicOperator::Or, false),
+            LogicOperator::Xor => (LogicOperator::Xor, false),
+            LogicOperator::Nand => (LogicOperator::And, true),
+            LogicOperator::Nor => (LogicOperator::Or, true),
+            LogicOperator::Xnor => (LogicOperator::Xor, true),
+            LogicOperator::Not => return Err(PredefinedError::Unsupported),
+        };
+        let mut result = self.position(reduction == LogicOperator::And);
+        for &element in elements {
+            result = self.binary(reduction, result, element)?;
+        }
+        if negated {
+            self.not(result)
+        } else {
+            Ok(result)
+        }
+    }
+}
+
+/// Reduces a `std_ulogic` to `'U'`, `'X'`, `'0'` or `'1'` (`To_UX01`).
+fn to_ux01(value: u32) -> Result<u32, PredefinedError> {
+    match value {
+        STD_U => Ok(STD_U),
+        STD_0 | STD_L => Ok(STD_0),
+        STD_1 | STD_H => Ok(STD_1),
+        STD_X | STD_Z | STD_W | STD_DONT_CARE => Ok(STD_X),
+        _ => Err(PredefinedError::InvalidOperands),
+    }
+}
+
+/// Reduces a `std_ulogic` to `'X'`, `'0'` or `'1'` (`To_X01`).
+fn to_x01(value: u32) -> Result<u32, PredefinedError> {
+    to_ux01(value).map(|reduced| if reduced == STD_U { STD_X } else { reduced })
+}
+
+/// `?=` on `std_ulogic`: `'-'` matches any value, and `'U'` dominates `'X'`.
+fn match_equality(left: u32, right: u32) -> Result<u32, PredefinedError> {
+    if left == STD_DONT_CARE || right == STD_DONT_CARE {
+        return Ok(STD_1);
+    }
+    Ok(match (to_ux01(left)?, to_ux01(right)?) {
+        (STD_U, _) | (_, STD_U) => STD_U,
+        (STD_X, _) | (_, STD_X) => STD_X,
+        (left_bit, right_bit) => Logic::StdUlogic.position(left_bit == right_bit),
+    })
+}
+
+/// `?<` on `std_ulogic`, for which `'-'` operands are an error.
+fn match_less(left: u32, right: u32) -> Result<u32, PredefinedError> {
+    if left == STD_DONT_CARE || right == STD_DONT_CARE {
+        return Err(PredefinedError::InvalidOperands);
+    }
+    Ok(match (to_ux01(left)?, to_ux01(right)?) {
+        (STD_U, _) | (_, STD_U) => STD_U,
+        (STD_X, _) | (_, STD_X) => STD_X,
+        (left_bit, right_bit) => Logic::StdUlogic.position(left_bit < right_bit),
+    })
+}
+
+/// `std_match` on `std_ulogic`: `'-'` matches any value, and metavalues match nothing else.
+fn std_match(left: u32, right: u32) -> Result<bool, PredefinedError> {
+    let std = Logic::StdUlogic;
+    let (left_bit, right_bit) = (std.to_bool(left)?, std.to_bool(right)?);
+    Ok(left == STD_DONT_CARE
+        || right == STD_DONT_CARE
+        || left_bit.is_some() && left_bit == right_bit)
+}
+
+/// Applies a conversion to a scalar, or to each element of an array.
+fn map_elements(value: &Value, convert: impl Fn(u32) -> Result<u32, PredefinedError>) -> Outcome {
+    match value {
+        Value::Enum(element) => convert(*element).map(Value::Enum),
+        Value::Array(elements) => positions(elements)?
+            .into_iter()
+            .map(|element| convert(element).map(Value::Enum))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Returns the value of an omitted or given trailing enumeration parameter.
+fn optional(operands: &[Value], default: u32) -> Result<u32, PredefinedError> {
+    match operands {
+        [] => Ok(default),
+        [Value::Enum(value)] => Ok(*value),
+        _ => Err(PredefinedError::Unsupported),
+    }
+}
+
+/// Returns the positions of the elements of an array of enumeration values.
+fn positions(elements: &[Value]) -> Result<Vec<u32>, PredefinedError> {
+    elements
+        .iter()
+        .map(|element| match element {
+            Value::Enum(position) => Ok(*position),
+            _ => Err(PredefinedError::InvalidOperands),
+        })
+        .collect()
+}
+
+/// Shift operator (LRM §9.2.5).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ShiftOperator {
+    Sll,
+    Srl,
+    Sla,
+    Sra,
+    Rol,
+    Ror,
+}
+
+impl ShiftOperator {
+    /// Shifts or rotates `elements` by `count` positions, in the opposite direction for a
+    /// negative count. Logical shifts fill with `fill`; arithmetic shifts replicate the
+    /// element at the vacated end.
+    fn apply(self, elements: &[Value], count: i64, fill: &Value) -> Vec<Value> {
+        let (Some(leftmost), Some(rightmost)) = (elements.first(), elements.last()) else {
+            return Vec::new();
+        };
+        match self {
+            Self::Sll => shift(elements, count, true, fill),
+            Self::Srl => shift(elements, count, false, fill),
+            Self::Sla => shift(
+                elements,
+                count,
+                true,
+                if count >= 0 { rightmost } else { leftmost },
+            ),
+            Self::Sra => shift(
+                elements,
+                count,
+                false,
+                if count >= 0 { leftmost } else { rightmost },
+            ),
+            Self::Rol => rotate(elements, count, true),
+            Self::Ror => rotate(elements, count, false),
+        }
+    }
+}
+
+/// Shifts `elements` by `count` positions towards the left if `towards_left` and the
+/// right otherwise, or in the opposite direction for a negative count.
+fn shift(elements: &[Value], count: i64, towards_left: bool, fill: &Value) -> Vec<Value> {
+    let len = elements.len();
+    let amount = usize::try_from(count.unsigned_abs()).map_or(len, |amount| amount.min(len));
+    let fill = iter::repeat_n(fill, amount);
+    if towards_left == (count >= 0) {
+        elements[amount..].iter().chain(fill).cloned().collect()
+    } else {
+        fill.chain(&elements[..len - amount]).cloned().collect()
+    }
+}
+
+/// Rotates `elements` by `count` positions, like [`shift`].
+fn rotate(elements: &[Value], count: i64, towards_left: bool) -> Vec<Value> {
+    let mut rotated = elements.to_vec();
+    if let Ok(len) = i64::try_from(rotated.len())
+        && len > 0
+    {
+        let amount = usize::try_from(count.rem_euclid(len)).unwrap_or_default();
+        if towards_left {
+            rotated.rotate_left(amount);
+        } else {
+            rotated.rotate_right(amount);
+        }
+    }
+    rotated
+}
+
+/// Operation of the numeric packages, see [`numeric_operation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NumericOperator {
+    Arithmetic(ArithmeticOperator),
+    Relational(Relation),
+    /// Matching relational operator, e.g. `?<`.
+    Matching(Relation),
+    Negation,
+    Absolute,
+    ShiftLeft,
+    ShiftRight,
+    RotateLeft,
+    RotateRight,
+    Shift(ShiftOperator),
+    Resize,
+    ToInteger,
+    /// `to_unsigned`, `to_signed` and `to_slv`.
+    FromInteger,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ArithmeticOperator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Mod,
+    Minimum,
+    Maximum,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Relation {
+    Equality,
+    Inequality,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Relation {
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Equality => ordering.is_eq(),
+            Self::Inequality => ordering.is_ne(),
+            Self::Less => ordering.is_lt(),
+            Self::LessEqual => ordering.is_le(),
+            Self::Greater => ordering.is_gt(),
+            Self::GreaterEqual => ordering.is_ge(),
+        }
+    }
+}
+
+/// `unsigned` or `signed` vector type of a numeric package.
+#[derive(Clone, Copy, Debug)]
+struct Numeric {
+    signed: bool,
+    logic: Logic,
+}
+
+/// Vector types of `numeric_std` and `numeric_std_unsigned`.
+const UNSIGNED: Numeric = Numeric {
+    signed: false,
+    logic: Logic::StdUlogic,
+};
+const SIGNED: Numeric = Numeric {
+    signed: true,
+    logic: Logic::StdUlogic,
+};
+/// Vector types of `numeric_bit`.
+const BIT_UNSIGNED: Numeric = Numeric {
+    signed: false,
+    logic: Logic::Bit,
+};
+const BIT_SIGNED: Numeric = Numeric {
+    signed: true,
+    logic: Logic::Bit,
+};
+
+/// Bits of the operands of a numeric comparison.
+type Comparands = (Vec<bool>, Vec<bool>);
+
+/// Operand of a numeric operation.
+#[derive(Clone, Copy, Debug)]
+enum NumericOperand<'value> {
+    Vector(&'value [Value]),
+    /// `std_ulogic` or `bit` operand of an addition or subtraction.
+    Element(u32),
+    Integer(i64),
+}
+
+impl NumericOperand<'_> {
+    fn len(self) -> Option<usize> {
+        match self {
+            Self::Vector(elements) => Some(elements.len()),
+            Self::Element(_) | Self::Integer(_) => None,
+        }
+    }
+}
+
+impl Numeric {
+    /// Returns an operand, checking that integers are natural for `unsigned` operations.
+    fn operand(self, value: &Value) -> Result<NumericOperand<'_>, PredefinedError> {
+        match value {
+            Value::Array(elements) => Ok(NumericOperand::Vector(elements)),
+            Value::Enum(element) => Ok(NumericOperand::Element(*element)),
+            Value::Integer(value) if self.signed || *value >= 0 => {
+                Ok(NumericOperand::Integer(*value))
+            },
+            Value::Integer(_) => Err(PredefinedError::InvalidOperands),
+            _ => Err(PredefinedError::Unsupported),
+        }
+    }
+
+    /// Returns the elements of an operand; elements are zero-extended and integers are
+    /// converted to `width` elements, truncating them if needed.
+    fn elements(
+        self,
+        operand: NumericOperand<'_>,
+        width: usize,
+    ) -> Result<Vec<u32>, PredefinedError> {
+        match operand {
+            NumericOperand::Vector(elements) => positions(elements),
+            NumericOperand::Element(element) => Ok(iter::repeat_n(
+                self.logic.position(false),
+                width.saturating_sub(1),
+            )
+            .chain([element])
+            .collect()),
+            NumericOperand::Integer(value) => Ok(integer_bits(value, width)
+                .into_iter()
+                .map(|bit| self.logic.position(bit))
+                .collect()),
+        }
+    }
+
+    /// Returns the leftmost element, which fills vacated positions of signed right shifts.
+    fn sign_fill(self, elements: &[Value]) -> Value {
+        match elements.first() {
+            Some(leftmost) if self.signed => leftmost.clone(),
+            _ => Value::Enum(self.logic.position(false)),
+        }
+    }
+
+    /// Arithmetic operators and `minimum`/`maximum`.
+    ///
+    /// Integer operands of `+`, `-`, `*`, `minimum` and `maximum` are truncated to the
+    /// length of the vector operand, as done by `to_unsigned` and `to_signed`, whereas
+    /// division operators use their exact value.
+    fn arithmetic(self, operator: ArithmeticOperator, operands: &[Value]) -> Outcome {
+        use ArithmeticOperator::*;
+
+        let [left, right] = operands else {
+            return Err(PredefinedError::Unsupported);
+        };
+        let (left, right) = (self.operand(left)?, self.operand(right)?);
+        let Some(vector_len) = left.len().or(right.len()) else {
+            return Err(PredefinedError::Unsupported);
+        };
+        if left.len() == Some(0) || right.len() == Some(0) {
+            return Ok(Value::Array(Vec::new()));
+        }
+        let exact = matches!(operator, Div | Rem | Mod);
+        let conversion_width = if exact {
+            vector_len.max(INTEGER_WIDTH)
+        } else {
+            vector_len
+        };
+        let left_elements = self.elements(left, conversion_width)?;
+        let right_elements = self.elements(right, conversion_width)?;
+        let width = left_elements.len().max(right_elements.len());
+        let result_width = match operator {
+            Add | Sub | Minimum | Maximum => width,
+            Mul => left_elements.len() + right_elements.len(),
+            Div => vector_len,
+            Rem | Mod => right.len().unwrap_or(vector_len),
+        };
+        let (Some(left_bits), Some(right_bits)) = (
+            self.logic.to_bits(&left_elements)?,
+            self.logic.to_bits(&right_elements)?,
+        ) else {
+            return Ok(unknown(result_width));
+        };
+        let operation_width = if operator == Mul { result_width } else { width };
+        let left_bits = extend(&left_bits, operation_width, self.signed);
+        let right_bits = extend(&right_bits, operation_width, self.signed);
+        let result = match operator {
+            Add => add(&left_bits, &right_bits, false),
+            Sub => subtract(&left_bits, &right_bits),
+            Mul => multiply(&left_bits, &right_bits),
+            Div | Rem | Mod => {
+                let (quotient, remainder) = divide_bits(&left_bits, &right_bits, self.signed)
+                    .ok_or(PredefinedError::DivisionByZero)?;
+                match operator {
+                    Div => quotient,
+                    // The result of `mod` has the sign of the right operand.
+                    Mod if self.signed
+                        && remainder.contains(&true)
+                        && remainder.first() != right_bits.first() =>
+                    {
+                        add(
+                            &remainder,
+                            &extend(&right_bits, remainder.len(), true),
+                            false,
+                        )
+                    },
+                    _ => remainder,
+                }
+            },
+            Minimum | Maximum => {
+                let ordering = compare_bits(&left_bits, &right_bits, self.signed);
+                if (operator == Minimum) == ordering.is_gt() {
+                    right_bits
+                } else {
+                    left_bits
+                }
+            },
+        };
+        Ok(self
+            .logic
+            .vector(&extend(&result, result_width, self.signed)))
+    }
+
+    /// Returns the bits of the operands of a comparison, extended to a common width, or
+    /// `None` if an operand is null or contains a metavalue.
+    fn comparands(self, operands: &[Value]) -> Result<Option<Comparands>, PredefinedError> {
+        let [left, right] = operands else {
+            return Err(PredefinedError::Unsupported);
+        };
+        let (left, right) = (self.operand(left)?, self.operand(right)?);
+        let Some(vector_len) = left.len().or(right.len()) else {
+            return Err(PredefinedError::Unsupported);
+        };
+        if left.len() == Some(0) || right.len() == Some(0) {
+            return Ok(None);
+        }
+        let conversion_width = vector_len.max(INTEGER_WIDTH);
+        let (Some(left_bits), Some(right_bits)) = (
+            self.logic
+                .to_bits(&self.elements(left, conversion_width)?)?,
+            self.logic
+                .to_bits(&self.elements(right, conversion_width)?)?,
+        ) else {
+            return Ok(None);
+        };
+        let width = left_bits.len().max(right_bits.len());
+        Ok(Some((
+            extend(&left_bits, width, self.signed),
+            extend(&right_bits, width, self.signed),
+        )))
+    }
+
+    /// Relational operators; null and unknown operands are unequal and unordered.
+    fn relational(self, relation: Relation, operands: &[Value]) -> Outcome {
+        Ok(Value::boolean(match self.comparands(operands)? {
+            Some((left, right)) => relation.holds(compare_bits(&left, &right, self.signed)),
+            None => relation == Relation::Inequality,
+        }))
+    }
+
+    /// Matching relational operators, which return `'X'` for null and unknown operands.
+    fn matching(self, relation: Relation, operands: &[Value]) -> Outcome {
+        let std = Logic::StdUlogic;
+        if self.logic == Logic::Bit {
+            let holds = self.comparands(operands)?.is_some_and(|(left, right)| {
+                relation.holds(compare_bits(&left, &right, self.signed))
+            });
+            return Ok(Value::Enum(u32::from(holds)));
+        }
+        let result = match relation {
+            Relation::Equality => self.match_equality(operands)?,
+            Relation::Inequality => std.not(self.match_equality(operands)?)?,
+            _ => {
+                for operand in operands {
+                    if let Value::Array(elements) = operand
+                        && positions(elements)?.contains(&STD_DONT_CARE)
+                    {
+                        return Err(PredefinedError::InvalidOperands);
+                    }
+                }
+                match self.comparands(operands)? {
+                    Some((left, right)) => {
+                        std.position(relation.holds(compare_bits(&left, &right, self.signed)))
+                    },
+                    None => STD_X,
+                }
+            },
+        };
+        Ok(Value::Enum(result))
+    }
+
+    /// `?=` on vectors, which matches the elements of operands extended to the same length.
+    fn match_equality(self, operands: &[Value]) -> Result<u32, PredefinedError> {
+        let std = Logic::StdUlogic;
+        let [left, right] = operands else {
+            return Err(PredefinedError::Unsupported);
+        };
+        let (left, right) = (self.operand(left)?, self.operand(right)?);
+        let Some(vector_len) = left.len().or(right.len()) else {
+            return Err(PredefinedError::Unsupported);
+        };
+        if left.len() == Some(0) || right.len() == Some(0) {
+            return Ok(STD_X);
+        }
+        let conversion_width = vector_len.max(INTEGER_WIDTH);
+        let left_elements = self.elements(left, conversion_width)?;
+        let right_elements = self.elements(right, conversion_width)?;
+        let width = left_elements.len().max(right_elements.len());
+        let mut result = STD_1;
+        for (left_element, right_element) in self
+            .extend_elements(&left_elements, width)
+            .into_iter()
+            .zip(self.extend_elements(&right_elements, width))
+        {
+            match match_equality(left_element, right_element)? {
+                STD_U => return Ok(STD_U),
+                STD_X => result = STD_X,
+                element if result != STD_X => result = std.and(result, element)?,
+                _ => {},
+            }
+        }
+        Ok(result)
+    }
+
+    /// Extends elements on the left with `'0'`, or with the sign for signed vectors.
+    fn extend_elements(self, elements: &[u32], width: usize) -> Vec<u32> {
+        let fill = match elements.first() {
+            Some(&leftmost) if self.signed => leftmost,
+            _ => self.logic.position(false),
+        };
+        iter::repeat_n(fill, width.saturating_sub(elements.len()))
+            .chain(elements.iter().copied())
+            .collect()
+    }
+
+    /// Unary `-` and `abs`, which wrap around for the most negative value.
+    fn unary(self, operator: NumericOperator, operands: &[Value]) -> Outcome {
+        let [Value::Array(elements)] = operands else {
+            return Err(PredefinedError::Unsupported);
+        };
+        let Some(bits) = self.logic.to_bits(&positions(elements)?)? else {
+            return Ok(unknown(elements.len()));
+        };
+        let negative = self.signed && bits.first() == Some(&true);
+        let result = match operator {
+            NumericOperator::Absolute if !negative => bits,
+            _ => negate(&bits),
+        };
+        Ok(self.logic.vector(&result))
+    }
+
+    /// Shift and rotate functions and operators.
+    ///
+    /// `shift_left`, `shift_right`, `rotate_left` and `rotate_right` take a natural count;
+    /// the operators take any integer and shift in the opposite direction for a negative
+    /// count. Only right shifts of signed vectors replicate the sign.
+    fn shift(self, operator: NumericOperator, operands: &[Value]) -> Outcome {
+        let &[Value::Array(ref elements), Value::Integer(count)] = operands else {
+            return Err(PredefinedError::Unsupported);
+        };
+        let zero = Value::Enum(self.logic.position(false));
+        let sign = self.sign_fill(elements);
+        if count < 0
+            && matches!(
+                operator,
+                NumericOperator::ShiftLeft
+                    | NumericOperator::ShiftRight
+                    | NumericOperator::RotateLeft
+                    | NumericOperator::RotateRight
+            )
+        {
+            return Err(PredefinedError::InvalidOperands);
+        }
+        Ok(Value::Array(match operator {
+            NumericOperator::ShiftLeft | NumericOperator::Shift(ShiftOperator::Sll) => {
+                shift(elements, count, true, &zero)
+            },
+            NumericOperator::ShiftRight => shift(elements, count, false, &sign),
+            NumericOperator::Shift(ShiftOperator::Srl) => shift(elements, count, false, &zero),
+            NumericOperator::Shift(ShiftOperator::Sla) => shift(
+                elements,
+                count,
+                true,
+                if count >= 0 { &zero } else { &sign },
+            ),
+            NumericOperator::Shift(ShiftOperator::Sra) => shift(
+                elements,
+                count,
+                false,
+                if count >= 0 { &sign } else { &zero },
+            ),
+            NumericOperator::RotateLeft | NumericOperator::Shift(ShiftOperator::Rol) => {
+                rotate(elements, count, true)
+            },
+            NumericOperator::RotateRight | NumericOperator::Shift(ShiftOperator::Ror) => {
+                rotate(elements, count, false)
+            },
+            _ => return Err(PredefinedError::Unsupported),
+        }))
+    }
+
+    /// `resize` to a natural length or to the length of a vector; signed vectors keep
+    /// their sign when truncated.
+    fn resize(self, operands: &[Value]) -> Outcome {
+        let (elements, size) = match operands {
+            [Value::Array(elements), Value::Integer(size)] => (
+                elements,
+                usize::try_from(*size).map_err(|_ignore| PredefinedError::InvalidOperands)?,
+            ),
+            [Value::Array(elements), Value::Array(size_res)] => (elements, size_res.len()),
+            _ => return Err(PredefinedError::Unsupported),
+        };
+        let (Some(leftmost), false) = (elements.first(), size == 0) else {
+            return Ok(Value::Array(Vec::new()));
+        };
+        let len = elements.len();
+        Ok(Value::Array(if size >= len {
+            let fill = self.sign_fill(elements);
+            iter::repeat_n(&fill, size - len)
+                .chain(elements)
+                .cloned()
+                .collect()
+        } else if self.signed {
+            iter::once(leftmost)
+                .chain(&elements[len - size + 1..])
+                .cloned()
+                .collect()
+        } else {
+            elements[len - size..].to_vec()
+        }))
+    }
+
+    /// `to_integer`, which returns 0 for null vectors and metavalues.
+    fn to_integer(self, operands: &[Value]) -> Outcome {
+        let [Value::Array(elements)] = operands else {
+            return Err(PredefinedError::Unsupported);
+        };
+        match self.logic.to_bits(&positions(elements)?)? {
+            Some(bits) => integer(bits_integer(&bits, self.signed)),
+            None => Ok(Value::Integer(0)),
+        }
+    }
+
+    /// `to_unsigned` and `to_signed` to a natural length or to the length of a vector,
+    /// truncating the value if needed.
+    fn to_vector(self, operands: &[Value]) -> Outcome {
+        let (value, size) = match operands {
+            [value, Value::Integer(size)] => (
+                value,
+                usize::try_from(*size).map_err(|_ignore| PredefinedError::InvalidOperands)?,
+            ),
+            [value, Value::Array(size_res)] => (value, size_res.len()),
+            _ => return Err(PredefinedError::Unsupported),
+        };
+        let NumericOperand::Integer(value) = self.operand(value)? else {
+            return Err(PredefinedError::Unsupported);
+        };
+        Ok(self.logic.vector(&integer_bits(value, size)))
+    }
+}
+
+/// Returns a vector of `'X'`, the result of arithmetic on metavalues.
+fn unknown(width: usize) -> Value {
+    Value::Array(vec![Value::Enum(STD_X); width])
+}
+
+/// Returns the `width` least significant bits of an integer in two's complement.
+fn integer_bits(value: i64, width: usize) -> Vec<bool> {
+    (0..width)
+        .rev()
+        .map(|index| {
+            u32::try_from(index)
+                .ok()
+                .and_then(|index| value.checked_shr(index))
+                .map_or(value < 0, |shifted| shifted & 1 == 1)
+        })
+        .collect()
+}
+
+/// Returns the value of bits in two's complement if `signed`, or `None` if it overflows.
+fn bits_integer(bits: &[bool], signed: bool) -> Option<i64> {
+    let negative = signed && bits.first() == Some(&true);
+    // Leading sign bits don't change the value.
+    let significant = bits
+        .iter()
+        .position(|&bit| bit != negative)
+        .map_or(&[][..], |start| &bits[start..]);
+    if significant.len() > 64 {
+        return None;
+    }
+    let mut value = 0_i128;
+    for &bit in significant {
+        value = value * 2 + i128::from(bit);
+    }
+    if negative {
+        value -= 1_i128 << significant.len();
+    }
+    i64::try_from(value).ok()
+}
+
+/// Truncates or extends bits on the left to `width`, replicating the sign if `signed`.
+fn extend(bits: &[bool], width: usize, signed: bool) -> Vec<bool> {
+    let len = bits.len();
+    if width <= len {
+        bits[len - width..].to_vec()
+    } else {
+        let sign = signed && bits.first() == Some(&true);
+        iter::repeat_n(sign, width - len)
+            .chain(bits.iter().copied())
+            .collect()
+    }
+}
+
+/// Adds bits of the same width, wrapping around.
+fn add(left: &[bool], right: &[bool], mut carry: bool) -> Vec<bool> {
+    let mut sum = vec![false; left.len()];
+    for (index, (&left_bit, &right_bit)) in left.iter().zip(right).enumerate().rev() {
+        if let Some(sum_bit) = sum.get_mut(index) {
+            *sum_bit = left_bit ^ right_bit ^ carry;
+        }
+        carry = left_bit && right_bit || carry && (left_bit ^ right_bit);
+    }
+    sum
+}
+
+fn subtract(left: &[bool], right: &[bool]) -> Vec<bool> {
+    let inverted = right.iter().map(|bit| !bit).collect::<Vec<_>>();
+    add(left, &inverted, true)
+}
+
+/// Negates bits in two's complement, wrapping around.
+fn negate(bits: &[bool]) -> Vec<bool> {
+    subtract(&vec![false; bits.len()], bits)
+}
+
+/// Multiplies bits of the same width, wrapping around.
+fn multiply(left: &[bool], right: &[bool]) -> Vec<bool> {
+    let width = left.len();
+    let mut product = vec![false; width];
+    for (index, &right_bit) in right.iter().enumerate() {
+        if right_bit {
+            let shift = width - 1 - index;
+            let shifted = left[shift..]
+                .iter()
+                .copied()
+                .chain(iter::repeat_n(false, shift))
+                .collect::<Vec<_>>();
+            product = add(&product, &shifted, false);
+        }
+    }
+    product
+}
+
+/// Orders bits of the same width.
+fn compare_bits(left: &[bool], right: &[bool], signed: bool) -> Ordering {
+    match (left.first(), right.first()) {
+        // Two's complement numbers of the same sign are ordered like unsigned ones.
+        (Some(&left_sign), Some(&right_sign)) if signed && left_sign != right_sign => {
+            right_sign.cmp(&left_sign)
+        },
+        _ => left.cmp(right),
+    }
+}
+
+/// Divides bits of the same width, returning the quotient truncated towards zero and
+/// the remainder with the sign of `left`, or `None` when dividing by zero.
+///
+/// Signed operands are divided one bit wider, so that dividing the most negative value
+/// by -1 doesn't overflow.
+fn divide_bits(left: &[bool], right: &[bool], signed: bool) -> Option<(Vec<bool>, Vec<bool>)> {
+    if !right.contains(&true) {
+        return None;
+    }
+    if !signed {
+        return Some(divide_unsigned(left, right));
+    }
+    let width = left.len() + 1;
+    let (left, right) = (extend(left, width, true), extend(right, width, true));
+    let (left_negative, right_negative) =
+        (left.first() == Some(&true), right.first() == Some(&true));
+    let magnitude = |bits: Vec<bool>, negative: bool| if negative { negate(&bits) } else { bits };
+    let (quotient, remainder) = divide_unsigned(
+        &magnitude(left, left_negative),
+        &magnitude(right, right_negative),
+    );
+    Some((
+        magnitude(quotient, left_negative != right_negative),
+        magnitude(remainder, left_negative),
+    ))
+}
+
+/// Divides unsigned bits of the same width by long division.
+fn divide_unsigned(left: &[bool], right: &[bool]) -> (Vec<bool>, Vec<bool>) {
+    let width = left.len();
+    let divisor = extend(right, width + 1, false);
+    let mut quotient = vec![false; width];
+    let mut remainder = vec![false; width + 1];
+    for (index, &left_bit) in left.iter().enumerate() {
+        remainder.remove(0);
+        remainder.push(left_bit);
+        if compare_bits(&remainder, &divisor, false).is_ge() {
+            remainder = subtract(&remainder, &divisor);
+            if let Some(quotient_bit) = quotient.get_mut(index) {
+                *quotient_bit = true;
+            }
+        }
+    }
+    (quotient, extend(&remainder, width, false))
+}
+
+/// Returns the digits of a vector in base 16 or 8, padding it on the left to whole digits.
+///
+/// Digits of `std_ulogic` vectors are `'Z'` if all their elements are `'Z'`, and `'X'` if
+/// any is another metavalue. Vectors starting with `'Z'` are padded with `'Z'`, and signed
+/// vectors with their sign.
+fn radix_image(logic: Logic, elements: &[Value], digit_bits: usize, signed: bool) -> Outcome {
+    let elements = positions(elements)?;
+    let pad = match elements.first() {
+        Some(&STD_Z) if logic == Logic::StdUlogic => STD_Z,
+        Some(&leftmost) if signed => leftmost,
+        _ => logic.position(false),
+    };
+    let padding = elements.len().div_ceil(digit_bits) * digit_bits - elements.len();
+    let padded = iter::repeat_n(pad, padding)
+        .chain(elements)
+        .collect::<Vec<_>>();
+    let mut text = String::new();
+    for digit in padded.chunks(digit_bits) {
+        let character = match logic.to_bits(digit)? {
+            Some(bits) => char::from_digit(
+                bits.iter()
+                    .fold(0, |value, &bit| value * 2 + u32::from(bit)),
+                16,
+            )
+            .map_or('X', |character| character.to_ascii_uppercase()),
+            None if digit.iter().all(|&element| element == STD_Z) => 'Z',
+            None => 'X',
+        };
+        text.push(character);
+    }
+    Ok(Value::string(&text))
+}
+
+/// Returns the image of a real, which is the shortest literal reading back as the same value.
+fn real_image(value: f64) -> String {
+    let mut image = format!("{value:?}");
+    if !image.contains('.') {
+        match image.find('e') {
+            Some(exponent) => image.insert_str(exponent, ".0"),
+            None => image.push_str(".0"),
+        }
+    }
+    image
+}
+
+/// Returns an integer result, which overflows only if it doesn't fit in 64 bits: checking it
+/// against the range of the result subtype is left to the caller.
+fn integer(value: Option<i64>) -> Outcome {
+    value.map(Value::Integer).ok_or(PredefinedError::Overflow)
+}
+
+/// Returns a physical result in primary units, which is range checked like [`integer`].
+fn physical(value: Option<i64>) -> Outcome {
+    value.map(Value::Physical).ok_or(PredefinedError::Overflow)
+}
+
+/// Returns a floating-point result, which overflows unless it's finite.
+fn real(value: f64) -> Outcome {
+    if value.is_finite() {
+        Ok(Value::Real(value))
+    } else {
+        Err(PredefinedError::Overflow)
+    }
+}
+
+/// Returns a floating-point result if its operands are in the domain of the function.
+fn domain(valid: bool, value: f64) -> Outcome {
+    if valid {
+        real(value)
+    } else {
+        Err(PredefinedError::InvalidOperands)
+    }
+}
+
+/// `**` of `math_real`, which is defined for a negative base only with integral exponents.
+fn power(base: f64, exponent: f64) -> Outcome {
+    domain(
+        (base != 0.0 || exponent > 0.0) && (base >= 0.0 || exponent.fract() == 0.0),
+        base.powf(exponent),
+    )
+}
+
+/// Integer division, truncating towards zero.
+fn divide(left: i64, right: i64) -> Result<i64, PredefinedError> {
+    if right == 0 {
+        return Err(PredefinedError::DivisionByZero);
+    }
+    left.checked_div(right).ok_or(PredefinedError::Overflow)
+}
+
+fn divide_real(left: f64, right: f64) -> Result<f64, PredefinedError> {
+    if right == 0.0 {
+        return Err(PredefinedError::DivisionByZero);
+    }
+    Ok(left / right)
+}
+
+/// `rem`: the result has the sign of the left operand. It's 0 for `i64::MIN rem -1`, whose
+/// quotient overflows.
+fn remainder(left: i64, right: i64) -> Result<i64, PredefinedError> {
+    if right == 0 {
+        return Err(PredefinedError::DivisionByZero);
+    }
+    Ok(left.wrapping_rem(right))
+}
+
+/// `mod`: the result has the sign of the right operand.
+fn modulus(left: i64, right: i64) -> Result<i64, PredefinedError> {
+    let remainder = remainder(left, right)?;
+    if remainder != 0 && (remainder < 0) != (right < 0) {
+        Ok(remainder + right)
+    } else {
+        Ok(remainder)
+    }
+}
+
+/// Orders two scalars of the same type, or two arrays of scalars lexicographically.
+fn compare(left: &Value, right: &Value) -> Result<Ordering, PredefinedError> {
+    match (left, right) {
+        (Value::Integer(left), Value::Integer(right))
+        | (Value::Physical(left), Value::Physical(right)) => Ok(left.cmp(right)),
+        (Value::Enum(left), Value::Enum(right)) => Ok(left.cmp(right)),
+        (Value::Real(left), Value::Real(right)) => left
+            .partial_cmp(right)
+            .ok_or(PredefinedError::InvalidOperands),
+        (Value::Array(left), Value::Array(right)) => {
+            for (left_element, right_element) in left.iter().zip(right) {
+                let ordering = compare(left_element, right_element)?;
+                if ordering.is_ne() {
+                    return Ok(ordering);
+                }
+            }
+            Ok(left.len().cmp(&right.len()))
+        },
+        _ => Err(PredefinedError::InvalidOperands),
+    }
+}
+
+#[expect(
+    clippy::cast_precision_loss,
+    reason = "VHDL converts to the nearest representable real"
+)]
+pub(crate) fn integer_to_real(value: i64) -> f64 {
+    value as f64
+}
+
+/// Rounds to the nearest integer, away from zero for halfway values (LRM §9.3.6).
+pub(crate) fn real_to_integer(value: f64) -> Option<i64> {
+    let rounded = value.round();
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "the range is checked before the conversion"
+    )]
+    (rounded >= -(2.0_f64.powi(63)) && rounded < 2.0_f64.powi(63)).then_some(rounded as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use ImplicitDefinition::*;
+
+    use super::*;
+
+    fn vector(elements: &[u32]) -> Value {
+        Value::Array(
+            elements
+                .iter()
+                .map(|&element| Value::Enum(element))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_integer_arithmetic() {
+        assert_eq!(
+            IntegerPlus.apply(&[Value::Integer(40), Value::Integer(2)]),
+            Ok(Value::Integer(42))
+        );
+        assert_eq!(
+            IntegerExp.apply(&[Value::Integer(2), Value::Integer(10)]),
+            Ok(Value::Integer(1024))
+        );
+        assert_eq!(
+            IntegerExp.apply(&[Value::Integer(0), Value::Integer(0)]),
+            Ok(Value::Integer(1))
+        );
+        assert_eq!(
+            IntegerExp.apply(&[Value::Integer(2), Value::Integer(-1)]),
+            Err(PredefinedError::InvalidOperands)
+        );
+        assert_eq!(
+            FloatingExp.apply(&[Value::Real(2.0), Value::Integer(-2)]),
+            Ok(Value::Real(0.25))
+        );
+        assert_eq!(
+            FloatingExp.apply(&[Value::Real(0.0), Value::Integer(-1)]),
+            Err(PredefinedError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_rem_mod() {
+        for (left, right, rem, modulo) in [
+            (7, 3, 1, 1),
+            (-7, 3, -1, 2),
+            (7, -3, 1, -2),
+            (-7, -3, -1, -1),
+            (-6, 3, 0, 0),
+            (i64::MIN, -1, 0, 0),
+        ] {
+            let integers = [Value::Integer(left), Value::Integer(right)];
+            assert_eq!(IntegerRem.apply(&integers), Ok(Value::Integer(rem)));
+            assert_eq!(IntegerMod.apply(&integers), Ok(Value::Integer(modulo)));
+            let physicals = [Value::Physical(left), Value::Physical(right)];
+            assert_eq!(PhysicalRem.apply(&physicals), Ok(Value::Physical(rem)));
+            assert_eq!(PhysicalMod.apply(&physicals), Ok(Value::Physical(modulo)));
+        }
+        assert_eq!(
+            IntegerMod.apply(&[Value::Integer(7), Value::Integer(0)]),
+            Err(PredefinedError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_range_edges() {
+        assert_eq!(
+            IntegerPlus.apply(&[Value::Integer(i64::MAX - 1), Value::Integer(1)]),
+            Ok(Value::Integer(i64::MAX))
+        );
+        assert_eq!(
+            IntegerPlus.apply(&[Value::Integer(i64::MAX), Value::Integer(1)]),
+            Err(PredefinedError::Overflow)
+        );
+        assert_eq!(
+            IntegerMinus.apply(&[Value::Integer(i64::MIN + 1), Value::Integer(1)]),
+            Ok(Value::Integer(i64::MIN))
+        );
+        assert_eq!(
+            IntegerNegation.apply(&[Value::Integer(-i64::MAX)]),
+            Ok(Value::Integer(i64::MAX))
+        );
+        assert_eq!(
+            IntegerNegation.apply(&[Value::Integer(i64::MIN)]),
+            Err(PredefinedError::Overflow)
+        );
+        assert_eq!(
+            IntegerAbsolute.apply(&[Value::Integer(i64::MIN)]),
+            Err(PredefinedError::Overflow)
+        );
+        assert_eq!(
+            IntegerDiv.apply(&[Value::Integer(i64::MIN), Value::Integer(1)]),
+            Ok(Value::Integer(i64::MIN))
+        );
+        assert_eq!(
+            IntegerDiv.apply(&[Value::Integer(i64::MIN), Value::Integer(-1)]),
+            Err(PredefinedError::Overflow)
+        );
+        assert_eq!(
+            IntegerExp.apply(&[Value::Integer(-2), Value::Integer(63)]),
+            Ok(Value::Integer(i64::MIN))
+        );
+        assert_eq!(
+            IntegerExp.apply(&[Value::Integer(2), Value::Integer(63)]),
+            Err(PredefinedError::Overflow)
+        );
+
+        assert_eq!(
+            PhysicalPlus.apply(&[Value::Physical(i64::MAX - 1), Value::Physical(1)]),
+            Ok(Value::Physical(i64::MAX))
+        );
+        assert_eq!(
+            PhysicalIntegerMul.apply(&[Value::Physical(i64::MAX), Value::Integer(-1)]),
+            Ok(Value::Physical(-i64::MAX))
+        );
+        assert_eq!(
+            IntegerPhysicalMul.apply(&[Value::Integer(2), Value::Physical(i64::MAX / 2 + 1)]),
+            Err(PredefinedError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_shifts() {
+        let bits = vector(&[1, 0, 0, 1]);
+        for (operation, count, expected) in [
+            (ArraySll, 1, [0, 0, 1, 0]),
+            (ArraySll, -1, [0, 1, 0, 0]),
+            (ArraySll, 4, [0, 0, 0, 0]),
+            (ArraySll, i64::MIN, [0, 0, 0, 0]),
+            (ArraySrl, 1, [0, 1, 0, 0]),
+            (ArraySrl, -1, [0, 0, 1, 0]),
+            (ArraySrl, 5, [0, 0, 0, 0]),
+            (ArraySla, 1, [0, 0, 1, 1]),
+            (ArraySla, -1, [1, 1, 0, 0]),
+            (ArraySla, 7, [1, 1, 1, 1]),
+            (ArraySra, 2, [1, 1, 1, 0]),
+            (ArraySra, -1, [0, 0, 1, 1]),
+            (ArraySra, 6, [1, 1, 1, 1]),
+            (ArrayRol, 1, [0, 0, 1, 1]),
+            (ArrayRol, -1, [1, 1, 0, 0]),
+            (ArrayRol, 5, [0, 0, 1, 1]),
+            (ArrayRol, i64::MIN, [1, 0, 0, 1]),
+            (ArrayRor, 1, [1, 1, 0, 0]),
+            (ArrayRor, -3, [1, 1, 0, 0]),
+            (ArrayRor, 6, [0, 1, 1, 0]),
+        ] {
+            assert_eq!(
+                operation.apply(&[bits.clone(), Value::Integer(count)]),
+                Ok(vector(&expected)),
+                "{operation:?} {count}"
+            );
+        }
+
+        for operation in [ArraySll, ArraySrl, ArraySla, ArraySra, ArrayRol, ArrayRor] {
+            for count in [0, 3, -3] {
+                assert_eq!(
+                    operation.apply(&[vector(&[]), Value::Integer(count)]),
+                    Ok(vector(&[]))
+                );
+            }
+        }
+
+        assert_eq!(
+            Ieee1164VectorSll.apply(&[vector(&[STD_1, STD_H]), Value::Integer(1)]),
+            Ok(vector(&[STD_H, STD_0]))
+        );
+        assert_eq!(
+            Ieee1164VectorRor.apply(&[vector(&[STD_1, STD_H, STD_Z]), Value::Integer(-4)]),
+            Ok(vector(&[STD_H, STD_Z, STD_1]))
+        );
+    }
+
+    #[test]
+    fn test_match_equality() {
+        // LRM §9.2.3: rows are the left operand and columns the right one, both in the
+        // order of std_ulogic.
+        const VALUES: &str = "UX01ZWLH-";
+        const TABLE: [&str; 9] = [
+            "UUUUUUUU1",
+            "UXXXXXXX1",
+            "UX10XX101",
+            "UX01XX011",
+            "UXXXXXXX1",
+            "UXXXXXXX1",
+            "UX10XX101",
+            "UX01XX011",
+            "111111111",
+        ];
+        let position = |value| {
+            VALUES
+                .find(value)
+                .map(|index| u32::try_from(index).unwrap())
+        };
+
+        for (left, row) in (0..).zip(TABLE) {
+            for (right, expected) in (0..).zip(row.chars()) {
+                assert_eq!(
+                    StdUlogicMatchEquality.apply(&[Value::Enum(left), Value::Enum(right)]),
+                    Ok(Value::Enum(position(expected).unwrap())),
+                    "{left} ?= {right}"
+                );
+            }
+        }
+
+        assert_eq!(
+            StdUlogicMatchLess.apply(&[Value::Enum(STD_DONT_CARE), Value::Enum(STD_0)]),
+            Err(PredefinedError::InvalidOperands)
+        );
+        assert_eq!(
+            TfReductionXor.apply(&[vector(&[1, 0, 0, 1])]),
+            Ok(Value::Enum(0))
+        );
+    }
+
+    #[test]
+    fn test_numeric_std() {
+        assert_eq!(
+            IeeeNumericStdAddUnsUns.apply(&[vector(&[STD_1, STD_1]), vector(&[STD_0, STD_1])]),
+            Ok(vector(&[STD_0, STD_0]))
+        );
+        assert_eq!(
+            IeeeNumericStdAddSgnInt.apply(&[vector(&[STD_0, STD_1, STD_0]), Value::Integer(-3)]),
+            Ok(vector(&[STD_1, STD_1, STD_1]))
+        );
+        assert_eq!(
+            IeeeNumericStdAddUnsUns.apply(&[vector(&[STD_X, STD_1]), vector(&[STD_0, STD_1])]),
+            Ok(vector(&[STD_X, STD_X]))
+        );
+        assert_eq!(
+            IeeeNumericStdAddUnsNat.apply(&[vector(&[STD_1]), Value::Integer(-1)]),
+            Err(PredefinedError::InvalidOperands)
+        );
+    }
+
+    #[test]
+    fn test_images() {
+        assert_eq!(
+            IntegerToString.apply(&[Value::Integer(-42)]),
+            Ok(Value::string("-42"))
+        );
+        assert_eq!(
+            FloatingToString.apply(&[Value::Real(1.0)]),
+            Ok(Value::string("1.0"))
+        );
+
+        // Vectors whose length isn't a multiple of the digit width are padded on the left.
+        for (elements, expected) in [
+            (&[1, 0, 1, 1, 1, 1][..], "2F"),
+            (&[1, 0, 0, 0, 1], "11"),
+            (&[1], "1"),
+            (&[], ""),
+        ] {
+            assert_eq!(
+                BitVectorToHstring.apply(&[vector(elements)]),
+                Ok(Value::string(expected))
+            );
+        }
+        assert_eq!(
+            BitVectorToOstring.apply(&[vector(&[1, 1, 1, 1])]),
+            Ok(Value::string("17"))
+        );
+        assert_eq!(
+            Ieee1164ToHstring.apply(&[vector(&[STD_1, STD_0, STD_X, STD_1, STD_1])]),
+            Ok(Value::string("1X"))
+        );
+        assert_eq!(
+            Ieee1164ToHstring.apply(&[vector(&[STD_Z; 5])]),
+            Ok(Value::string("ZZ"))
+        );
+        assert_eq!(
+            IeeeNumericStdToHstringSgn
+                .apply(&[vector(&[STD_1, STD_0, STD_1, STD_0, STD_1, STD_1])]),
+            Ok(Value::string("EB"))
+        );
+    }
+}
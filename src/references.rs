@@ -0,0 +1,344 @@
+//! Enumeration of the node IDs stored in the fields of a node.
+//!
+//! Node structs are declared with [`node_struct!`], which implements
+//! [`NodeFields`] for them: every field is passed to a [`FieldSink`] in
+//! declaration order, together with its serialized name and whether it owns the
+//! nodes it refers to. The field types implement [`FieldValue`], which reports
+//! the node IDs ([`NodeReference`]) and [`Identifier`]s they contain.
+//!
+//! Fields are either *owning* (the referenced node is part of the subtree, e.g.
+//! `concurrent_statements`) or *non-owning* (the referenced node lives
+//! elsewhere, e.g. `type` or `named_entity`). Non-owning fields are marked with
+//! `@reference` in the struct declaration. Only owning fields contribute to the
+//! parent index.
+
+use super::*;
+
+/// Declares a node struct and implements [`NodeFields`] for it.
+///
+/// Fields preceded by `@reference` refer to nodes owned by another node; all
+/// other fields own the nodes they refer to.
+macro_rules! node_struct {
+    (@owning) => { true };
+    (@owning reference) => { false };
+
+    // Serialized name of a field: the `serde` rename if there is one, the field name otherwise.
+    (@name $field:ident) => { stringify!($field) };
+    (@name $field:ident #[serde(rename = $name:literal)] $($rest:tt)*) => { $name };
+    (@name $field:ident #[serde(default, rename = $name:literal)] $($rest:tt)*) => { $name };
+    (@name $field:ident #[$($attr:tt)*] $($rest:tt)*) => {
+        $crate::references::node_struct!(@name $field $($rest)*)
+    };
+
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {}
+    ) => {
+        $(#[$attr])*
+        pub struct $name {}
+
+        impl $crate::references::NodeFields for $name {
+            fn visit_fields(&self, _sink: &mut impl $crate::references::FieldSink) {}
+        }
+    };
+
+    (
+        $(#[$attr:meta])*
+        pub struct $name:ident {
+            $(
+                $(#[$($field_attr:tt)*])*
+                $(@$marker:ident)?
+                pub $field:ident: $type:ty
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        pub struct $name {
+            $(
+                $(#[$($field_attr)*])*
+                pub $field: $type,
+            )+
+        }
+
+        impl $crate::references::NodeFields for $name {
+            fn visit_fields(&self, sink: &mut impl $crate::references::FieldSink) {
+                $(
+                    $crate::references::FieldValue::visit(
+                        &self.$field,
+                        $crate::references::Field {
+                            name: $crate::references::node_struct!(
+                                @name $field $(#[$($field_attr)*])*
+                            ),
+                            owning: $crate::references::node_struct!(@owning $($marker)?),
+                        },
+                        sink,
+                    );
+                )+
+            }
+        }
+    };
+}
+
+pub(crate) use node_struct;
+
+/// A field of a node struct.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Field {
+    /// Serialized name of the field.
+    pub(crate) name: &'static str,
+    /// Whether the field owns the nodes it refers to.
+    pub(crate) owning: bool,
+}
+
+/// A node ID stored in a field of a node.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Reference {
+    /// The field containing the ID.
+    pub(crate) field: Field,
+    /// Name of the ID type, see [`NodeReference::NAME`].
+    pub(crate) expected: &'static str,
+    /// Returns whether the ID type may refer to the given node, see [`NodeReference::accepts`].
+    pub(crate) accepts: fn(&Node) -> bool,
+    /// The referenced node.
+    pub(crate) id: GenericNodeId,
+}
+
+/// Node ID type which may be stored in a node field.
+///
+/// Implemented for the [`NodeId`]s of all node kinds, [`GenericNodeId`], and by
+/// [`subset_declaration!`] for all subset IDs.
+#[doc(hidden)]
+pub trait NodeReference: AstNodeId + Copy {
+    /// Name of the ID type, e.g. `NodeId<SimpleName>` or `NameNodeId`.
+    const NAME: &'static str;
+
+    /// Returns whether an ID of this type may refer to the given node.
+    fn accepts(node: &Node) -> bool;
+}
+
+/// Node struct whose fields can be enumerated, implemented by [`node_struct!`].
+pub(crate) trait NodeFields {
+    /// Passes every node reference and identifier stored in this node to `sink`, in field order.
+    fn visit_fields(&self, sink: &mut impl FieldSink);
+}
+
+/// Type of a node struct field.
+pub(crate) trait FieldValue {
+    /// Passes the node references and identifiers stored in this value to `sink`.
+    fn visit(&self, field: Field, sink: &mut impl FieldSink);
+}
+
+impl<I> FieldValue for I
+where
+    I: NodeReference,
+{
+    fn visit(&self, field: Field, sink: &mut impl FieldSink) {
+        sink.reference(Reference {
+            field,
+            expected: I::NAME,
+            accepts: I::accepts,
+            id: GenericNodeId::from_raw(self.id_primitive()),
+        });
+    }
+}
+
+impl<T> FieldValue for Option<T>
+where
+    T: FieldValue,
+{
+    fn visit(&self, field: Field, sink: &mut impl FieldSink) {
+        if let Some(value) = self {
+            value.visit(field, sink);
+        }
+    }
+}
+
+impl<T> FieldValue for Vec<T>
+where
+    T: FieldValue,
+{
+    fn visit(&self, field: Field, sink: &mut impl FieldSink) {
+        for value in self {
+            value.visit(field, sink);
+        }
+    }
+}
+
+impl FieldValue for Identifier {
+    fn visit(&self, field: Field, sink: &mut impl FieldSink) {
+        sink.identifier(field, self);
+    }
+}
+
+impl FieldValue for IndexList {
+    fn visit(&self, field: Field, sink: &mut impl FieldSink) {
+        match self {
+            Self::Items(items) => items.visit(field, sink),
+            Self::Others => {},
+        }
+    }
+}
+
+impl FieldValue for SensitivityList {
+    fn visit(&self, field: Field, sink: &mut impl FieldSink) {
+        match self {
+            Self::Signals(signals) => signals.visit(field, sink),
+            Self::All => {},
+        }
+    }
+}
+
+impl FieldValue for InstantiationList {
+    fn visit(&self, field: Field, sink: &mut impl FieldSink) {
+        match self {
+            Self::Items(items) => items.visit(field, sink),
+            Self::All | Self::Others => {},
+        }
+    }
+}
+
+/// Implements [`FieldValue`] for field types which contain neither node IDs nor identifiers.
+macro_rules! plain_field_values {
+    ($($type:ty),+ $(,)?) => {
+        $(
+            impl FieldValue for $type {
+                fn visit(&self, _field: Field, _sink: &mut impl FieldSink) {}
+            }
+        )+
+    };
+}
+
+plain_field_values!(
+    bool,
+    i32,
+    i64,
+    u32,
+    f64,
+    CompactString,
+    Latin1String,
+    AttributeKind,
+    BinaryOperatorKind,
+    DelayMechanism,
+    Direction,
+    ForceMode,
+    ImplicitDefinition,
+    Mode,
+    SignalKind,
+    UnaryOperatorKind,
+);
+
+/// Checks every node reference of `source` against the kind of the referenced node.
+pub(crate) fn check_references(
+    ast: &Ast,
+    source: GenericNodeId,
+    node: &Node,
+    violations: &mut Vec<ReferenceViolation>,
+) {
+    node.for_each_reference(|reference| {
+        let target = ast.nodes.get(reference.id.to_raw().get() as usize);
+        // Nodes of unknown kinds have already been reported while loading.
+        if matches!(target, Some(Node::Unknown { .. }))
+            || target.is_some_and(|target| (reference.accepts)(target))
+        {
+            return;
+        }
+        violations.push(ReferenceViolation {
+            node: source,
+            node_kind: node.type_str(),
+            field: reference.field.name,
+            target: reference.id,
+            expected: reference.expected,
+            actual: target.map_or(Node::Empty.type_str(), Node::type_str),
+        });
+    });
+}
+
+/// A node reference which doesn't point to a node of an accepted kind, as reported by [`Ast::validate`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "{node_kind} #{node}: field `{field}` refers to #{target}, which is {actual} rather than {expected}",
+    node = node.to_raw(),
+    target = target.to_raw(),
+)]
+pub struct ReferenceViolation {
+    /// The node containing the reference.
+    pub node: GenericNodeId,
+    /// [`Node::type_str`] of the node containing the reference.
+    pub node_kind: &'static str,
+    /// Serialized name of the field containing the reference (e.g. `"type"` rather than `"typ"`).
+    pub field: &'static str,
+    /// The referenced node.
+    pub target: GenericNodeId,
+    /// Type of the ID stored in the field, e.g. `NodeId<SimpleName>` or `NameNodeId`.
+    pub expected: &'static str,
+    /// [`Node::type_str`] of the referenced node, or `"<empty>"` if the ID is out of range.
+    pub actual: &'static str,
+}
+
+/// Receiver of the node references and identifiers stored in a node.
+pub(crate) trait FieldSink {
+    /// Called for every node reference.
+    fn reference(&mut self, reference: Reference);
+
+    /// Called for every identifier.
+    fn identifier(&mut self, _field: Field, _identifier: &Identifier) {}
+}
+
+impl<F> FieldSink for F
+where
+    F: FnMut(Reference),
+{
+    fn reference(&mut self, reference: Reference) {
+        self(reference);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+    use crate::tests::load_fixture;
+
+    #[test]
+    fn test_validate() {
+        load_fixture().validate().unwrap();
+
+        let input = FIXTURE
+            .replace(
+                r#""sequential_statements":[15]"#,
+                r#""sequential_statements":[13]"#,
+            )
+            .replace(r#""we_value":19"#, r#""we_value":42"#);
+        let ast = Ast::from_json(&mut input.as_bytes(), 1).unwrap().ast;
+        let id = |raw| GenericNodeId::from_raw(IdPrimitive::new(raw).unwrap());
+
+        let violations = ast.validate().unwrap_err();
+        assert_eq!(
+            violations,
+            [
+                ReferenceViolation {
+                    node: id(14),
+                    node_kind: "SensitizedProcessStatement",
+                    field: "sequential_statements",
+                    target: id(13),
+                    expected: "SequentialStatementNodeId",
+                    actual: "IntegerLiteral",
+                },
+                ReferenceViolation {
+                    node: id(18),
+                    node_kind: "WaveformElement",
+                    field: "we_value",
+                    target: id(42),
+                    expected: "ExpressionNodeId",
+                    actual: "<empty>",
+                },
+            ]
+        );
+        assert_eq!(
+            violations[0].to_string(),
+            "SensitizedProcessStatement #14: field `sequential_statements` refers to #13, \
+             which is IntegerLiteral rather than SequentialStatementNodeId"
+        );
+    }
+}
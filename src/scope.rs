@@ -0,0 +1,561 @@
+//! Visibility of declarations at a node of an [`Ast`] (LRM §12).
+//!
+//! A [`Scope`] collects the declarations of the declarative regions enclosing
+//! a node, and the declarations made visible by use clauses, either in those
+//! regions or in the context clause of the design unit. Overloads aren't
+//! resolved: all visible homographs of overloadable declarations are kept.
+
+use super::*;
+
+/// The named entities visible at a node, see [`Ast::scope`].
+#[derive(Clone)]
+pub struct Scope<'ast> {
+    ast: &'ast Ast,
+    /// Declarations of the enclosing declarative regions, innermost region first.
+    regions: Vec<Vec<NamedEntityNodeId>>,
+    /// Declarations made potentially visible by use clauses.
+    used: Vec<NamedEntityNodeId>,
+    /// Libraries made visible by library clauses, including the implicit `std`.
+    libraries: Vec<NodeId<Library>>,
+    /// Library of the design unit, only visible as `work`.
+    work_library: Option<NodeId<Library>>,
+}
+
+impl fmt::Debug for Scope<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope")
+            .field("regions", &self.regions)
+            .field("used", &self.used)
+            .field("libraries", &self.libraries)
+            .field("work_library", &self.work_library)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Scope<'_> {
+    /// Returns the visible named entities, directly visible declarations of inner regions first.
+    ///
+    /// A declaration hides the declarations of the same name in outer regions and those made
+    /// potentially visible by use clauses, unless all of them are overloadable (subprograms
+    /// and enumeration literals). Conflicting declarations made potentially visible by
+    /// different use clauses aren't visible at all (LRM §12.4).
+    #[must_use]
+    pub fn visible(&self) -> Vec<NamedEntityNodeId> {
+        let ast = self.ast;
+        let mut visible = Vec::new();
+        // Whether all declarations of the name seen so far are overloadable.
+        let mut declared = Map::<Symbol, bool>::default();
+
+        for region in &self.regions {
+            let mut declared_here = Vec::new();
+            for &id in region {
+                let Some(symbol) = ast.symbol(id) else {
+                    continue;
+                };
+                let overloadable = is_overloadable(ast, id);
+                if declared
+                    .get(&symbol)
+                    .is_some_and(|&all_overloadable| !all_overloadable || !overloadable)
+                {
+                    continue;
+                }
+                if !visible.contains(&id) {
+                    visible.push(id);
+                }
+                declared_here.push((symbol, overloadable));
+            }
+            for (symbol, overloadable) in declared_here {
+                *declared.entry(symbol).or_insert(true) &= overloadable;
+            }
+        }
+
+        let mut used = Map::<Symbol, Vec<NamedEntityNodeId>>::default();
+        for &id in &self.used {
+            if let Some(symbol) = ast.symbol(id) {
+                let homographs = used.entry(symbol).or_default();
+                if !homographs.contains(&id) {
+                    homographs.push(id);
+                }
+            }
+        }
+        let mut used = used.into_iter().collect::<Vec<_>>();
+        used.sort_unstable_by_key(|(_, homographs)| {
+            homographs.first().map(AstNodeId::id_primitive)
+        });
+        for (symbol, homographs) in used {
+            let non_overloadable = homographs
+                .iter()
+                .filter(|&&id| !is_overloadable(ast, id))
+                .count();
+            let hidden = match declared.get(&symbol) {
+                Some(&all_overloadable) => !all_overloadable || non_overloadable > 0,
+                None => non_overloadable > 1,
+            };
+            if !hidden {
+                for id in homographs {
+                    if !visible.contains(&id) {
+                        visible.push(id);
+                    }
+                }
+                declared.insert(symbol, non_overloadable == 0);
+            }
+        }
+
+        for &library_id in &self.libraries {
+            let id = NamedEntityNodeId::from(library_id);
+            if let Some(symbol) = ast.symbol(id)
+                && !declared.contains_key(&symbol)
+            {
+                visible.push(id);
+                declared.insert(symbol, false);
+            }
+        }
+        visible
+    }
+
+    /// Returns the visible named entities with the given name, see [`Scope::visible`].
+    ///
+    /// `work` denotes the library of the design unit unless a declaration of that name is visible.
+    #[must_use]
+    pub fn lookup(&self, identifier: &NormalizedIdentifier) -> Vec<NamedEntityNodeId> {
+        let Some(symbol) = self.ast.symbols.get(identifier) else {
+            return self.lookup_work(identifier);
+        };
+        let found = self
+            .visible()
+            .into_iter()
+            .filter(|&id| self.ast.symbol(id) == Some(symbol))
+            .collect::<Vec<_>>();
+        if found.is_empty() {
+            self.lookup_work(identifier)
+        } else {
+            found
+        }
+    }
+
+    /// Returns the library of the design unit enclosing the node, visible as `work`.
+    #[must_use]
+    pub fn work_library(&self) -> Option<NodeId<Library>> {
+        self.work_library
+    }
+
+    fn lookup_work(&self, identifier: &NormalizedIdentifier) -> Vec<NamedEntityNodeId> {
+        match self.work_library {
+            Some(library_id) if *identifier == *"work" => vec![library_id.into()],
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Collects the scope at `id`, see [`Ast::scope`].
+pub(crate) fn scope(ast: &Ast, id: GenericNodeId) -> Scope<'_> {
+    let root = ast.ancestors(id).last().unwrap_or(id);
+    let work_library = NodeId::<Library>::from_raw(root.to_raw());
+    let mut builder = ScopeBuilder {
+        scope: Scope {
+            ast,
+            regions: Vec::new(),
+            used: Vec::new(),
+            libraries: Vec::new(),
+            work_library: work_library.try_get(ast).is_ok().then_some(work_library),
+        },
+        applied_contexts: Set::default(),
+    };
+
+    let mut child = None;
+    for region_id in iter::once(id).chain(ast.ancestors(id)) {
+        builder.enter(region_id, child);
+        child = Some(region_id);
+    }
+
+    // Every design unit is preceded by an implicit `library std, work; use std.standard.all;`
+    // (LRM §13.2).
+    if let Some(std_id) = ast.lookup_library(&NormalizedIdentifier::static_normalized("std")) {
+        builder.scope.libraries.push(std_id);
+        if let Some(standard_id) = ast
+            .lookup_package_declaration(std_id, NormalizedIdentifier::static_normalized("standard"))
+        {
+            builder.use_all(standard_id.into());
+        }
+    }
+    builder.scope
+}
+
+struct ScopeBuilder<'ast> {
+    scope: Scope<'ast>,
+    /// Context declarations whose items were already applied, guarding against reference cycles.
+    applied_contexts: Set<NodeId<ContextDeclaration>>,
+}
+
+impl ScopeBuilder<'_> {
+    /// Adds the declarative region of the node `id`, if any.
+    ///
+    /// `child` is the ancestor of the scope's node which is a direct child of `id`; when it's
+    /// a declaration of the region, later declarations of the region aren't visible yet.
+    fn enter(&mut self, id: GenericNodeId, child: Option<GenericNodeId>) {
+        let ast = self.scope.ast;
+        let Some(node) = ast.nodes.get(id.to_raw().get() as usize) else {
+            return;
+        };
+        match node {
+            Node::EntityDeclaration(entity) => self.enter_entity(entity, child),
+            Node::ArchitectureBody(architecture) => {
+                self.add_region(ids(&architecture.declarations), child);
+                let entity_id: Option<NodeId<EntityDeclaration>> = architecture
+                    .entity_name
+                    .try_get(ast)
+                    .ok()
+                    .and_then(|name| name.named_entity())
+                    .and_then(|named_entity| named_entity.try_downcast(ast).ok());
+                if let Some(entity) = entity_id.and_then(|entity_id| entity_id.try_get(ast).ok()) {
+                    self.enter_entity(entity, None);
+                }
+            },
+            Node::PackageDeclaration(package) => self.enter_package(package, child),
+            Node::PackageBody(package_body) => {
+                self.add_region(ids(&package_body.declarations), child);
+                if let Some(package) = package_body
+                    .package
+                    .and_then(|package_id| package_id.try_get(ast).ok())
+                {
+                    self.enter_package(package, None);
+                }
+            },
+            Node::BlockStatement(block) => {
+                let mut items = Vec::new();
+                if let Some(header) = block
+                    .block_header
+                    .and_then(|header_id| header_id.try_get(ast).ok())
+                {
+                    items.extend(ids(&header.generics));
+                    items.extend(ids(&header.ports));
+                }
+                items.extend(block.guard_decl.map(GenericNodeId::from));
+                items.extend(ids(&block.declarations));
+                self.add_region(items, child);
+            },
+            Node::ProcessStatement(process) => self.add_region(ids(&process.declarations), child),
+            Node::SensitizedProcessStatement(process) => {
+                self.add_region(ids(&process.declarations), child);
+            },
+            Node::FunctionBody(body) => {
+                let mut items = Vec::new();
+                if let Ok(specification) = body.subprogram_specification.try_get(ast) {
+                    items.extend(ids(&specification.generics));
+                    items.extend(ids(&specification.interface_declarations));
+                }
+                items.extend(ids(&body.declarations));
+                self.add_region(items, child);
+            },
+            Node::ProcedureBody(body) => {
+                let mut items = Vec::new();
+                if let Ok(specification) = body.subprogram_specification.try_get(ast) {
+                    items.extend(ids(&specification.generics));
+                    items.extend(ids(&specification.interface_declarations));
+                }
+                items.extend(ids(&body.declarations));
+                self.add_region(items, child);
+            },
+            Node::ForGenerateStatement(statement) => {
+                self.add_region(vec![statement.parameter_specification.into()], child);
+            },
+            Node::ForLoopStatement(statement) => {
+                self.add_region(vec![statement.parameter_specification.into()], child);
+            },
+            Node::GenerateStatementBody(body) => self.add_region(ids(&body.declarations), child),
+            Node::DesignUnit(design_unit) => self.apply_context_items(&design_unit.context_items),
+            _ => {},
+        }
+    }
+
+    /// Adds the region of an entity and the context clause of its design unit.
+    fn enter_entity(&mut self, entity: &EntityDeclaration, child: Option<GenericNodeId>) {
+        let mut items = ids(&entity.generics);
+        items.extend(ids(&entity.ports));
+        items.extend(ids(&entity.declarations));
+        self.add_region(items, child);
+        if child.is_none() {
+            self.apply_design_unit_context(entity.design_unit);
+        }
+    }
+
+    /// Adds the region of a package and the context clause of its design unit.
+    fn enter_package(&mut self, package: &PackageDeclaration, child: Option<GenericNodeId>) {
+        let ast = self.scope.ast;
+        let mut items = Vec::new();
+        if let Some(header) = package
+            .package_header
+            .and_then(|header_id| header_id.try_get(ast).ok())
+        {
+            items.extend(ids(&header.generics));
+        }
+        items.extend(ids(&package.declarations));
+        self.add_region(items, child);
+        if child.is_none() {
+            self.apply_design_unit_context(package.design_unit);
+        }
+    }
+
+    /// Adds a region with the given declarative items, up to and including `child`.
+    fn add_region(&mut self, mut items: Vec<GenericNodeId>, child: Option<GenericNodeId>) {
+        if let Some(position) = child.and_then(|child| items.iter().position(|&id| id == child)) {
+            items.truncate(position + 1);
+        }
+        let ast = self.scope.ast;
+        let mut region = Vec::new();
+        for id in items {
+            if let Ok(use_clause) = NodeId::<UseClause>::from_raw(id.to_raw()).try_get(ast) {
+                self.apply_use_clause(use_clause);
+            } else {
+                declared_entities(ast, id, &mut region);
+            }
+        }
+        self.scope.regions.push(region);
+    }
+
+    fn apply_design_unit_context(&mut self, design_unit_id: NodeId<DesignUnit>) {
+        if let Ok(design_unit) = design_unit_id.try_get(self.scope.ast) {
+            self.apply_context_items(&design_unit.context_items);
+        }
+    }
+
+    fn apply_context_items(&mut self, context_items: &[ContextItemNodeId]) {
+        let ast = self.scope.ast;
+        for context_item in context_items {
+            match context_item.try_get(ast) {
+                Ok(ContextItem::LibraryClause(library_clause)) => {
+                    if let Some(library_id) =
+                        ast.lookup_library(&library_clause.identifier.normalized)
+                    {
+                        self.scope.libraries.push(library_id);
+                    }
+                },
+                Ok(ContextItem::UseClause(use_clause)) => self.apply_use_clause(use_clause),
+                Ok(ContextItem::ContextReference(context_reference)) => {
+                    let context_id: Option<NodeId<ContextDeclaration>> = context_reference
+                        .selected_name
+                        .and_then(|name_id| selected_entity(ast, name_id))
+                        .and_then(|named_entity| named_entity.try_downcast(ast).ok());
+                    if let Some(context_id) = context_id
+                        && self.applied_contexts.insert(context_id)
+                        && let Ok(context) = context_id.try_get(ast)
+                    {
+                        self.apply_context_items(&context.context_items);
+                    }
+                },
+                Err(_) => {},
+            }
+        }
+    }
+
+    fn apply_use_clause(&mut self, use_clause: &UseClause) {
+        let ast = self.scope.ast;
+        let Some(named_entity) = selected_entity(ast, use_clause.selected_name) else {
+            return;
+        };
+        match use_clause.selected_name.get(ast) {
+            AnySelectedName::SelectedName(_) => self.scope.used.push(named_entity),
+            AnySelectedName::SelectedByAllName(_) => self.use_all(named_entity),
+        }
+    }
+
+    /// Makes all declarations of a package, or all primary units of a library, potentially visible.
+    fn use_all(&mut self, named_entity: NamedEntityNodeId) {
+        let ast = self.scope.ast;
+        let used = &mut self.scope.used;
+        match named_entity.try_get(ast) {
+            Ok(NamedEntity::PackageDeclaration(package)) => {
+                for id in ids(&package.declarations) {
+                    declared_entities(ast, id, used);
+                }
+            },
+            Ok(NamedEntity::PackageInstantiationDeclaration(package)) => {
+                for id in ids(&package.declarations) {
+                    declared_entities(ast, id, used);
+                }
+            },
+            Ok(NamedEntity::Library(library)) => {
+                used.extend(
+                    library
+                        .library_units_iter(ast)
+                        .filter_map(|(library_unit_id, _)| {
+                            library_unit_id
+                                .downcast_subset::<NamedEntityNodeId>(ast)
+                                .ok()
+                        }),
+                );
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Converts a list of node IDs into generic IDs.
+fn ids<T>(items: &[T]) -> Vec<GenericNodeId>
+where
+    for<'item> &'item T: Into<GenericNodeId>,
+{
+    items.iter().map(Into::into).collect()
+}
+
+/// Appends the named entities declared by the declarative item `id` to `entities`.
+///
+/// Besides the declaration itself, a type declaration declares its enumeration literals or
+/// physical units.
+fn declared_entities(ast: &Ast, id: GenericNodeId, entities: &mut Vec<NamedEntityNodeId>) {
+    if let Ok(declaration) = NodeId::<AnonymousTypeDeclaration>::from_raw(id.to_raw()).try_get(ast)
+    {
+        if let Ok(AnonymousTypeDefinition::Physical(physical)) =
+            declaration.type_definition.try_get(ast)
+        {
+            entities.extend(physical.units.iter().copied().map(NamedEntityNodeId::from));
+        }
+        return;
+    }
+    let named_entity_id = NamedEntityNodeId::from(id.to_raw());
+    match named_entity_id.try_get(ast) {
+        Ok(NamedEntity::TypeDeclaration(declaration)) => {
+            entities.push(named_entity_id);
+            if let Ok(TypeDefinition::Enumeration(enumeration)) =
+                declaration.type_definition.try_get(ast)
+            {
+                entities.extend(
+                    enumeration
+                        .enumeration_literal_list
+                        .iter()
+                        .copied()
+                        .map(NamedEntityNodeId::from),
+                );
+            }
+        },
+        Ok(NamedEntity::Unresolved(_)) | Err(_) => {},
+        Ok(_) => entities.push(named_entity_id),
+    }
+}
+
+/// Returns the named entity denoted by a selected name, or the prefix of a `.all` name.
+fn selected_entity(ast: &Ast, name_id: AnySelectedNameNodeId) -> Option<NamedEntityNodeId> {
+    match name_id.try_get(ast).ok()? {
+        AnySelectedName::SelectedName(selected_name) => Some(selected_name.named_entity),
+        AnySelectedName::SelectedByAllName(selected_by_all_name) => selected_by_all_name
+            .prefix
+            .downcast_subset::<NameNodeId>(ast)
+            .ok()?
+            .get(ast)
+            .named_entity(),
+    }
+}
+
+/// Returns whether declarations of the kind of `id` can be overloaded (LRM §4.5).
+fn is_overloadable(ast: &Ast, id: NamedEntityNodeId) -> bool {
+    matches!(
+        id.try_get(ast),
+        Ok(NamedEntity::FunctionDeclaration(_)
+            | NamedEntity::ProcedureDeclaration(_)
+            | NamedEntity::FunctionInstantiationDeclaration(_)
+            | NamedEntity::ProcedureInstantiationDeclaration(_)
+            | NamedEntity::InterfaceFunctionDeclaration(_)
+            | NamedEntity::InterfaceProcedureDeclaration(_)
+            | NamedEntity::EnumerationLiteral(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+    use crate::tests::load_fixture;
+
+    /// Chunk following [`FIXTURE`] with a package, an entity using it and an architecture of that entity.
+    const SCOPE_CHUNK: &str = r#"{"first_id":20,"last_id":40,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"dut.vhd","start":20,"end":41}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22,28,34]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"package_declaration":{"id":23,"identifier":["pkg",null],"parent":22,"declarations":[24,27]}}
+{"type_declaration":{"identifier":["state",null],"type_definition":25}}
+{"enumeration_type_definition":{"enumeration_literal_list":[26]}}
+{"enumeration_literal":{"enum_pos":0,"identifier":["idle",null]}}
+{"constant_declaration":{"identifier":["width",null],"type":10}}
+{"design_unit":{"library_unit":29,"design_file":21,"context_items":[30]}}
+{"entity_declaration":{"id":29,"identifier":["dut",null],"parent":28}}
+{"use_clause":{"selected_name":31}}
+{"selected_by_all_name":{"prefix":32}}
+{"selected_name":{"identifier":["pkg",null],"named_entity":23,"prefix":33}}
+{"simple_name":{"identifier":["work",null],"named_entity":20}}
+{"design_unit":{"library_unit":35,"design_file":21}}
+{"architecture_body":{"identifier":["rtl",null],"entity_name":36,"parent":34,"declarations":[37,38],"concurrent_statements":[39]}}
+{"simple_name":{"identifier":["dut",null],"named_entity":29}}
+{"signal_declaration":{"identifier":["width",null],"type":10}}
+{"signal_declaration":{"identifier":["late",null],"type":10}}
+{"process_statement":{"label":["p",null],"postponed_flag":false,"declarations":[40]}}
+{"variable_declaration":{"identifier":["v",null],"type":10}}
+
+"#;
+
+    fn load_scope_chunk() -> Ast {
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut SCOPE_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        builder.finish()
+    }
+
+    fn node(raw: u32) -> GenericNodeId {
+        GenericNodeId::from_raw(IdPrimitive::new(raw).unwrap())
+    }
+
+    fn lookup(scope: &Scope<'_>, name: &str) -> Vec<u32> {
+        scope
+            .lookup(&NormalizedIdentifier::new(name))
+            .into_iter()
+            .map(|id| id.id_primitive().get())
+            .collect()
+    }
+
+    #[test]
+    fn test_scope_of_statement() {
+        let ast = load_fixture();
+        let scope = ast.scope(node(15));
+        assert_eq!(lookup(&scope, "clk"), [9]);
+        assert_eq!(lookup(&scope, "work"), [1]);
+        assert!(lookup(&scope, "top").is_empty());
+    }
+
+    #[test]
+    fn test_scope_use_clause() {
+        let ast = load_scope_chunk();
+
+        // The architecture extends the entity, whose context clause uses `work.pkg.all`.
+        let in_process = ast.scope(node(39));
+        assert_eq!(lookup(&in_process, "v"), [40]);
+        assert_eq!(lookup(&in_process, "width"), [37]);
+        assert_eq!(lookup(&in_process, "state"), [24]);
+        assert_eq!(lookup(&in_process, "idle"), [26]);
+        assert_eq!(lookup(&in_process, "work"), [20]);
+        assert!(lookup(&in_process, "lib").is_empty());
+        assert!(lookup(&in_process, "clk").is_empty());
+        assert_eq!(
+            in_process.work_library().map(GenericNodeId::from),
+            Some(node(20))
+        );
+        assert!(lookup(&ast.scope(node(15)), "idle").is_empty());
+    }
+
+    #[test]
+    fn test_scope_declaration_order() {
+        let ast = load_scope_chunk();
+        assert_eq!(lookup(&ast.scope(node(39)), "late"), [38]);
+
+        let in_declaration = ast.scope(node(37));
+        assert!(lookup(&in_declaration, "late").is_empty());
+        assert!(lookup(&in_declaration, "v").is_empty());
+    }
+
+    #[test]
+    fn test_scope_of_package() {
+        let ast = load_scope_chunk();
+        assert_eq!(lookup(&ast.scope(node(23)), "width"), [27]);
+    }
+}
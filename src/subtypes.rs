@@ -0,0 +1,336 @@
+//! Resolution of subtype chains to base types and type marks (LRM §5.1).
+//!
+//! Every subtype definition refers to the type or subtype it constrains
+//! through its `parent_type`, ending at an anonymous or named base type
+//! definition. Incomplete type definitions are replaced by their completion.
+
+use super::*;
+
+/// Declaration of a type mark, see [`Ast::type_declaration_of`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TypeMark {
+    /// Type declaration, e.g. of an enumeration, record or access type.
+    Type(NodeId<TypeDeclaration>),
+    /// Subtype declaration, including the first subtype of an integer, floating or physical type.
+    Subtype(NodeId<SubtypeDeclaration>),
+    /// Generic type of an interface list.
+    InterfaceType(NodeId<InterfaceTypeDeclaration>),
+}
+
+/// Returns the base type of a type or subtype, see [`Ast::base_type`].
+pub(crate) fn base_type(
+    ast: &Ast,
+    definition: TypeAndSubtypeDefinitionNodeId,
+) -> Result<TypeAndSubtypeDefinitionNodeId> {
+    let mut current = definition;
+    // A well-formed chain visits every node at most once.
+    for _ in 0..=ast.nodes.len() {
+        match parent_type(ast, current)? {
+            Some(parent) => current = parent,
+            None => return Ok(current),
+        }
+    }
+    bail!("subtype chain of {definition} is cyclic")
+}
+
+/// Returns the declaration naming a type or subtype, see [`Ast::type_declaration_of`].
+pub(crate) fn type_declaration_of(
+    ast: &Ast,
+    definition: TypeAndSubtypeDefinitionNodeId,
+) -> Option<TypeMark> {
+    let mut current = definition;
+    for _ in 0..=ast.nodes.len() {
+        if let Some(type_mark) = declaring_type_mark(ast, current) {
+            return Some(type_mark);
+        }
+        if let Some(type_mark) = subtype_type_mark(current.try_get(ast).ok()?)
+            .and_then(|name_id| name_id.try_get(ast).ok()?.named_entity())
+            .and_then(|named_entity| to_type_mark(ast, named_entity.into()))
+        {
+            return Some(type_mark);
+        }
+        current = parent_type(ast, current).ok()??;
+    }
+    None
+}
+
+/// Returns whether `subtype` is `of` or derived from it through its chain of parent types.
+pub(crate) fn is_subtype_of(
+    ast: &Ast,
+    subtype: TypeAndSubtypeDefinitionNodeId,
+    of: TypeAndSubtypeDefinitionNodeId,
+) -> bool {
+    let mut current = subtype;
+    for _ in 0..=ast.nodes.len() {
+        if current == of {
+            return true;
+        }
+        match parent_type(ast, current) {
+            Ok(Some(parent)) => current = parent,
+            Ok(None) | Err(_) => return false,
+        }
+    }
+    false
+}
+
+/// Returns the type designated by an access type or subtype, see [`Ast::designated_type`].
+pub(crate) fn designated_type(
+    ast: &Ast,
+    access: TypeAndSubtypeDefinitionNodeId,
+) -> Result<TypeAndSubtypeDefinitionNodeId> {
+    let mut current = access;
+    for _ in 0..=ast.nodes.len() {
+        let (designated, designated_subtype_indication, parent) = match current.try_get(ast)? {
+            TypeAndSubtypeDefinition::AccessType(access_type) => (
+                access_type.designated_type,
+                access_type.designated_subtype_indication,
+                None,
+            ),
+            TypeAndSubtypeDefinition::AccessSubtype(access_subtype) => (
+                access_subtype.designated_type,
+                access_subtype.designated_subtype_indication,
+                access_subtype.parent_type,
+            ),
+            definition => bail!("{} {current} is not an access type", definition.type_str()),
+        };
+        if let Some(designated) =
+            designated.or(designated_subtype_indication.map(TypeAndSubtypeDefinitionNodeId::from))
+        {
+            return match designated.try_get(ast)? {
+                TypeAndSubtypeDefinition::IncompleteType(incomplete) => {
+                    complete_type(ast, designated, incomplete)
+                },
+                _ => Ok(designated),
+            };
+        }
+        let Some(parent) = parent else {
+            bail!("access type {current} has no designated type");
+        };
+        current = parent;
+    }
+    bail!("subtype chain of {access} is cyclic")
+}
+
+/// Returns the type or subtype `definition` is derived from, or `None` for base types.
+fn parent_type(
+    ast: &Ast,
+    definition: TypeAndSubtypeDefinitionNodeId,
+) -> Result<Option<TypeAndSubtypeDefinitionNodeId>> {
+    use TypeAndSubtypeDefinition::*;
+
+    let node = definition.try_get(ast)?;
+    let parent = match node {
+        IntegerSubtype(subtype) => subtype.parent_type,
+        FloatingSubtype(subtype) => subtype.parent_type,
+        PhysicalSubtype(subtype) => Some(subtype.parent_type.into()),
+        EnumerationSubtype(subtype) => subtype.parent_type,
+        ArraySubtype(subtype) => subtype.parent_type,
+        RecordSubtype(subtype) => subtype.parent_type,
+        AccessSubtype(subtype) => subtype.parent_type,
+        FileSubtype(subtype) => subtype.parent_type,
+        IncompleteType(incomplete) => return complete_type(ast, definition, incomplete).map(Some),
+        IntegerType(_) | FloatingType(_) | PhysicalType(_) | EnumerationType(_) | ArrayType(_)
+        | RecordType(_) | AccessType(_) | FileType(_) | WildcardType(_) => return Ok(None),
+    };
+    match parent {
+        Some(parent) => Ok(Some(parent)),
+        None => bail!("{} {definition} has no parent type", node.type_str()),
+    }
+}
+
+/// Returns the completion of an incomplete type definition.
+///
+/// GHDL redirects the designated type of the access types referring to an
+/// incomplete type once it's completed, so those are consulted if the
+/// completion isn't recorded on the incomplete type itself.
+fn complete_type(
+    ast: &Ast,
+    definition: TypeAndSubtypeDefinitionNodeId,
+    incomplete: &IncompleteTypeDefinition,
+) -> Result<TypeAndSubtypeDefinitionNodeId> {
+    if let Some(complete) = incomplete.complete_type_definition {
+        return Ok(complete);
+    }
+    let Some(complete) = incomplete
+        .incomplete_type_refs
+        .iter()
+        .filter_map(|access_id| access_id.try_get(ast).ok()?.designated_type)
+        .find(|&designated| designated != definition)
+    else {
+        bail!("incomplete type {definition} is never completed");
+    };
+    Ok(complete)
+}
+
+/// Returns the type mark of a subtype definition, if any.
+fn subtype_type_mark(definition: TypeAndSubtypeDefinition<'_>) -> Option<NameNodeId> {
+    use TypeAndSubtypeDefinition::*;
+
+    match definition {
+        IntegerSubtype(subtype) => subtype.subtype_type_mark,
+        FloatingSubtype(subtype) => subtype.subtype_type_mark,
+        EnumerationSubtype(subtype) => subtype.subtype_type_mark,
+        ArraySubtype(subtype) => subtype.subtype_type_mark,
+        RecordSubtype(subtype) => subtype.subtype_type_mark,
+        AccessSubtype(subtype) => subtype.subtype_type_mark,
+        FileSubtype(subtype) => subtype.subtype_type_mark,
+        _ => None,
+    }
+}
+
+/// Returns the declaration which owns `definition` as the type or subtype it declares.
+fn declaring_type_mark(ast: &Ast, definition: TypeAndSubtypeDefinitionNodeId) -> Option<TypeMark> {
+    let parent = ast.parent(definition)?;
+    let definition = GenericNodeId::from(definition);
+    match ast.nodes.get(parent.to_raw().get() as usize)? {
+        Node::TypeDeclaration(declaration)
+            if GenericNodeId::from(declaration.type_definition) == definition =>
+        {
+            Some(TypeMark::Type(NodeId::from_raw(parent.to_raw())))
+        },
+        Node::SubtypeDeclaration(declaration)
+            if GenericNodeId::from(declaration.subtype_indication) == definition =>
+        {
+            Some(TypeMark::Subtype(NodeId::from_raw(parent.to_raw())))
+        },
+        // The anonymous base type and its first subtype are named by the subtype
+        // declaration following the anonymous type declaration in the same region.
+        Node::AnonymousTypeDeclaration(declaration) => {
+            let first_subtype = GenericNodeId::from(declaration.subtype_definition?);
+            let region = ast.parent(parent)?;
+            ast.nodes
+                .get(region.to_raw().get() as usize)?
+                .children()
+                .find_map(|(_, id)| match ast.nodes.get(id.to_raw().get() as usize)? {
+                    Node::SubtypeDeclaration(subtype_declaration)
+                        if GenericNodeId::from(subtype_declaration.subtype_indication)
+                            == first_subtype =>
+                    {
+                        Some(TypeMark::Subtype(NodeId::from_raw(id.to_raw())))
+                    },
+                    _ => None,
+                })
+        },
+        _ => None,
+    }
+}
+
+/// Returns the type mark of the declaration `id`, if it declares a type or subtype.
+fn to_type_mark(ast: &Ast, id: GenericNodeId) -> Option<TypeMark> {
+    let declaration_id = id.to_raw();
+    match ast.nodes.get(declaration_id.get() as usize)? {
+        Node::TypeDeclaration(_) => Some(TypeMark::Type(NodeId::from_raw(declaration_id))),
+        Node::SubtypeDeclaration(_) => Some(TypeMark::Subtype(NodeId::from_raw(declaration_id))),
+        Node::InterfaceTypeDeclaration(_) => {
+            Some(TypeMark::InterfaceType(NodeId::from_raw(declaration_id)))
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+
+    /// Chunk following [`FIXTURE`] with a package declaring derived, incomplete and access types:
+    ///
+    /// ```vhdl
+    /// package pkg is
+    ///   type my_int is range 0 to 1;
+    ///   subtype nibble is my_int range 0 to 1;
+    ///   type node_t;
+    ///   type ptr_t is access node_t;
+    ///   type node_t is record end record;
+    ///   signal x : nibble range 0 to 1;
+    /// end package;
+    /// ```
+    const TYPES_CHUNK: &str = r#"{"first_id":20,"last_id":39,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"types.vhd","start":20,"end":40}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"package_declaration":{"id":23,"identifier":["pkg",null],"parent":22,"declarations":[24,27,28,31,33,35,37]}}
+{"anonymous_type_declaration":{"type_definition":25,"subtype_definition":26}}
+{"integer_type_definition":{}}
+{"integer_subtype_definition":{"range_constraint":11,"parent_type":25}}
+{"subtype_declaration":{"identifier":["my_int",null],"subtype_indication":26}}
+{"subtype_declaration":{"identifier":["nibble",null],"subtype_indication":29}}
+{"integer_subtype_definition":{"range_constraint":11,"parent_type":26,"subtype_type_mark":30}}
+{"simple_name":{"identifier":["my_int",null],"named_entity":27}}
+{"type_declaration":{"identifier":["node_t",null],"type_definition":32}}
+{"incomplete_type_definition":{"incomplete_type_refs":[34]}}
+{"type_declaration":{"identifier":["ptr_t",null],"type_definition":34}}
+{"access_type_definition":{"designated_type":36}}
+{"type_declaration":{"identifier":["node_t",null],"type_definition":36}}
+{"record_type_definition":{}}
+{"signal_declaration":{"identifier":["x",null],"type":38,"subtype_indication":38}}
+{"integer_subtype_definition":{"range_constraint":11,"parent_type":29,"subtype_type_mark":39}}
+{"simple_name":{"identifier":["nibble",null],"named_entity":28}}
+
+"#;
+
+    fn load_types_chunk() -> Ast {
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut TYPES_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        builder.finish()
+    }
+
+    fn definition(raw: u32) -> TypeAndSubtypeDefinitionNodeId {
+        TypeAndSubtypeDefinitionNodeId::from(IdPrimitive::new(raw).unwrap())
+    }
+
+    #[test]
+    fn test_base_type() {
+        let ast = load_types_chunk();
+        for raw in [25, 26, 29, 38] {
+            assert_eq!(ast.base_type(definition(raw)).unwrap(), definition(25));
+        }
+        assert_eq!(ast.base_type(definition(32)).unwrap(), definition(36));
+        assert_eq!(ast.base_type(definition(34)).unwrap(), definition(34));
+        let error = ast.base_type(definition(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "IntegerSubtypeDefinition 10 has no parent type"
+        );
+    }
+
+    #[test]
+    fn test_type_declaration_of() {
+        let ast = load_types_chunk();
+        let subtype = |raw| TypeMark::Subtype(NodeId::from_raw(IdPrimitive::new(raw).unwrap()));
+        let typ = |raw| TypeMark::Type(NodeId::from_raw(IdPrimitive::new(raw).unwrap()));
+
+        // The anonymous base type and its first subtype are named by the subtype declaration.
+        assert_eq!(ast.type_declaration_of(definition(25)), Some(subtype(27)));
+        assert_eq!(ast.type_declaration_of(definition(26)), Some(subtype(27)));
+        assert_eq!(ast.type_declaration_of(definition(29)), Some(subtype(28)));
+        // The anonymous subtype of a signal is named by its type mark.
+        assert_eq!(ast.type_declaration_of(definition(38)), Some(subtype(28)));
+        assert_eq!(ast.type_declaration_of(definition(32)), Some(typ(31)));
+        assert_eq!(ast.type_declaration_of(definition(34)), Some(typ(33)));
+        assert_eq!(ast.type_declaration_of(definition(36)), Some(typ(35)));
+        assert_eq!(ast.type_declaration_of(definition(10)), None);
+    }
+
+    #[test]
+    fn test_is_subtype_of() {
+        let ast = load_types_chunk();
+        assert!(ast.is_subtype_of(definition(38), definition(38)));
+        assert!(ast.is_subtype_of(definition(38), definition(29)));
+        assert!(ast.is_subtype_of(definition(38), definition(26)));
+        assert!(ast.is_subtype_of(definition(32), definition(36)));
+        assert!(!ast.is_subtype_of(definition(26), definition(29)));
+        assert!(!ast.is_subtype_of(definition(38), definition(36)));
+    }
+
+    #[test]
+    fn test_designated_type() {
+        let ast = load_types_chunk();
+        assert_eq!(ast.designated_type(definition(34)).unwrap(), definition(36));
+        ast.designated_type(definition(36)).unwrap_err();
+    }
+}
@@ -0,0 +1,149 @@
+//! Interning of normalized identifiers.
+//!
+//! While loading, the normalized text of every [`Identifier`] in the AST is
+//! interned in the [`SymbolTable`] of the [`Ast`]. Comparing and hashing the
+//! resulting [`Symbol`]s is a matter of comparing and hashing integers.
+
+use indexmap::IndexSet;
+use rustc_hash::FxBuildHasher;
+
+use super::*;
+
+/// Interned [`NormalizedIdentifier`], unique within an [`Ast`].
+///
+/// Symbols of different ASTs can't be compared meaningfully.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the index of this symbol in its [`SymbolTable`].
+    #[must_use]
+    pub fn to_raw(self) -> u32 {
+        self.0
+    }
+
+    /// Creates the symbol of the identifier at the given index of a [`SymbolTable`].
+    fn from_index(index: usize) -> Self {
+        #[expect(
+            clippy::cast_possible_truncation,
+            reason = "the number of identifiers is bounded by the number of nodes, which fits in u32"
+        )]
+        Self(index as u32)
+    }
+}
+
+/// Table of the normalized identifiers of an [`Ast`], see [`Ast::symbols`].
+///
+/// Each identifier is stored once, and the lookup maps of the [`Ast`] are keyed
+/// by symbols instead of copies of the names. Symbols are never removed, so a
+/// symbol stays valid when further chunks are appended to the AST.
+#[derive(Debug, Default)]
+pub struct SymbolTable {
+    /// Interned identifiers, indexed by symbol.
+    identifiers: IndexSet<NormalizedIdentifier, FxBuildHasher>,
+}
+
+impl SymbolTable {
+    /// Returns the symbol of the given identifier, or `None` if it doesn't occur in the AST.
+    #[must_use]
+    pub fn get(&self, identifier: &str) -> Option<Symbol> {
+        self.identifiers
+            .get_index_of(identifier)
+            .map(Symbol::from_index)
+    }
+
+    /// Returns the identifier of the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol belongs to another, larger table.
+    #[must_use]
+    pub fn resolve(&self, symbol: Symbol) -> &NormalizedIdentifier {
+        #[expect(
+            clippy::indexing_slicing,
+            reason = "symbols are only created by this table"
+        )]
+        &self.identifiers[symbol.0 as usize]
+    }
+
+    /// Returns the number of symbols.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.identifiers.len()
+    }
+
+    /// Returns whether the table has no symbols.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.identifiers.is_empty()
+    }
+
+    /// Returns the symbol of the given normalized identifier, adding it to the table if necessary.
+    pub(crate) fn intern(&mut self, normalized: &str) -> Symbol {
+        let index = self
+            .identifiers
+            .get_index_of(normalized)
+            .unwrap_or_else(|| {
+                let identifier = NormalizedIdentifier(CompactString::new(normalized));
+                self.identifiers.insert_full(identifier).0
+            });
+        Symbol::from_index(index)
+    }
+}
+
+impl Serialize for SymbolTable {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(&self.identifiers)
+    }
+}
+
+impl<'de> Deserialize<'de> for SymbolTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let identifiers = Vec::<NormalizedIdentifier>::deserialize(deserializer)?;
+        Ok(Self {
+            identifiers: identifiers.into_iter().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::load_fixture;
+
+    #[test]
+    fn test_symbols() {
+        let ast = load_fixture();
+        let id = |raw| GenericNodeId::from_raw(IdPrimitive::new(raw).unwrap());
+
+        let clk = ast.symbol(id(9)).unwrap();
+        assert_eq!(ast.symbol(id(16)), Some(clk));
+        assert_eq!(ast.symbol(id(17)), Some(clk));
+        assert_eq!(ast.symbols().resolve(clk), "clk");
+        assert_eq!(ast.symbols().get("clk"), Some(clk));
+        assert_eq!(ast.symbol(id(5)), ast.symbol(id(8)));
+        assert_eq!(ast.symbol(id(14)), None);
+        assert_eq!(ast.symbols().get("missing"), None);
+        // work, top, rtl, clk
+        assert_eq!(ast.symbols().len(), 4);
+
+        let work = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap();
+        assert!(
+            ast.lookup_entity_declaration(work, NormalizedIdentifier::new("top"))
+                .is_some()
+        );
+        assert!(
+            ast.lookup_entity_declaration(work, NormalizedIdentifier::new("clk"))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Discovery of the top-level design units of an [`Ast`].
+
+use super::*;
+
+/// Libraries which never contain top-level units: the standard and IEEE libraries.
+pub(crate) const STANDARD_LIBRARIES: [&str; 2] = ["std", "ieee"];
+
+/// Options for [`Ast::top_level_candidates`].
+#[derive(Clone, Debug)]
+pub struct TopLevelOptions {
+    /// Libraries whose units are neither candidates nor count as instantiating other units.
+    ///
+    /// Defaults to `std` and `ieee`; add vendor and verification libraries such as `unisim`,
+    /// `vunit_lib` or `osvvm` as needed.
+    pub ignored_libraries: Vec<NormalizedIdentifier>,
+}
+
+impl Default for TopLevelOptions {
+    fn default() -> Self {
+        Self {
+            ignored_libraries: STANDARD_LIBRARIES
+                .into_iter()
+                .map(NormalizedIdentifier::static_normalized)
+                .collect(),
+        }
+    }
+}
+
+/// A design unit which may be elaborated as the root of a design hierarchy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TopLevelCandidate {
+    /// An entity which isn't instantiated by any unit.
+    Entity(NodeId<EntityDeclaration>),
+    /// A configuration which isn't used as the entity aspect of any unit.
+    Configuration(NodeId<ConfigurationDeclaration>),
+}
+
+/// Finds the top-level candidates of `ast`, see [`Ast::top_level_candidates`].
+pub(crate) fn top_level_candidates(
+    ast: &Ast,
+    library_id: Option<NodeId<Library>>,
+    options: &TopLevelOptions,
+) -> Vec<TopLevelCandidate> {
+    let ignored_libraries = options
+        .ignored_libraries
+        .iter()
+        .filter_map(|name| ast.lookup_library(name))
+        .collect::<Set<_>>();
+    let library_of = |id: GenericNodeId| {
+        let root = ast.ancestors(id).last().unwrap_or(id);
+        NodeId::<Library>::from_raw(root.to_raw())
+    };
+    let is_ignored = |id: GenericNodeId| ignored_libraries.contains(&library_of(id));
+
+    let mut instantiated_entities = Set::default();
+    let mut instantiated_configurations = Set::default();
+
+    for (aspect_id, aspect) in ast.nodes_of::<EntityAspectEntity>() {
+        if !is_ignored(aspect_id.into())
+            && let Some(entity_id) = resolve::<EntityDeclaration>(ast, aspect.entity_name)
+        {
+            instantiated_entities.insert(entity_id);
+        }
+    }
+
+    for (aspect_id, aspect) in ast.nodes_of::<EntityAspectConfiguration>() {
+        if !is_ignored(aspect_id.into())
+            && let Some(configuration_id) =
+                resolve::<ConfigurationDeclaration>(ast, aspect.configuration_name)
+        {
+            instantiated_configurations.insert(configuration_id);
+            let configuration = configuration_id.get(ast);
+            if let Some(entity_id) = configuration
+                .entity_name
+                .and_then(|entity_name| resolve::<EntityDeclaration>(ast, entity_name))
+            {
+                instantiated_entities.insert(entity_id);
+            }
+        }
+    }
+
+    // Instantiations of components are bound by default to the entity of the same name
+    // in the library of the instantiating unit (LRM §7.3.3).
+    for (statement_id, statement) in ast.nodes_of::<ComponentInstantiationStatement>() {
+        if is_ignored(statement_id.into()) {
+            continue;
+        }
+        let Ok(name) = Name::try_from(statement.instantiated_unit.get(ast)) else {
+            continue;
+        };
+        let component_id: Option<NodeId<ComponentDeclaration>> = name
+            .named_entity()
+            .and_then(|named_entity| named_entity.try_downcast(ast).ok());
+        if let Some(component_id) = component_id
+            && let Some(symbol) = ast.symbol(component_id)
+            && let Some(&entity_id) = ast
+                .entity_declarations
+                .get(&(library_of(statement_id.into()), symbol))
+        {
+            instantiated_entities.insert(entity_id);
+        }
+    }
+
+    let library_ids = match library_id {
+        Some(library_id) => vec![library_id],
+        None => ast
+            .library_ids
+            .iter()
+            .copied()
+            .filter(|id| !ignored_libraries.contains(id))
+            .collect(),
+    };
+    let mut candidates = Vec::new();
+    for id in library_ids {
+        let Ok(library) = id.try_get(ast) else {
+            continue;
+        };
+        for (library_unit_id, library_unit) in library.library_units_iter(ast) {
+            match library_unit {
+                LibraryUnit::EntityDeclaration(_) => {
+                    let entity_id = library_unit_id.downcast();
+                    if !instantiated_entities.contains(&entity_id) {
+                        candidates.push(TopLevelCandidate::Entity(entity_id));
+                    }
+                },
+                LibraryUnit::ConfigurationDeclaration(_) => {
+                    let configuration_id = library_unit_id.downcast();
+                    if !instantiated_configurations.contains(&configuration_id) {
+                        candidates.push(TopLevelCandidate::Configuration(configuration_id));
+                    }
+                },
+                _ => {},
+            }
+        }
+    }
+    candidates
+}
+
+/// Resolves a name to the declaration of type `T` it denotes, if any.
+fn resolve<T>(ast: &Ast, name: NameNodeId) -> Option<NodeId<T>>
+where
+    T: 'static,
+    for<'ast> &'ast T: TryFrom<&'ast Node>,
+    NamedEntityNodeId: DowncastNodeId<T>,
+{
+    let named_entity = name.try_get(ast).ok()?.named_entity()?;
+    named_entity.try_downcast(ast).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::FIXTURE;
+
+    #[test]
+    fn test_top_level_candidates() {
+        const TESTBENCH_CHUNK: &str = r#"{"first_id":20,"last_id":37,"files":[{"source":"top.vhd","start":1,"end":20},{"source":"tb.vhd","start":20,"end":38}],"libraries":[20]}
+{"library_declaration":{"identifier":["lib",null],"design_files":[21]}}
+{"design_file":{"design_units":[22,24,26,35]}}
+{"design_unit":{"library_unit":23,"design_file":21}}
+{"entity_declaration":{"id":23,"identifier":["leaf",null],"parent":22}}
+{"design_unit":{"library_unit":25,"design_file":21}}
+{"entity_declaration":{"id":25,"identifier":["tb",null],"parent":24}}
+{"design_unit":{"library_unit":27,"design_file":21}}
+{"architecture_body":{"identifier":["sim",null],"entity_name":28,"parent":26,"declarations":[29],"concurrent_statements":[30,32]}}
+{"simple_name":{"identifier":["tb",null],"named_entity":25}}
+{"component_declaration":{"identifier":["leaf",null]}}
+{"component_instantiation_statement":{"label":["u_leaf",null],"instantiated_unit":31}}
+{"simple_name":{"identifier":["leaf",null],"named_entity":29}}
+{"component_instantiation_statement":{"label":["u_top",null],"instantiated_unit":33}}
+{"entity_aspect_entity":{"entity_name":34}}
+{"simple_name":{"identifier":["top",null],"named_entity":5}}
+{"design_unit":{"library_unit":36,"design_file":21}}
+{"configuration_declaration":{"identifier":["cfg",null],"parent":35,"entity_name":37}}
+{"simple_name":{"identifier":["tb",null],"named_entity":25}}
+
+"#;
+
+        let mut builder = AstBuilder::new();
+        let next_line_number = builder.append_json(&mut FIXTURE.as_bytes(), 1).unwrap();
+        builder
+            .append_json(&mut TESTBENCH_CHUNK.as_bytes(), next_line_number)
+            .unwrap();
+        let ast = builder.finish();
+        let entity = |raw| NodeId::from_raw(IdPrimitive::new(raw).unwrap());
+        let work = ast
+            .lookup_library(&NormalizedIdentifier::new("work"))
+            .unwrap();
+
+        let options = TopLevelOptions::default();
+        assert_eq!(
+            ast.top_level_candidates(None, &options),
+            [
+                TopLevelCandidate::Entity(entity(25)),
+                TopLevelCandidate::Configuration(NodeId::from_raw(IdPrimitive::new(36).unwrap())),
+            ]
+        );
+        assert_eq!(ast.top_level_candidates(Some(work), &options), []);
+
+        let mut ignoring_lib = TopLevelOptions::default();
+        ignoring_lib
+            .ignored_libraries
+            .push(NormalizedIdentifier::new("lib"));
+        assert_eq!(
+            ast.top_level_candidates(None, &ignoring_lib),
+            [TopLevelCandidate::Entity(entity(5))]
+        );
+
+        // IDs which aren't libraries have no candidates.
+        for raw in [5, 1000] {
+            let library_id = NodeId::from_raw(IdPrimitive::new(raw).unwrap());
+            assert_eq!(ast.top_level_candidates(Some(library_id), &options), []);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Read-only traversal of the AST.
+//!
+//! Implement [`Visitor`] and override the `visit_*` hooks for the node kinds of
+//! interest. To keep descending into the children of a node, an overriding hook
+//! must call the corresponding `walk_*` function.
+//!
+//! The traversal descends into the nodes referred to by the owning fields of a
+//! node (see [`Ast::parent`]), including the IDs stored in [`IndexList`],
+//! [`SensitivityList`] and [`InstantiationList`] fields. References to nodes of
+//! other subtrees, such as `type` or `named_entity`, are passed to
+//! [`Visitor::visit_reference`] instead of being visited, so every node is
+//! visited at most once per traversal.
+//!
+//! ```ignore
+//! struct SignalCounter(usize);
+//!
+//! impl<'ast> Visitor<'ast> for SignalCounter {
+//!     fn visit_signal_declaration(
+//!         &mut self,
+//!         ast: &'ast Ast,
+//!         id: NodeId<SignalDeclaration>,
+//!         node: &'ast SignalDeclaration,
+//!     ) {
+//!         self.0 += 1;
+//!         visit::walk_signal_declaration(self, ast, node);
+//!     }
+//! }
+//! ```
+
+use super::*;
+use crate::nodes::with_node_kinds;
+
+macro_rules! visitor_declaration {
+    (
+        $(
+            $(#[$($variant_attr:meta),*])?
+            $variant:ident
+        ),+ $(,)?
+    ) => {
+        paste::paste! {
+            /// Read-only AST visitor with one hook per node kind.
+            ///
+            /// The default implementation of each hook calls the corresponding `walk_*`
+            /// function, which visits the children of the node.
+            pub trait Visitor<'ast> {
+                /// Visits the node with the given ID, dispatching on its kind.
+                fn visit_node(&mut self, ast: &'ast Ast, id: GenericNodeId) {
+                    walk_node(self, ast, id);
+                }
+
+                /// Called for every reference to a node of another subtree, such as `type` or
+                /// `named_entity`, with the serialized name of the field containing it.
+                ///
+                /// The referenced node isn't visited. The default implementation does nothing.
+                fn visit_reference(&mut self, _ast: &'ast Ast, _field: &'static str, _id: GenericNodeId) {}
+
+                $(
+                    #[doc = concat!("Visits a [`", stringify!($variant), "`] node.")]
+                    fn [<visit_ $variant:snake>](
+                        &mut self,
+                        ast: &'ast Ast,
+                        _id: NodeId<$variant>,
+                        node: &'ast $variant,
+                    ) {
+                        [<walk_ $variant:snake>](self, ast, node);
+                    }
+                )+
+            }
+
+            /// Calls the `visit_*` hook matching the kind of the given node.
+            ///
+            /// Empty, unknown and out-of-range nodes are ignored.
+            pub fn walk_node<'ast, V>(visitor: &mut V, ast: &'ast Ast, id: GenericNodeId)
+            where
+                V: Visitor<'ast> + ?Sized,
+            {
+                match ast.nodes.get(id.to_raw().get() as usize) {
+                    $(
+                        Some(Node::$variant(node)) => {
+                            visitor.[<visit_ $variant:snake>](ast, NodeId::from_raw(id.to_raw()), node);
+                        },
+                    )+
+                    Some(Node::Unknown { .. } | Node::Empty) | None => {},
+                }
+            }
+
+            $(
+                #[doc = concat!("Visits the children of a [`", stringify!($variant), "`] node.")]
+                pub fn [<walk_ $variant:snake>]<'ast, V>(visitor: &mut V, ast: &'ast Ast, node: &'ast $variant)
+                where
+                    V: Visitor<'ast> + ?Sized,
+                {
+                    walk_children(visitor, ast, node);
+                }
+            )+
+        }
+    };
+}
+
+with_node_kinds!(visitor_declaration);
+
+fn walk_children<'ast, V, T>(visitor: &mut V, ast: &'ast Ast, node: &'ast T)
+where
+    V: Visitor<'ast> + ?Sized,
+    T: references::NodeFields,
+{
+    node.visit_fields(&mut |reference: references::Reference| {
+        if reference.field.owning {
+            visitor.visit_node(ast, reference.id);
+        } else {
+            visitor.visit_reference(ast, reference.field.name, reference.id);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    #![expect(clippy::unwrap_used, reason = "ok for tests")]
+    use super::*;
+    use crate::tests::load_fixture;
+
+    #[test]
+    fn test_visitors() {
+        struct Collector(Vec<&'static str>, Vec<(&'static str, u32)>);
+
+        impl<'ast> Visitor<'ast> for Collector {
+            fn visit_node(&mut self, ast: &'ast Ast, id: GenericNodeId) {
+                self.0.push(id.get(ast).type_str());
+                walk_node(self, ast, id);
+            }
+
+            fn visit_reference(&mut self, _ast: &'ast Ast, field: &'static str, id: GenericNodeId) {
+                self.1.push((field, id.to_raw().get()));
+            }
+
+            fn visit_range_expression(
+                &mut self,
+                _ast: &'ast Ast,
+                _id: NodeId<RangeExpression>,
+                _node: &'ast RangeExpression,
+            ) {
+                // Don't descend.
+            }
+        }
+
+        struct Doubler;
+
+        impl VisitorMut for Doubler {
+            fn visit_integer_literal(&mut self, ast: &mut Ast, id: NodeId<IntegerLiteral>) {
+                id.get_mut(ast).value *= 2;
+                visit_mut::walk_integer_literal(self, ast, id);
+            }
+        }
+
+        let mut ast = load_fixture();
+        let work = GenericNodeId::from_raw(IdPrimitive::new(1).unwrap());
+
+        let mut collector = Collector(Vec::new(), Vec::new());
+        collector.visit_node(&ast, work);
+        assert_eq!(
+            collector.0,
+            [
+                "Library",
+                "DesignFile",
+                "DesignUnit",
+                "EntityDeclaration",
+                "DesignUnit",
+                "ArchitectureBody",
+                "SimpleName",
+                "SignalDeclaration",
+                "IntegerSubtypeDefinition",
+                "RangeExpression",
+                "SensitizedProcessStatement",
+                "SimpleSignalAssignmentStatement",
+                "SimpleName",
+                "WaveformElement",
+                "IntegerLiteral",
+                "SimpleName",
+            ]
+        );
+        // References are reported, but not visited.
+        assert_eq!(
+            collector.1,
+            [
+                ("id", 5),
+                ("parent", 4),
+                ("design_file", 3),
+                ("named_entity", 5),
+                ("parent", 6),
+                ("type", 10),
+                ("named_entity", 9),
+                ("named_entity", 9),
+                ("design_file", 3),
+            ]
+        );
+
+        Doubler.visit_node(&mut ast, work);
+        let value = |raw| {
+            NodeId::<IntegerLiteral>::from_raw(IdPrimitive::new(raw).unwrap())
+                .get(&ast)
+                .value
+        };
+        // The literals of the range constraint are reachable from the signal declaration.
+        assert_eq!([value(12), value(13), value(19)], [0, 2, 2]);
+    }
+}
@@ -0,0 +1,96 @@
+//! Mutable traversal of the AST.
+//!
+//! Works like [`visit`], but the hooks get mutable access to the
+//! whole [`Ast`] and receive node IDs only; use [`NodeId::get_mut`] to modify
+//! the visited node.
+//!
+//! Hooks may only make edits which the indexes of the AST don't depend on, such
+//! as changing the value of a literal; see [`NodeId::get_mut`]. As in
+//! [`visit`], references to nodes of other subtrees are passed to
+//! [`VisitorMut::visit_reference`] instead of being visited.
+
+use smallvec::SmallVec;
+
+use super::*;
+use crate::nodes::with_node_kinds;
+
+macro_rules! visitor_mut_declaration {
+    (
+        $(
+            $(#[$($variant_attr:meta),*])?
+            $variant:ident
+        ),+ $(,)?
+    ) => {
+        paste::paste! {
+            /// Mutable AST visitor with one hook per node kind.
+            ///
+            /// The default implementation of each hook calls the corresponding `walk_*`
+            /// function, which visits the children of the node.
+            pub trait VisitorMut {
+                /// Visits the node with the given ID, dispatching on its kind.
+                fn visit_node(&mut self, ast: &mut Ast, id: GenericNodeId) {
+                    walk_node(self, ast, id);
+                }
+
+                /// Called for every reference to a node of another subtree, such as `type` or
+                /// `named_entity`, with the serialized name of the field containing it.
+                ///
+                /// The referenced node isn't visited. The default implementation does nothing.
+                fn visit_reference(&mut self, _ast: &mut Ast, _field: &'static str, _id: GenericNodeId) {}
+
+                $(
+                    #[doc = concat!("Visits a [`", stringify!($variant), "`] node.")]
+                    fn [<visit_ $variant:snake>](&mut self, ast: &mut Ast, id: NodeId<$variant>) {
+                        [<walk_ $variant:snake>](self, ast, id);
+                    }
+                )+
+            }
+
+            /// Calls the `visit_*` hook matching the kind of the given node.
+            ///
+            /// Empty, unknown and out-of-range nodes are ignored.
+            pub fn walk_node<V>(visitor: &mut V, ast: &mut Ast, id: GenericNodeId)
+            where
+                V: VisitorMut + ?Sized,
+            {
+                match ast.nodes.get(id.to_raw().get() as usize) {
+                    $(
+                        Some(Node::$variant(_)) => {
+                            visitor.[<visit_ $variant:snake>](ast, NodeId::from_raw(id.to_raw()));
+                        },
+                    )+
+                    Some(Node::Unknown { .. } | Node::Empty) | None => {},
+                }
+            }
+
+            $(
+                #[doc = concat!("Visits the children of a [`", stringify!($variant), "`] node.")]
+                pub fn [<walk_ $variant:snake>]<V>(visitor: &mut V, ast: &mut Ast, id: NodeId<$variant>)
+                where
+                    V: VisitorMut + ?Sized,
+                {
+                    walk_children(visitor, ast, id.into());
+                }
+            )+
+        }
+    };
+}
+
+with_node_kinds!(visitor_mut_declaration);
+
+fn walk_children<V>(visitor: &mut V, ast: &mut Ast, id: GenericNodeId)
+where
+    V: VisitorMut + ?Sized,
+{
+    let mut children = SmallVec::<[_; 8]>::new();
+    if let Some(node) = ast.nodes.get(id.to_raw().get() as usize) {
+        node.for_each_reference(|reference| children.push(reference));
+    }
+    for reference in children {
+        if reference.field.owning {
+            visitor.visit_node(ast, reference.id);
+        } else {
+            visitor.visit_reference(ast, reference.field.name, reference.id);
+        }
+    }
+}